@@ -41,17 +41,25 @@ fn main() {
         );
     }
 
-    // Warn if the `__doc` feature is used on stable or beta.
+    // Warn if the `__doc` or `step` features are used on stable or beta.
     if !rustc::Channel::read().map_or(false, |channel| channel.supports_features()) {
         #[cfg(__time_02_docs)]
         warning!(
             "`--cfg __time_02_docs` requires a nightly compiler, and is intended for internal \
              usage only."
         );
+
+        #[cfg(feature = "step")]
+        warning!("The `step` feature requires a nightly compiler.");
     }
 
     // ==== features that affect runtime directly ====
 
+    // `const` initializers in `thread_local!` were stabilized in 1.59.0.
+    if rustc::is_min_version("1.59.0").unwrap_or(false) {
+        cfg_emit!(__time_02_const_thread_local);
+    }
+
     // `#[non_exhaustive]` was stabilized in 1.40.0.
     if rustc::is_min_version("1.40.0").unwrap_or(false) {
         cfg_emit!(__time_02_supports_non_exhaustive);
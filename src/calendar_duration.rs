@@ -0,0 +1,109 @@
+use core::ops::Neg;
+
+/// A span of time expressed in calendar units: years, months, and days.
+///
+/// Unlike [`Duration`](crate::Duration), the length of a `CalendarDuration`
+/// depends on the date it is applied to, as months and years vary in length.
+/// It is obtained from [`Date::calendar_difference`](crate::Date::calendar_difference).
+///
+/// All components share the same sign. A `CalendarDuration` is never
+/// normalized beyond that: months are always in the range `-11..=11` and days
+/// are always smaller in magnitude than the month they were borrowed from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CalendarDuration {
+    /// Number of whole years.
+    pub(crate) years: i32,
+    /// Number of whole months, excluding those accounted for by `years`.
+    pub(crate) months: i8,
+    /// Number of days, excluding those accounted for by `years` and `months`.
+    pub(crate) days: i8,
+}
+
+impl CalendarDuration {
+    /// A `CalendarDuration` with all components equal to zero.
+    ///
+    /// ```rust
+    /// # use time::{CalendarDuration, date};
+    /// assert_eq!(
+    ///     date!(2020-01-01).calendar_difference(date!(2020-01-01)),
+    ///     CalendarDuration::ZERO
+    /// );
+    /// ```
+    pub const ZERO: Self = Self {
+        years: 0,
+        months: 0,
+        days: 0,
+    };
+
+    /// Get the number of whole years.
+    ///
+    /// ```rust
+    /// # use time::date;
+    /// let diff = date!(2022-04-06).calendar_difference(date!(2020-01-01));
+    /// assert_eq!(diff.years(), 2);
+    /// ```
+    pub const fn years(self) -> i32 {
+        self.years
+    }
+
+    /// Get the number of whole months, excluding those accounted for by
+    /// [`years`](CalendarDuration::years).
+    ///
+    /// ```rust
+    /// # use time::date;
+    /// let diff = date!(2022-04-06).calendar_difference(date!(2020-01-01));
+    /// assert_eq!(diff.months(), 3);
+    /// ```
+    pub const fn months(self) -> i8 {
+        self.months
+    }
+
+    /// Get the number of days, excluding those accounted for by
+    /// [`years`](CalendarDuration::years) and
+    /// [`months`](CalendarDuration::months).
+    ///
+    /// ```rust
+    /// # use time::date;
+    /// let diff = date!(2022-04-06).calendar_difference(date!(2020-01-01));
+    /// assert_eq!(diff.days(), 5);
+    /// ```
+    pub const fn days(self) -> i8 {
+        self.days
+    }
+
+    /// Check if all components are zero.
+    ///
+    /// ```rust
+    /// # use time::{CalendarDuration, date};
+    /// assert!(CalendarDuration::ZERO.is_zero());
+    /// assert!(!date!(2020-01-02).calendar_difference(date!(2020-01-01)).is_zero());
+    /// ```
+    pub const fn is_zero(self) -> bool {
+        (self.years == 0) & (self.months == 0) & (self.days == 0)
+    }
+
+    /// Check if the `CalendarDuration` is negative, which is the case when the
+    /// later date was passed as the argument to
+    /// [`Date::calendar_difference`](crate::Date::calendar_difference).
+    ///
+    /// ```rust
+    /// # use time::date;
+    /// assert!(date!(2020-01-01).calendar_difference(date!(2020-01-02)).is_negative());
+    /// assert!(!date!(2020-01-02).calendar_difference(date!(2020-01-01)).is_negative());
+    /// ```
+    pub const fn is_negative(self) -> bool {
+        (self.years < 0) | (self.months < 0) | (self.days < 0)
+    }
+}
+
+impl Neg for CalendarDuration {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            years: -self.years,
+            months: -self.months,
+            days: -self.days,
+        }
+    }
+}
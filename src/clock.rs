@@ -0,0 +1,156 @@
+//! Information about the system clock.
+
+use crate::{Duration, OffsetDateTime};
+
+/// Obtain the resolution of the system clock used by
+/// [`OffsetDateTime::now_utc`](crate::OffsetDateTime::now_utc).
+///
+/// Timestamps are only meaningful to the precision returned here; for example,
+/// a resolution of one millisecond means that the nanosecond component of the
+/// current time should not be relied upon below that.
+///
+/// On Unix, this queries `clock_getres`. Where the resolution cannot be
+/// determined, a conservative value of 15.625 milliseconds (the default
+/// Windows timer interval, and the coarsest clock commonly encountered) is
+/// returned.
+///
+/// ```rust
+/// # use time::{clock_resolution, prelude::*};
+/// let resolution = clock_resolution();
+/// assert!(resolution.is_positive());
+/// assert!(resolution <= 1.seconds());
+/// ```
+#[cfg_attr(docs, doc(cfg(feature = "std")))]
+pub fn clock_resolution() -> Duration {
+    system_clock_resolution().unwrap_or_else(|| Duration::microseconds(15_625))
+}
+
+/// Query the resolution of the system's realtime clock, returning `None` if
+/// it cannot be determined.
+#[allow(clippy::missing_const_for_fn)] // only const on non-Unix platforms
+fn system_clock_resolution() -> Option<Duration> {
+    #[cfg(target_family = "unix")]
+    {
+        use standback::mem::MaybeUninit;
+
+        let mut timespec = MaybeUninit::<libc::timespec>::uninit();
+
+        // Safety: We are calling a system API, which writes to `timespec` on
+        // success.
+        #[allow(unsafe_code)]
+        let result = unsafe { libc::clock_getres(libc::CLOCK_REALTIME, timespec.as_mut_ptr()) };
+        if result != 0 {
+            return None;
+        }
+
+        // Safety: The value was initialized, as the call succeeded.
+        #[allow(unsafe_code)]
+        let timespec = unsafe { timespec.assume_init() };
+
+        #[allow(clippy::useless_conversion)]
+        let resolution = Duration::new(timespec.tv_sec.into(), timespec.tv_nsec as i32);
+        if resolution.is_positive() {
+            Some(resolution)
+        } else {
+            None
+        }
+    }
+    #[cfg(not(target_family = "unix"))]
+    {
+        None
+    }
+}
+
+/// Create a [`MonotonicGuard`] over
+/// [`OffsetDateTime::now_utc`](crate::OffsetDateTime::now_utc).
+///
+/// ```rust
+/// # use time::clock::monotonic_guard;
+/// let mut clock = monotonic_guard();
+/// let first = clock.now_utc();
+/// assert!(clock.now_utc() >= first);
+/// ```
+#[cfg_attr(docs, doc(cfg(feature = "std")))]
+pub fn monotonic_guard() -> MonotonicGuard {
+    MonotonicGuard {
+        clock: OffsetDateTime::now_utc,
+        last: None,
+    }
+}
+
+/// A wrapper around the wall clock that never goes backwards.
+///
+/// The system clock can jump, such as when it is stepped by NTP or changed
+/// manually. A `MonotonicGuard` remembers the last value it returned, and if
+/// the clock is ever found to be earlier than that, the previous value is
+/// returned again until the clock catches up.
+///
+/// This is smoothing of the wall clock, _not_ a true monotonic clock. Forward
+/// jumps are passed through unchanged, and time appears to stand still for as
+/// long as the clock is behind. For measuring elapsed time, use
+/// [`Instant`](crate::Instant) instead.
+///
+/// Each guard tracks its own last value, so values are only guaranteed to be
+/// non-decreasing when obtained from the same guard.
+#[cfg_attr(docs, doc(cfg(feature = "std")))]
+#[allow(missing_copy_implementations)] // A clone tracks its own last value, so must be explicit.
+#[derive(Debug, Clone)]
+pub struct MonotonicGuard {
+    /// The underlying source of the current time.
+    clock: fn() -> OffsetDateTime,
+    /// The most recently returned value, if any.
+    last: Option<OffsetDateTime>,
+}
+
+impl MonotonicGuard {
+    /// Obtain the current time in UTC, clamped so that it is never earlier
+    /// than a value previously returned by this guard.
+    pub fn now_utc(&mut self) -> OffsetDateTime {
+        let now = (self.clock)();
+        let now = match self.last {
+            Some(last) if now < last => last,
+            _ => now,
+        };
+        self.last = Some(now);
+        now
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ext::NumericalDuration;
+
+    #[test]
+    fn clock_resolution_is_reasonable() {
+        let resolution = clock_resolution();
+        assert!(resolution.is_positive());
+        assert!(resolution <= 1.seconds());
+    }
+
+    #[test]
+    fn monotonic_guard_clamps_backward_jumps() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        /// The number of times `clock` has been called.
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        /// A clock that jumps backwards by an hour and then slowly recovers.
+        fn clock() -> OffsetDateTime {
+            let offsets = [0, 10, -3_600, -5, 20, 15, 30];
+            let index = CALLS.fetch_add(1, Ordering::Relaxed);
+            OffsetDateTime::from_unix_timestamp(1_609_459_200 + offsets[index])
+        }
+
+        let mut guard = MonotonicGuard { clock, last: None };
+        let values: Vec<_> = (0..7).map(|_| guard.now_utc().timestamp()).collect();
+        assert_eq!(
+            values,
+            [0, 10, 10, 10, 20, 20, 30]
+                .iter()
+                .map(|offset| 1_609_459_200 + offset)
+                .collect::<Vec<_>>()
+        );
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
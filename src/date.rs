@@ -2,18 +2,24 @@ use crate::{
     error,
     format::parse::{parse, ParsedItems},
     internals,
-    util::{days_in_year, days_in_year_month, is_leap_year, weeks_in_year},
-    DeferredFormat, Duration, ParseResult, PrimitiveDateTime, Time, Weekday,
+    util::{
+        days_in_year, days_in_year_month, is_leap_year, weeks_in_year,
+        DAYS_BEFORE_MONTH_COMMON_LEAP,
+    },
+    CalendarDuration, DeferredFormat, Duration, MonthAddPolicy, ParseResult, PrimitiveDateTime,
+    Time, Weekday, WeekdaySet,
 };
 #[cfg(not(feature = "std"))]
 use alloc::{
     borrow::ToOwned,
     string::{String, ToString},
+    vec::Vec,
 };
 use const_fn::const_fn;
 use core::{
     cmp::{Ord, Ordering, PartialOrd},
     fmt::{self, Display},
+    num::NonZeroU8,
     ops::{Add, AddAssign, Sub, SubAssign},
     time::Duration as StdDuration,
 };
@@ -26,9 +32,21 @@ use standback::prelude::*;
 pub(crate) const MIN_YEAR: i32 = -100_000;
 /// The maximum valid year.
 pub(crate) const MAX_YEAR: i32 = 100_000;
+/// The Julian day of the minimum valid date.
+pub(crate) const MIN_JULIAN_DAY: i64 = -34_803_190;
+/// The Julian day of the maximum valid date.
+pub(crate) const MAX_JULIAN_DAY: i64 = 38_245_675;
+/// The Julian day of the Unix epoch, 1970-01-01.
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
 
 /// Calendar date.
 ///
+/// Dates use the proleptic Gregorian calendar with [astronomical year
+/// numbering](https://en.wikipedia.org/wiki/Astronomical_year_numbering): the
+/// year before 1 is 0 (1 BCE), which is preceded by -1 (2 BCE), and so on.
+/// When formatted with `%Y`, negative years and years with more than four
+/// digits are prefixed with a sign, as in ISO 8601's expanded representation.
+///
 /// Years between `-100_000` and `+100_000` inclusive are guaranteed to be
 /// representable. Any values outside this range may have incidental support
 /// that can change at any time without notice. If you need support outside this
@@ -106,11 +124,15 @@ impl Date {
     /// assert!(Date::try_from_ymd(2019, 12, 31).is_ok());
     /// ```
     ///
-    /// Returns `None` if the date is not valid.
+    /// Returns an error if the date is not valid. The month is checked before
+    /// the day, so [`name`](crate::ComponentRangeError::name) indicates which of
+    /// the two was at fault.
     ///
     /// ```rust
     /// # use time::Date;
     /// assert!(Date::try_from_ymd(2019, 2, 29).is_err()); // 2019 isn't a leap year.
+    /// assert_eq!(Date::try_from_ymd(2019, 13, 1).map_err(|e| e.name()), Err("month"));
+    /// assert_eq!(Date::try_from_ymd(2019, 2, 29).map_err(|e| e.name()), Err("day"));
     /// ```
     ///
     /// This function is `const fn` when using rustc >= 1.46.
@@ -127,6 +149,36 @@ impl Date {
         Ok(internals::Date::from_ymd_unchecked(year, month, day))
     }
 
+    /// Attempt to create a `Date` from each of the provided year, month, and
+    /// day triples, as with [`Date::try_from_ymd`].
+    ///
+    /// If every row is valid, the dates are returned in the same order.
+    /// Otherwise, the index of the first invalid row is returned alongside its
+    /// error.
+    ///
+    /// ```rust
+    /// # use time::{Date, date};
+    /// assert_eq!(
+    ///     Date::from_ymd_batch(&[(2019, 1, 1), (2020, 2, 29)]),
+    ///     Ok(vec![date!(2019-01-01), date!(2020-02-29)])
+    /// );
+    /// assert_eq!(
+    ///     Date::from_ymd_batch(&[(2019, 1, 1), (2019, 2, 29), (2019, 13, 1)])
+    ///         .map_err(|(index, _)| index),
+    ///     Err(1)
+    /// );
+    /// ```
+    pub fn from_ymd_batch(
+        rows: &[(i32, u8, u8)],
+    ) -> Result<Vec<Self>, (usize, error::ComponentRange)> {
+        rows.iter()
+            .enumerate()
+            .map(|(index, &(year, month, day))| {
+                Self::try_from_ymd(year, month, day).map_err(|err| (index, err))
+            })
+            .collect()
+    }
+
     /// Create a `Date` from the year and ordinal day number.
     ///
     /// ```rust
@@ -328,39 +380,31 @@ impl Date {
     // significantly faster to write the statements out by hand.
     #[const_fn("1.46")]
     pub const fn month_day(self) -> (u8, u8) {
-        /// The number of days up to and including the given month. Common years
-        /// are first, followed by leap years.
-        #[allow(clippy::items_after_statements)]
-        const CUMULATIVE_DAYS_IN_MONTH_COMMON_LEAP: [[u16; 11]; 2] = [
-            [31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334],
-            [31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335],
-        ];
-
-        let days = CUMULATIVE_DAYS_IN_MONTH_COMMON_LEAP[is_leap_year(self.year()) as usize];
+        let days = DAYS_BEFORE_MONTH_COMMON_LEAP[is_leap_year(self.year()) as usize];
         let ordinal = self.ordinal();
 
-        if ordinal > days[10] {
-            (12, (ordinal - days[10]) as u8)
+        if ordinal > days[11] {
+            (12, (ordinal - days[11]) as u8)
+        } else if ordinal > days[10] {
+            (11, (ordinal - days[10]) as u8)
         } else if ordinal > days[9] {
-            (11, (ordinal - days[9]) as u8)
+            (10, (ordinal - days[9]) as u8)
         } else if ordinal > days[8] {
-            (10, (ordinal - days[8]) as u8)
+            (9, (ordinal - days[8]) as u8)
         } else if ordinal > days[7] {
-            (9, (ordinal - days[7]) as u8)
+            (8, (ordinal - days[7]) as u8)
         } else if ordinal > days[6] {
-            (8, (ordinal - days[6]) as u8)
+            (7, (ordinal - days[6]) as u8)
         } else if ordinal > days[5] {
-            (7, (ordinal - days[5]) as u8)
+            (6, (ordinal - days[5]) as u8)
         } else if ordinal > days[4] {
-            (6, (ordinal - days[4]) as u8)
+            (5, (ordinal - days[4]) as u8)
         } else if ordinal > days[3] {
-            (5, (ordinal - days[3]) as u8)
+            (4, (ordinal - days[3]) as u8)
         } else if ordinal > days[2] {
-            (4, (ordinal - days[2]) as u8)
+            (3, (ordinal - days[2]) as u8)
         } else if ordinal > days[1] {
-            (3, (ordinal - days[1]) as u8)
-        } else if ordinal > days[0] {
-            (2, (ordinal - days[0]) as u8)
+            (2, (ordinal - days[1]) as u8)
         } else {
             (1, ordinal as u8)
         }
@@ -384,8 +428,24 @@ impl Date {
         (self.value & 0x1FF) as u16
     }
 
+    /// Get a key that orders dates chronologically with a single integer
+    /// comparison. The key is `year * 512 + ordinal`, which is exactly the
+    /// packed representation, so it is monotonic across the full range of
+    /// years, including negative ones.
+    pub(crate) const fn to_ordinal_value(self) -> i32 {
+        self.value
+    }
+
     /// Get the ISO 8601 year and week number.
     ///
+    /// ISO weeks begin on Monday. Week 1 of a year is the week containing its
+    /// first Thursday (equivalently, the week containing January 4). A year
+    /// has 53 weeks if it begins on a Thursday, or if it is a leap year
+    /// beginning on a Wednesday; otherwise it has 52. Days in late December
+    /// may therefore belong to week 1 of the following year, and days in
+    /// early January to the last week of the previous year, in which case
+    /// the returned year differs from [`Date::year`].
+    ///
     /// ```rust
     /// # use time::date;
     /// assert_eq!(date!(2019-01-01).iso_year_week(), (2019, 1));
@@ -453,6 +513,31 @@ impl Date {
         ((self.ordinal() as i16 - self.weekday().number_days_from_monday() as i16 + 6) / 7) as u8
     }
 
+    /// Get the week of the month, where weeks begin on the provided day and
+    /// week 1 contains the first day of the month.
+    ///
+    /// The returned value will always be in the range `1..=6`.
+    ///
+    /// ```rust
+    /// # use time::{date, Weekday::*};
+    /// // 2021-03-01 is a Monday.
+    /// assert_eq!(date!(2021-03-01).week_of_month(Monday), 1);
+    /// assert_eq!(date!(2021-03-07).week_of_month(Monday), 1);
+    /// assert_eq!(date!(2021-03-07).week_of_month(Sunday), 2);
+    /// assert_eq!(date!(2021-03-31).week_of_month(Monday), 5);
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn week_of_month(self, week_start: Weekday) -> u8 {
+        let days_into_week = (self.weekday().number_days_from_monday() + 7
+            - week_start.number_days_from_monday())
+            % 7;
+        // The number of days into the week of the first day of the month.
+        let first_days_into_week = (days_into_week + 35 - (self.day() - 1)) % 7;
+        (self.day() - 1 + first_days_into_week) / 7 + 1
+    }
+
     /// Get the year, month, and day.
     ///
     /// ```rust
@@ -483,8 +568,8 @@ impl Date {
 
     /// Get the weekday.
     ///
-    /// This current uses [Zeller's congruence](https://en.wikipedia.org/wiki/Zeller%27s_congruence)
-    /// internally.
+    /// This is computed in constant time from the Julian day, which is zero on
+    /// a Monday.
     ///
     /// ```rust
     /// # use time::{date, Weekday::*};
@@ -501,31 +586,24 @@ impl Date {
     /// assert_eq!(date!(2019-11-01).weekday(), Friday);
     /// assert_eq!(date!(2019-12-01).weekday(), Sunday);
     /// ```
-    pub fn weekday(self) -> Weekday {
-        let (year, month, day) = self.as_ymd();
-
-        let (month, adjusted_year) = if month < 3 {
-            (month + 12, year - 1)
-        } else {
-            (month, year)
-        };
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn weekday(self) -> Weekday {
+        /// Weekdays, indexed by the number of days from Monday.
+        const WEEKDAYS: [Weekday; 7] = [
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+            Weekday::Sunday,
+        ];
 
-        match (day as i32 + (13 * (month as i32 + 1)) / 5 + adjusted_year + adjusted_year / 4
-            - adjusted_year / 100
-            + adjusted_year / 400)
-            .rem_euclid(7)
-        {
-            0 => Weekday::Saturday,
-            1 => Weekday::Sunday,
-            2 => Weekday::Monday,
-            3 => Weekday::Tuesday,
-            4 => Weekday::Wednesday,
-            5 => Weekday::Thursday,
-            6 => Weekday::Friday,
-            // FIXME The compiler isn't able to optimize this away. See
-            // rust-lang/rust#66993.
-            _ => unreachable!("A value mod 7 is always in the range 0..7"),
-        }
+        // The Julian day is negative for dates before -4713-11-24, so the
+        // remainder must be made non-negative.
+        WEEKDAYS[(self.julian_day() % 7 + 7) as usize % 7]
     }
 
     /// Get the next calendar date.
@@ -578,6 +656,351 @@ impl Date {
         internals::Date::from_yo_unchecked(year, ordinal)
     }
 
+    /// Get the next calendar date, or `self` if it is the last representable
+    /// date. Unlike [`Date::next_day`], this never panics.
+    ///
+    /// ```rust
+    /// # use time::{date, Date};
+    /// assert_eq!(date!(2019-12-31).saturating_next_day(), date!(2020-01-01));
+    /// let last = Date::try_from_ymd(100_000, 12, 31)?;
+    /// assert_eq!(last.saturating_next_day(), last);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn saturating_next_day(self) -> Self {
+        let (year, ordinal) = self.as_yo();
+        if year == MAX_YEAR && ordinal == days_in_year(year) {
+            self
+        } else {
+            self.next_day()
+        }
+    }
+
+    /// Get the previous calendar date, or `self` if it is the first
+    /// representable date. Unlike [`Date::previous_day`], this never panics.
+    ///
+    /// ```rust
+    /// # use time::{date, Date};
+    /// assert_eq!(date!(2020-01-01).saturating_previous_day(), date!(2019-12-31));
+    /// let first = Date::try_from_ymd(-100_000, 1, 1)?;
+    /// assert_eq!(first.saturating_previous_day(), first);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn saturating_previous_day(self) -> Self {
+        if self.as_yo() == (MIN_YEAR, 1) {
+            self
+        } else {
+            self.previous_day()
+        }
+    }
+
+    /// Get the next date falling on the provided weekday, returning `None` if
+    /// it is outside the representable range. The result is always after
+    /// `self`; if `self` is already on the provided weekday, the date one week
+    /// later is returned.
+    ///
+    /// ```rust
+    /// # use time::{date, Weekday};
+    /// assert_eq!(
+    ///     date!(2019-01-01).checked_next_weekday(Weekday::Friday),
+    ///     Some(date!(2019-01-04))
+    /// );
+    /// assert_eq!(
+    ///     date!(2019-01-01).checked_next_weekday(Weekday::Tuesday),
+    ///     Some(date!(2019-01-08))
+    /// );
+    /// ```
+    pub fn checked_next_weekday(self, weekday: Weekday) -> Option<Self> {
+        let days = (weekday.number_days_from_monday() as i64
+            - self.weekday().number_days_from_monday() as i64)
+            .rem_euclid(7);
+        let days = if days == 0 { 7 } else { days };
+
+        Self::try_from_julian_day(self.julian_day() + days).ok()
+    }
+
+    /// Get the date falling on the provided weekday that is closest to `self`,
+    /// choosing whichever of the previous or next occurrence is fewer days
+    /// away. If `self` is already on the provided weekday, it is returned
+    /// unchanged.
+    ///
+    /// Ties break toward the future. As a week has an odd number of days, a
+    /// tie between the previous and next occurrences can only arise when
+    /// `self` is already on the provided weekday, in which case no movement is
+    /// needed.
+    ///
+    /// ```rust
+    /// # use time::{date, Weekday};
+    /// // 2019-01-03 is a Thursday.
+    /// assert_eq!(
+    ///     date!(2019-01-03).closest_weekday(Weekday::Monday),
+    ///     date!(2018-12-31)
+    /// );
+    /// assert_eq!(
+    ///     date!(2019-01-03).closest_weekday(Weekday::Saturday),
+    ///     date!(2019-01-05)
+    /// );
+    /// assert_eq!(
+    ///     date!(2019-01-03).closest_weekday(Weekday::Thursday),
+    ///     date!(2019-01-03)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if the resulting date is out of range.
+    pub fn closest_weekday(self, weekday: Weekday) -> Self {
+        let days_until = (weekday.number_days_from_monday() as i64
+            - self.weekday().number_days_from_monday() as i64)
+            .rem_euclid(7);
+        let days_since = (7 - days_until).rem_euclid(7);
+        let days = if days_until <= days_since {
+            days_until
+        } else {
+            -days_since
+        };
+
+        Self::try_from_julian_day(self.julian_day() + days)
+            .expect("resulting value is out of range")
+    }
+
+    /// Iterate over the dates one week apart, starting with `self`. The
+    /// iterator ends after the last date that can be represented.
+    ///
+    /// ```rust
+    /// # use time::date;
+    /// let mut weeks = date!(2019-12-24).iter_weeks();
+    /// assert_eq!(weeks.next(), Some(date!(2019-12-24)));
+    /// assert_eq!(weeks.next(), Some(date!(2019-12-31)));
+    /// assert_eq!(weeks.next(), Some(date!(2020-01-07)));
+    /// ```
+    pub fn iter_weeks(self) -> impl Iterator<Item = Self> {
+        standback::iter::successors(Some(self), |date| {
+            Self::try_from_julian_day(date.julian_day() + 7).ok()
+        })
+    }
+
+    /// Get the number of whole weeks from `self` to `other`. The value is
+    /// negative if `other` is earlier than `self`, and any partial week is
+    /// truncated toward zero.
+    ///
+    /// ```rust
+    /// # use time::date;
+    /// assert_eq!(date!(2019-01-01).weeks_between(date!(2019-01-15)), 2);
+    /// assert_eq!(date!(2019-01-01).weeks_between(date!(2019-01-14)), 1);
+    /// assert_eq!(date!(2019-01-14).weeks_between(date!(2019-01-01)), -1);
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn weeks_between(self, other: Self) -> i64 {
+        (other.julian_day() - self.julian_day()) / 7
+    }
+
+    /// Check whether the day of the week of the date is in the provided set.
+    ///
+    /// ```rust
+    /// # use time::{date, Weekday::*, WeekdaySet};
+    /// let set: WeekdaySet = [Monday, Wednesday, Friday].iter().cloned().collect();
+    /// assert!(date!(2019-01-02).matches(&set)); // Wednesday
+    /// assert!(!date!(2019-01-03).matches(&set)); // Thursday
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn matches(self, set: &WeekdaySet) -> bool {
+        set.contains(self.weekday())
+    }
+
+    /// Computes `self + duration`, returning `None` if the result is out of
+    /// range. Only the whole days of the duration are added; any remainder is
+    /// truncated toward zero.
+    ///
+    /// ```rust
+    /// # use time::{date, Duration, prelude::*};
+    /// assert_eq!(date!(2019-01-01).checked_add(36.hours()), Some(date!(2019-01-02)));
+    /// assert_eq!(date!(2019-01-01).checked_add((-36).hours()), Some(date!(2018-12-31)));
+    /// assert_eq!(date!(2019-01-01).checked_add(Duration::max_value()), None);
+    /// ```
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        Self::try_from_julian_day(self.julian_day() + duration.whole_days()).ok()
+    }
+
+    /// Computes `self - duration`, returning `None` if the result is out of
+    /// range. Only the whole days of the duration are subtracted; any
+    /// remainder is truncated toward zero.
+    ///
+    /// ```rust
+    /// # use time::{date, Duration, prelude::*};
+    /// assert_eq!(date!(2019-01-02).checked_sub(36.hours()), Some(date!(2019-01-01)));
+    /// assert_eq!(date!(2019-01-01).checked_sub(Duration::min_value()), None);
+    /// ```
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        Self::try_from_julian_day(self.julian_day() - duration.whole_days()).ok()
+    }
+
+    /// Add the given number of months to the date, choosing the day of the
+    /// resulting month according to `policy`. Returns `None` if the resulting
+    /// year is out of range.
+    ///
+    /// ```rust
+    /// # use time::{date, MonthAddPolicy};
+    /// assert_eq!(
+    ///     date!(2020-01-31).add_months_with_policy(1, MonthAddPolicy::ClampDay),
+    ///     Some(date!(2020-02-29))
+    /// );
+    /// assert_eq!(
+    ///     date!(2019-02-28).add_months_with_policy(1, MonthAddPolicy::ClampDay),
+    ///     Some(date!(2019-03-28))
+    /// );
+    /// assert_eq!(
+    ///     date!(2019-02-28).add_months_with_policy(1, MonthAddPolicy::PreserveEndOfMonth),
+    ///     Some(date!(2019-03-31))
+    /// );
+    /// assert_eq!(
+    ///     date!(2020-03-31).add_months_with_policy(-13, MonthAddPolicy::ClampDay),
+    ///     Some(date!(2019-02-28))
+    /// );
+    /// ```
+    pub fn add_months_with_policy(self, months: i32, policy: MonthAddPolicy) -> Option<Self> {
+        let (year, month, day) = self.as_ymd();
+
+        let months = year as i64 * 12 + (month - 1) as i64 + months as i64;
+        let mut new_year = months / 12;
+        let mut new_month = months % 12;
+        if new_month < 0 {
+            new_year -= 1;
+            new_month += 12;
+        }
+        if new_year < MIN_YEAR as i64 || new_year > MAX_YEAR as i64 {
+            return None;
+        }
+        let new_year = new_year as i32;
+        let new_month = new_month as u8 + 1;
+
+        let last_day = days_in_year_month(new_year, new_month);
+        let day = match policy {
+            MonthAddPolicy::PreserveEndOfMonth if day == days_in_year_month(year, month) => {
+                last_day
+            }
+            MonthAddPolicy::ClampDay | MonthAddPolicy::PreserveEndOfMonth => day.min(last_day),
+        };
+
+        Some(internals::Date::from_ymd_unchecked(
+            new_year, new_month, day,
+        ))
+    }
+
+    /// Get the first day of the month containing the date.
+    ///
+    /// ```rust
+    /// # use time::date;
+    /// assert_eq!(date!(2020-02-29).first_of_month(), date!(2020-02-01));
+    /// assert_eq!(date!(2020-03-01).first_of_month(), date!(2020-03-01));
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn first_of_month(self) -> Self {
+        let (year, month, _) = self.as_ymd();
+        internals::Date::from_ymd_unchecked(year, month, 1)
+    }
+
+    /// Lay out the days of the given month as a calendar grid, one week per
+    /// row of seven cells, with rows starting on `week_start`. Cells before
+    /// the first and after the last day of the month are `None`.
+    ///
+    /// The grid contains only as many rows as the month needs (four to six),
+    /// so its length is always a multiple of seven.
+    ///
+    /// ```rust
+    /// # use time::{date, Date, Weekday};
+    /// // March 2020 starts on a Sunday.
+    /// let grid = Date::month_grid(2020, 3, Weekday::Monday)?;
+    /// assert_eq!(grid.len(), 42);
+    /// assert_eq!(grid[5], None);
+    /// assert_eq!(grid[6], Some(date!(2020-03-01)));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn month_grid(
+        year: i32,
+        month: u8,
+        week_start: Weekday,
+    ) -> Result<Vec<Option<Self>>, error::ComponentRange> {
+        let first = Self::try_from_ymd(year, month, 1)?;
+        let leading = (first.weekday().number_days_from_monday() + 7
+            - week_start.number_days_from_monday())
+            % 7;
+        let days = days_in_year_month(year, month);
+        let trailing = (7 - (leading + days) % 7) % 7;
+        let cells = leading + days + trailing;
+
+        let mut grid = Vec::with_capacity(cells as usize);
+        grid.extend((0..leading).map(|_| None));
+        grid.extend(
+            (1..=days).map(|day| Some(internals::Date::from_ymd_unchecked(year, month, day))),
+        );
+        grid.extend((leading + days..cells).map(|_| None));
+        Ok(grid)
+    }
+
+    /// Get the difference between two dates as a number of years, months, and
+    /// days. The result is positive if `self` is later than `other`, and
+    /// negative if it is earlier.
+    ///
+    /// The difference is computed by counting whole years and months from the
+    /// earlier date, borrowing a month (using the length of the earlier date's
+    /// month) when the day of the later date is smaller. As months vary in
+    /// length, calendar differences are not associative: adding the components
+    /// back in a different order or to a different starting date may not yield
+    /// the original date.
+    ///
+    /// ```rust
+    /// # use time::date;
+    /// let diff = date!(2022-04-06).calendar_difference(date!(2020-01-01));
+    /// assert_eq!((diff.years(), diff.months(), diff.days()), (2, 3, 5));
+    ///
+    /// let diff = date!(2020-03-01).calendar_difference(date!(2020-01-31));
+    /// assert_eq!((diff.years(), diff.months(), diff.days()), (0, 1, 1));
+    ///
+    /// let diff = date!(2020-01-01).calendar_difference(date!(2022-04-06));
+    /// assert_eq!((diff.years(), diff.months(), diff.days()), (-2, -3, -5));
+    /// ```
+    pub fn calendar_difference(self, other: Self) -> CalendarDuration {
+        let (earlier, later) = if self < other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        let (earlier_year, earlier_month, earlier_day) = earlier.as_ymd();
+        let (later_year, later_month, later_day) = later.as_ymd();
+
+        let mut years = later_year - earlier_year;
+        let mut months = later_month as i8 - earlier_month as i8;
+        let mut days = later_day as i8 - earlier_day as i8;
+
+        if days < 0 {
+            months -= 1;
+            days += days_in_year_month(earlier_year, earlier_month) as i8;
+        }
+        if months < 0 {
+            years -= 1;
+            months += 12;
+        }
+
+        let difference = CalendarDuration {
+            years,
+            months,
+            days,
+        };
+
+        if self < other {
+            -difference
+        } else {
+            difference
+        }
+    }
+
     /// Get the Julian day for the date.
     ///
     /// ```rust
@@ -591,16 +1014,14 @@ impl Date {
     /// This function is `const fn` when using rustc >= 1.46.
     #[const_fn("1.46")]
     pub const fn julian_day(self) -> i64 {
-        let (year, month, day) = self.as_ymd();
-        let year = year as i64;
-        let month = month as i64;
-        let day = day as i64;
+        // Count the days in all preceding years. Floored division is required,
+        // as the year may be negative.
+        let year = self.year() as i64 - 1;
+        let leap_years = (year / 4 - (year % 4 < 0) as i64)
+            - (year / 100 - (year % 100 < 0) as i64)
+            + (year / 400 - (year % 400 < 0) as i64);
 
-        (1_461 * (year + 4_800 + (month - 14) / 12)) / 4
-            + (367 * (month - 2 - 12 * ((month - 14) / 12))) / 12
-            - (3 * ((year + 4_900 + (month - 14) / 12) / 100)) / 4
-            + day
-            - 32_075
+        365 * year + leap_years + self.ordinal() as i64 + 1_721_425
     }
 
     /// Create a `Date` from the Julian day.
@@ -621,7 +1042,20 @@ impl Date {
     /// ```
     // TODO Return a `Result<Self, error::ComponentRange>` in 0.3
     pub fn from_julian_day(julian_day: i64) -> Self {
-        #![allow(clippy::missing_docs_in_private_items)]
+        match Self::try_from_julian_day(julian_day) {
+            Ok(date) => date,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Create a `Date` from the Julian day, returning an error if the
+    /// resulting date is out of range.
+    pub(crate) fn try_from_julian_day(julian_day: i64) -> Result<Self, error::ComponentRange> {
+        #![allow(
+            clippy::missing_docs_in_private_items,
+            clippy::missing_const_for_fn,
+            clippy::manual_range_contains
+        )]
         const Y: i64 = 4_716;
         const J: i64 = 1_401;
         const M: i64 = 2;
@@ -635,18 +1069,62 @@ impl Date {
         const B: i64 = 274_277;
         const C: i64 = -38;
 
-        let f = julian_day + J + (((4 * julian_day + B) / 146_097) * 3) / 4 + C;
+        ensure_value_in_range!(julian_day in MIN_JULIAN_DAY => MAX_JULIAN_DAY);
+
+        // Floored division is required for Julian days before 0.
+        let f = julian_day + J + ((4 * julian_day + B).div_euclid(146_097) * 3).div_euclid(4) + C;
         let e = R * f + V;
         let g = e.rem_euclid(P) / R;
         let h = U * g + W;
         let day = h.rem_euclid(S) / U + 1;
-        let month = (h / S + M).rem_euclid(N) + 1;
-        let year = (e / P) - Y + (N + M - month) / N;
+        let month = (h.div_euclid(S) + M).rem_euclid(N) + 1;
+        let year = e.div_euclid(P) - Y + (N + M - month) / N;
 
-        match Date::try_from_ymd(year as i32, month as u8, day as u8) {
-            Ok(date) => date,
-            Err(err) => panic!("{}", err),
-        }
+        Ok(internals::Date::from_ymd_unchecked(
+            year as i32,
+            month as u8,
+            day as u8,
+        ))
+    }
+
+    /// Get the number of days since the Unix epoch (1970-01-01), which is
+    /// negative for dates before the epoch.
+    ///
+    /// The difference between two such values is the number of whole days
+    /// between the dates, making this suitable for compact storage and fast
+    /// arithmetic.
+    ///
+    /// ```rust
+    /// # use time::date;
+    /// assert_eq!(date!(1970-01-01).to_days_since_epoch(), 0);
+    /// assert_eq!(date!(1969-12-31).to_days_since_epoch(), -1);
+    /// assert_eq!(date!(2000-01-01).to_days_since_epoch(), 10_957);
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn to_days_since_epoch(self) -> i32 {
+        // Every valid Julian day is well within the range of an `i32`.
+        (self.julian_day() - UNIX_EPOCH_JULIAN_DAY) as i32
+    }
+
+    /// Attempt to create a `Date` from the number of days since the Unix epoch
+    /// (1970-01-01), as returned by [`Date::to_days_since_epoch`].
+    ///
+    /// ```rust
+    /// # use time::{date, Date};
+    /// assert_eq!(Date::from_days_since_epoch(0), Ok(date!(1970-01-01)));
+    /// assert_eq!(Date::from_days_since_epoch(-1), Ok(date!(1969-12-31)));
+    /// assert!(Date::from_days_since_epoch(i32::max_value()).is_err());
+    /// ```
+    pub fn from_days_since_epoch(days: i32) -> Result<Self, error::ComponentRange> {
+        #![allow(clippy::manual_range_contains)]
+        let days = days as i64;
+        ensure_value_in_range!(
+            days in MIN_JULIAN_DAY - UNIX_EPOCH_JULIAN_DAY
+                => MAX_JULIAN_DAY - UNIX_EPOCH_JULIAN_DAY
+        );
+        Self::try_from_julian_day(days + UNIX_EPOCH_JULIAN_DAY)
     }
 }
 
@@ -890,6 +1368,18 @@ impl Date {
         self.lazy_format(format).to_string()
     }
 
+    /// Format the `Date` as an ISO 8601 week date in the form `YYYY-Www-D`.
+    /// This is equivalent to formatting with `%G-W%V-%u`.
+    ///
+    /// ```rust
+    /// # use time::date;
+    /// assert_eq!(date!(2021-01-01).to_iso_week_date_string(), "2020-W53-5");
+    /// assert_eq!(date!(2019-12-30).to_iso_week_date_string(), "2020-W01-1");
+    /// ```
+    pub fn to_iso_week_date_string(self) -> String {
+        self.format("%G-W%V-%u")
+    }
+
     /// Format the `Date` using the provided string.
     ///
     /// ```rust
@@ -923,6 +1413,22 @@ impl Date {
         Self::try_from_parsed_items(parse(s.as_ref(), &format.into())?)
     }
 
+    /// Attempt to parse an ISO 8601 week date in the form `YYYY-Www-D`, as
+    /// produced by [`Date::to_iso_week_date_string`]. This is equivalent to
+    /// parsing with the format `%G-W%V-%u`.
+    ///
+    /// ```rust
+    /// # use time::{Date, date};
+    /// assert_eq!(
+    ///     Date::from_iso_week_date_str("2020-W53-5"),
+    ///     Ok(date!(2021-01-01))
+    /// );
+    /// assert!(Date::from_iso_week_date_str("2019-W53-1").is_err());
+    /// ```
+    pub fn from_iso_week_date_str(s: &str) -> ParseResult<Self> {
+        Self::parse(s, "%G-W%V-%u")
+    }
+
     /// Given the items already parsed, attempt to create a `Date`.
     pub(crate) fn try_from_parsed_items(items: ParsedItems) -> ParseResult<Self> {
         macro_rules! items {
@@ -945,6 +1451,28 @@ impl Date {
             }
         }
 
+        /// Obtain the date of the weekday in the given week of the month.
+        fn day_from_week_of_month(
+            year: i32,
+            month: NonZeroU8,
+            week: NonZeroU8,
+            weekday: Weekday,
+            week_start: Weekday,
+        ) -> ParseResult<Date> {
+            /// Get the number of days since the start of the week.
+            fn days_into_week(weekday: Weekday, week_start: Weekday) -> i16 {
+                (weekday.number_days_from_monday() as i16 + 7
+                    - week_start.number_days_from_monday() as i16)
+                    % 7
+            }
+
+            let first = Date::try_from_ymd(year, month.get(), 1)?;
+            let day = (week.get() as i16 - 1) * 7 + days_into_week(weekday, week_start)
+                - days_into_week(first.weekday(), week_start)
+                + 1;
+            Date::try_from_ymd(year, month.get(), day.max(0) as u8).map_err(Into::into)
+        }
+
         match items {
             items!(year, month, day) => {
                 Date::try_from_ymd(year, month.get(), day.get()).map_err(Into::into)
@@ -969,6 +1497,12 @@ impl Date {
                     + 1) as u16,
             )
             .map_err(Into::into),
+            items!(year, month, monday_week_of_month, weekday) => {
+                day_from_week_of_month(year, month, monday_week_of_month, weekday, Weekday::Monday)
+            }
+            items!(year, month, sunday_week_of_month, weekday) => {
+                day_from_week_of_month(year, month, sunday_week_of_month, weekday, Weekday::Sunday)
+            }
             _ => Err(error::Parse::InsufficientInformation),
         }
     }
@@ -991,8 +1525,16 @@ impl Display for Date {
 impl Add<Duration> for Date {
     type Output = Self;
 
+    /// Add the whole days of the `Duration` to the `Date`, truncating any
+    /// remainder toward zero.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the result is out of range. Use
+    /// [`Date::checked_add`](crate::Date::checked_add) to handle this case.
     fn add(self, duration: Duration) -> Self::Output {
-        Self::from_julian_day(self.julian_day() + duration.whole_days())
+        self.checked_add(duration)
+            .expect("resulting value is out of range")
     }
 }
 
@@ -1019,8 +1561,16 @@ impl AddAssign<StdDuration> for Date {
 impl Sub<Duration> for Date {
     type Output = Self;
 
+    /// Subtract the whole days of the `Duration` from the `Date`, truncating
+    /// any remainder toward zero.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the result is out of range. Use
+    /// [`Date::checked_sub`](crate::Date::checked_sub) to handle this case.
     fn sub(self, duration: Duration) -> Self::Output {
-        self + -duration
+        self.checked_sub(duration)
+            .expect("resulting value is out of range")
     }
 }
 
@@ -1047,6 +1597,8 @@ impl SubAssign<StdDuration> for Date {
 impl Sub<Date> for Date {
     type Output = Duration;
 
+    /// Get the number of whole days from `other` to `self`. The result is
+    /// positive if `self` is later.
     fn sub(self, other: Self) -> Self::Output {
         Duration::days(self.julian_day() - other.julian_day())
     }
@@ -1060,9 +1612,46 @@ impl PartialOrd for Date {
 
 impl Ord for Date {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.year()
-            .cmp(&other.year())
-            .then_with(|| self.ordinal().cmp(&other.ordinal()))
+        self.to_ordinal_value().cmp(&other.to_ordinal_value())
+    }
+}
+
+/// Each step is one day, allowing ranges of dates to be iterated over directly.
+///
+/// ```rust
+/// # use time::date;
+/// let days = (date!(2021-01-30)..=date!(2021-02-02)).count();
+/// assert_eq!(days, 4);
+/// ```
+///
+/// `Step` is not yet stable, so this implementation requires a nightly compiler.
+#[cfg(feature = "step")]
+#[cfg_attr(docs, doc(cfg(feature = "step")))]
+impl core::iter::Step for Date {
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        use standback::convert::TryInto;
+
+        if start > end {
+            return (0, None);
+        }
+        match (end.julian_day() - start.julian_day()).try_into() {
+            Ok(steps) => (steps, Some(steps)),
+            Err(_) => (usize::max_value(), None),
+        }
+    }
+
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        use standback::convert::TryInto;
+
+        let julian_day = start.julian_day().checked_add(count.try_into().ok()?)?;
+        Self::try_from_julian_day(julian_day).ok()
+    }
+
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        use standback::convert::TryInto;
+
+        let julian_day = start.julian_day().checked_sub(count.try_into().ok()?)?;
+        Self::try_from_julian_day(julian_day).ok()
     }
 }
 
@@ -1071,6 +1660,8 @@ impl Ord for Date {
 mod test {
     use super::*;
     use crate::ext::{NumericalDuration, NumericalStdDuration};
+    #[cfg(not(feature = "std"))]
+    use alloc::vec;
 
     macro_rules! julian {
         ($julian:literal) => {
@@ -1130,6 +1721,67 @@ mod test {
         assert_eq!(days_in_year_month(2020, 12), 31);
     }
 
+    #[test]
+    fn days_in_year_month_matches_constructors() {
+        for &year in &[1900, 2000, 2019, 2020] {
+            let mut total = 0;
+            for month in 1..=12 {
+                let days = days_in_year_month(year, month);
+                assert!(Date::try_from_ymd(year, month, days).is_ok());
+                assert!(Date::try_from_ymd(year, month, days + 1).is_err());
+                assert_eq!(
+                    Date::try_from_ymd(year, month, days).map(Date::next_day),
+                    Date::try_from_ymd(year + (month == 12) as i32, month % 12 + 1, 1)
+                );
+                total += days as u16;
+            }
+            assert_eq!(total, crate::days_in_year(year));
+        }
+    }
+
+    #[test]
+    fn from_ymd_batch() -> crate::Result<()> {
+        assert_eq!(
+            Date::from_ymd_batch(&[(2019, 1, 1), (2020, 2, 29), (-100, 12, 31)]),
+            Ok(vec![
+                date!(2019-01-01),
+                date!(2020-02-29),
+                date!(-0100-12-31)
+            ])
+        );
+        assert_eq!(Date::from_ymd_batch(&[]), Ok(vec![]));
+
+        let (index, err) = match Date::from_ymd_batch(&[
+            (2019, 1, 1),
+            (2019, 1, 2),
+            (2019, 2, 29),
+            (2019, 0, 1),
+        ]) {
+            Err(err) => err,
+            Ok(dates) => panic!("expected an error, got {:?}", dates),
+        };
+        assert_eq!(index, 2);
+        assert_eq!(Some(err), Date::try_from_ymd(2019, 2, 29).err());
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_ymd_error_name() {
+        let name = |year, month, day| {
+            Date::try_from_ymd(year, month, day).map_err(error::ComponentRange::name)
+        };
+        assert_eq!(name(2019, 13, 1), Err("month"));
+        assert_eq!(name(2019, 0, 1), Err("month"));
+        // The month is reported even when the day is also invalid.
+        assert_eq!(name(2019, 13, 32), Err("month"));
+        assert_eq!(name(2019, 1, 32), Err("day"));
+        assert_eq!(name(2019, 1, 0), Err("day"));
+        assert_eq!(name(2020, 2, 30), Err("day"));
+        assert_eq!(name(2019, 2, 29), Err("day"));
+        assert_eq!(name(1_000_000, 1, 1), Err("year"));
+        assert!(name(2020, 2, 29).is_ok());
+    }
+
     // Test all dominical letters. For leap years, check the dates
     // immediately preceding and after the leap day.
 
@@ -1985,8 +2637,114 @@ mod test {
     }
 
     #[test]
-    fn week() -> crate::Result<()> {
-        assert_eq!(date!(2019-01-01).week(), 1);
+    fn iso_ywd_round_trip() -> crate::Result<()> {
+        let mut date = Date::try_from_ymd(-1000, 1, 1)?;
+        let end = Date::try_from_ymd(3000, 12, 31)?;
+        while date <= end {
+            let (year, week) = date.iso_year_week();
+            assert!(week >= 1 && week <= crate::weeks_in_year(year));
+            assert_eq!(Date::try_from_iso_ywd(year, week, date.weekday()), Ok(date));
+            assert_eq!(date.next_day().weekday(), date.weekday().next());
+            date = date.next_day();
+        }
+
+        // January 4 is a Sunday, so week 1 begins in the previous year.
+        assert_eq!(
+            Date::try_from_iso_ywd(2015, 1, Weekday::Monday),
+            Ok(date!(2014-12-29))
+        );
+        assert_eq!(date!(2014-12-29).iso_year_week(), (2015, 1));
+        // Leap days in negative years, matching 1996 four centuries later.
+        assert_eq!(date!(-0004-02-29).weekday(), Weekday::Thursday);
+        assert_eq!(date!(-0004-03-01).weekday(), Weekday::Friday);
+        Ok(())
+    }
+
+    #[test]
+    fn iso_week_date_string() -> crate::Result<()> {
+        // Week 53 of 2020 extends into 2021.
+        assert_eq!(date!(2021-01-01).to_iso_week_date_string(), "2020-W53-5");
+        assert_eq!(
+            Date::from_iso_week_date_str("2020-W53-5"),
+            Ok(date!(2021-01-01))
+        );
+        // Week 1 of 2020 begins in 2019.
+        assert_eq!(date!(2019-12-30).to_iso_week_date_string(), "2020-W01-1");
+        assert_eq!(
+            Date::from_iso_week_date_str("2020-W01-1"),
+            Ok(date!(2019-12-30))
+        );
+
+        for &date in &[date!(2020-12-31), date!(2021-01-04), date!(2026-06-15)] {
+            assert_eq!(
+                Date::from_iso_week_date_str(&date.to_iso_week_date_string()),
+                Ok(date)
+            );
+        }
+
+        assert!(Date::from_iso_week_date_str("2021-W53-1").is_err());
+        assert!(Date::from_iso_week_date_str("2020-53-5").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn weekday() -> crate::Result<()> {
+        use Weekday::*;
+
+        let known = [
+            (date!(-4713-11-24), Monday),
+            (date!(-2000-01-01), Saturday),
+            (date!(-0001-12-31), Friday),
+            (date!(0000-01-01), Saturday),
+            (date!(0001-01-01), Monday),
+            (date!(1582-10-15), Friday),
+            (date!(1600-01-01), Saturday),
+            (date!(1700-02-28), Sunday),
+            (date!(1752-09-14), Thursday),
+            (date!(1800-12-31), Wednesday),
+            (date!(1900-01-01), Monday),
+            (date!(1900-03-01), Thursday),
+            (date!(1969-07-20), Sunday),
+            (date!(2000-02-29), Tuesday),
+            (date!(2038-01-19), Tuesday),
+            (date!(2100-03-01), Monday),
+            (date!(2400-12-31), Sunday),
+            (date!(9999-12-31), Friday),
+        ];
+        for &(date, weekday) in &known {
+            assert_eq!(date.weekday(), weekday, "{}", date);
+        }
+
+        // The ends of the range are 400-year cycles from 2000.
+        assert_eq!(Date::try_from_ymd(MIN_YEAR, 1, 1)?.weekday(), Saturday);
+        assert_eq!(Date::try_from_ymd(MAX_YEAR, 1, 1)?.weekday(), Saturday);
+        Ok(())
+    }
+
+    #[test]
+    fn matches() -> crate::Result<()> {
+        let set: WeekdaySet = [Weekday::Monday, Weekday::Wednesday, Weekday::Friday]
+            .iter()
+            .cloned()
+            .collect();
+        let matching: Vec<_> = (1..=14)
+            .map(|day| Date::try_from_ymd(2019, 1, day))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|date| date.matches(&set))
+            .map(Date::day)
+            .collect();
+        assert_eq!(matching, [2, 4, 7, 9, 11, 14]);
+
+        assert!(date!(2019-01-05).matches(&WeekdaySet::weekends()));
+        assert!(!date!(2019-01-05).matches(&WeekdaySet::weekdays()));
+        assert!(!date!(2019-01-05).matches(&WeekdaySet::empty()));
+        Ok(())
+    }
+
+    #[test]
+    fn week() -> crate::Result<()> {
+        assert_eq!(date!(2019-01-01).week(), 1);
         assert_eq!(date!(2019-10-04).week(), 40);
         assert_eq!(date!(2020-01-01).week(), 1);
         assert_eq!(date!(2020-12-31).week(), 53);
@@ -1994,6 +2752,72 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn week_of_month() -> crate::Result<()> {
+        use Weekday::{Monday, Sunday};
+
+        // 2021-08-01 is a Sunday and 2021-08-31 is a Tuesday.
+        assert_eq!(date!(2021-08-01).week_of_month(Monday), 1);
+        assert_eq!(date!(2021-08-01).week_of_month(Sunday), 1);
+        assert_eq!(date!(2021-08-02).week_of_month(Monday), 2);
+        assert_eq!(date!(2021-08-02).week_of_month(Sunday), 1);
+        assert_eq!(date!(2021-08-31).week_of_month(Monday), 6);
+        assert_eq!(date!(2021-08-31).week_of_month(Sunday), 5);
+
+        // 2021-02-01 is a Monday and 2021-02-28 is a Sunday.
+        assert_eq!(date!(2021-02-01).week_of_month(Monday), 1);
+        assert_eq!(date!(2021-02-01).week_of_month(Sunday), 1);
+        assert_eq!(date!(2021-02-28).week_of_month(Monday), 4);
+        assert_eq!(date!(2021-02-28).week_of_month(Sunday), 5);
+
+        let mut date = date!(2020-01-01);
+        while date.year() == 2020 {
+            for &week_start in &[Monday, Sunday, Weekday::Thursday] {
+                let week = date.week_of_month(week_start);
+                assert_ne!(week, 0);
+                assert!(week <= 6);
+                let previous = date.previous_day();
+                if previous.month() == date.month() {
+                    let increments = date.weekday() == week_start;
+                    assert_eq!(week, previous.week_of_month(week_start) + increments as u8);
+                } else {
+                    assert_eq!(week, 1);
+                }
+            }
+            date = date.next_day();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn format_week_of_month() -> crate::Result<()> {
+        assert_eq!(date!(2021-08-31).format("%o %O"), "6 5");
+        assert_eq!(date!(2021-08-01).format("%o %O"), "1 1");
+
+        let mut date = date!(2021-01-01);
+        while date.year() == 2021 {
+            assert_eq!(
+                Date::parse(date.format("%Y-%m %o %a"), "%Y-%m %o %a"),
+                Ok(date)
+            );
+            assert_eq!(
+                Date::parse(date.format("%Y-%m %O %a"), "%Y-%m %O %a"),
+                Ok(date)
+            );
+            date = date.next_day();
+        }
+
+        // Week 1 of August 2021 does not contain a Monday when weeks begin on
+        // Monday, and week 6 does not contain a Wednesday.
+        assert!(Date::parse("2021-08 1 Mon", "%Y-%m %o %a").is_err());
+        assert!(Date::parse("2021-08 6 Wed", "%Y-%m %o %a").is_err());
+        assert_eq!(
+            Date::parse("2021-08 7 Mon", "%Y-%m %o %a"),
+            Err(crate::ParseError::InvalidWeek)
+        );
+        Ok(())
+    }
+
     #[test]
     fn as_ymd() -> crate::Result<()> {
         assert_eq!(date!(2019-01-02).as_ymd(), (2019, 1, 2));
@@ -2022,6 +2846,153 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn saturating_next_previous_day() -> crate::Result<()> {
+        assert_eq!(date!(2019-01-01).saturating_next_day(), date!(2019-01-02));
+        assert_eq!(date!(2020-02-28).saturating_next_day(), date!(2020-02-29));
+        assert_eq!(
+            date!(2019-03-01).saturating_previous_day(),
+            date!(2019-02-28)
+        );
+        assert_eq!(
+            date!(2021-01-01).saturating_previous_day(),
+            date!(2020-12-31)
+        );
+
+        let last = Date::try_from_ymd(MAX_YEAR, 12, 31)?;
+        assert_eq!(last.saturating_next_day(), last);
+        assert_eq!(
+            last.saturating_previous_day(),
+            Date::try_from_ymd(MAX_YEAR, 12, 30)?
+        );
+        let first = Date::try_from_ymd(MIN_YEAR, 1, 1)?;
+        assert_eq!(first.saturating_previous_day(), first);
+        assert_eq!(
+            first.saturating_next_day(),
+            Date::try_from_ymd(MIN_YEAR, 1, 2)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn checked_next_weekday() -> crate::Result<()> {
+        assert_eq!(
+            date!(2019-01-01).checked_next_weekday(Weekday::Wednesday),
+            Some(date!(2019-01-02))
+        );
+        assert_eq!(
+            date!(2019-01-01).checked_next_weekday(Weekday::Monday),
+            Some(date!(2019-01-07))
+        );
+        assert_eq!(
+            date!(2019-01-01).checked_next_weekday(Weekday::Tuesday),
+            Some(date!(2019-01-08))
+        );
+        assert_eq!(
+            date!(2019-12-30).checked_next_weekday(Weekday::Thursday),
+            Some(date!(2020-01-02))
+        );
+
+        let max = Date::try_from_ymd(MAX_YEAR, 12, 31)?;
+        assert_eq!(
+            max.previous_day().checked_next_weekday(max.weekday()),
+            Some(max)
+        );
+        assert_eq!(max.checked_next_weekday(max.weekday()), None);
+        assert_eq!(max.checked_next_weekday(max.weekday().next()), None);
+        assert_eq!(
+            max.previous_day()
+                .checked_next_weekday(max.weekday().next()),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn closest_weekday() -> crate::Result<()> {
+        // 2021-03-03 is a Wednesday.
+        let wednesday = date!(2021-03-03);
+        assert_eq!(
+            wednesday.closest_weekday(Weekday::Monday),
+            date!(2021-03-01)
+        );
+        assert_eq!(
+            wednesday.closest_weekday(Weekday::Tuesday),
+            date!(2021-03-02)
+        );
+        assert_eq!(
+            wednesday.closest_weekday(Weekday::Thursday),
+            date!(2021-03-04)
+        );
+        // Three days ahead beats four days behind, and vice versa.
+        assert_eq!(
+            wednesday.closest_weekday(Weekday::Saturday),
+            date!(2021-03-06)
+        );
+        assert_eq!(
+            wednesday.closest_weekday(Weekday::Sunday),
+            date!(2021-02-28)
+        );
+
+        // The previous and next Wednesdays are equally far away, so the date
+        // itself is closest.
+        assert_eq!(wednesday.closest_weekday(Weekday::Wednesday), wednesday);
+
+        let mut date = date!(2020-12-25);
+        while date.year() < 2021 {
+            for &weekday in &[Weekday::Monday, Weekday::Friday, Weekday::Sunday] {
+                let closest = date.closest_weekday(weekday);
+                assert_eq!(closest.weekday(), weekday);
+                assert!((closest - date).whole_days().abs() <= 3);
+            }
+            date = date.next_day();
+        }
+
+        let max = Date::try_from_ymd(MAX_YEAR, 12, 31)?;
+        assert_eq!(max.closest_weekday(max.weekday()), max);
+        assert_eq!(
+            max.closest_weekday(max.weekday().previous()),
+            max.previous_day()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn iter_weeks() -> crate::Result<()> {
+        assert_eq!(
+            date!(2019-12-17).iter_weeks().take(4).collect::<Vec<_>>(),
+            [
+                date!(2019-12-17),
+                date!(2019-12-24),
+                date!(2019-12-31),
+                date!(2020-01-07),
+            ]
+        );
+        // Leap days are stepped over like any other day.
+        assert_eq!(
+            date!(2020-02-25).iter_weeks().nth(1),
+            Some(date!(2020-03-03))
+        );
+
+        let last = Date::try_from_yo(MAX_YEAR, super::days_in_year(MAX_YEAR))?;
+        assert_eq!(last.iter_weeks().collect::<Vec<_>>(), [last]);
+        assert_eq!((last - 13.days()).iter_weeks().count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn weeks_between() -> crate::Result<()> {
+        assert_eq!(date!(2019-12-31).weeks_between(date!(2019-12-31)), 0);
+        assert_eq!(date!(2019-12-31).weeks_between(date!(2020-01-06)), 0);
+        assert_eq!(date!(2019-12-31).weeks_between(date!(2020-01-07)), 1);
+        assert_eq!(date!(2019-12-31).weeks_between(date!(2020-01-13)), 1);
+        assert_eq!(date!(2020-01-07).weeks_between(date!(2019-12-31)), -1);
+        assert_eq!(date!(2020-01-06).weeks_between(date!(2019-12-31)), 0);
+        assert_eq!(date!(2020-01-13).weeks_between(date!(2019-12-31)), -1);
+        assert_eq!(date!(2019-01-01).weeks_between(date!(2020-01-01)), 52);
+        Ok(())
+    }
+
     #[test]
     fn julian_day() -> crate::Result<()> {
         assert_eq!(date!(-4713-11-24).julian_day(), 0);
@@ -2037,6 +3008,66 @@ mod test {
         assert_eq!(julian!(2_451_545), date!(2000-01-01));
         assert_eq!(julian!(2_458_485), date!(2019-01-01));
         assert_eq!(julian!(2_458_849), date!(2019-12-31));
+
+        let min = Date::try_from_ymd(MIN_YEAR, 1, 1)?;
+        let max = Date::try_from_ymd(MAX_YEAR, 12, 31)?;
+        assert_eq!(min.julian_day(), MIN_JULIAN_DAY);
+        assert_eq!(max.julian_day(), MAX_JULIAN_DAY);
+        assert_eq!(Date::try_from_julian_day(MIN_JULIAN_DAY), Ok(min));
+        assert_eq!(Date::try_from_julian_day(MAX_JULIAN_DAY), Ok(max));
+        assert!(Date::try_from_julian_day(MIN_JULIAN_DAY - 1).is_err());
+        assert!(Date::try_from_julian_day(MAX_JULIAN_DAY + 1).is_err());
+        // Every date in the range round trips.
+        for year in (MIN_YEAR..=MAX_YEAR).step_by(997).chain(-4_800..=-4_700) {
+            for &(month, day) in &[(1, 1), (2, 28), (3, 1), (12, 31)] {
+                let date = Date::try_from_ymd(year, month, day)?;
+                assert_eq!(Date::try_from_julian_day(date.julian_day()), Ok(date));
+                if date != max {
+                    assert_eq!(date.next_day().julian_day(), date.julian_day() + 1);
+                }
+            }
+        }
+
+        // Values far out of range must not wrap around into the valid range.
+        assert!(Date::try_from_julian_day(1 << 40).is_err());
+        assert!(Date::try_from_julian_day(i64::min_value()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn days_since_epoch() -> crate::Result<()> {
+        for &(date, days) in &[
+            (date!(1970-01-01), 0),
+            (date!(1969-12-31), -1),
+            (date!(1900-01-01), -25_567),
+            (date!(9999-12-31), 2_932_896),
+        ] {
+            assert_eq!(date.to_days_since_epoch(), days);
+            assert_eq!(Date::from_days_since_epoch(days), Ok(date));
+        }
+
+        let min = Date::try_from_ymd(MIN_YEAR, 1, 1)?;
+        let max = Date::try_from_ymd(MAX_YEAR, 12, 31)?;
+        for &date in &[min, max] {
+            assert_eq!(
+                Date::from_days_since_epoch(date.to_days_since_epoch()),
+                Ok(date)
+            );
+        }
+        assert!(Date::from_days_since_epoch(min.to_days_since_epoch() - 1).is_err());
+        assert!(Date::from_days_since_epoch(max.to_days_since_epoch() + 1).is_err());
+        assert!(Date::from_days_since_epoch(i32::min_value()).is_err());
+
+        for &(a, b) in &[
+            (date!(2021-03-01), date!(1970-01-01)),
+            (date!(1969-07-20), date!(2019-07-20)),
+            (max, min),
+        ] {
+            assert_eq!(
+                (a.to_days_since_epoch() - b.to_days_since_epoch()) as i64,
+                (a - b).whole_days()
+            );
+        }
         Ok(())
     }
 
@@ -2148,6 +3179,29 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn format_expanded_year() -> crate::Result<()> {
+        assert_eq!(date!(10_000-01-01).format("%Y-%m-%d"), "+10000-01-01");
+        assert_eq!(date!(99_999-12-31).format("%F"), "+99999-12-31");
+        assert_eq!(date!(0-01-01).format("%Y-%m-%d"), "0000-01-01");
+        assert_eq!(date!(-1-12-31).format("%Y-%m-%d"), "-0001-12-31");
+        assert_eq!(date!(-1-12-31).format("%-Y"), "-1");
+        assert_eq!(date!(-12_345-01-01).format("%Y"), "-12345");
+
+        // Padding is applied to the digits alone, with spaces preceding the
+        // sign and zeros following it.
+        assert_eq!(date!(-1-06-15).format("%_Y"), "   -1");
+        assert_eq!(date!(-12-06-15).format("%_Y"), "  -12");
+        assert_eq!(date!(1-06-15).format("%_Y"), "   1");
+        assert_eq!(date!(-1-06-15).format("%G"), "-0001");
+        assert_eq!(date!(-1-06-15).format("%_G"), "   -1");
+        assert_eq!(date!(-1-06-15).format("%-G"), "-1");
+        assert_eq!(date!(-123-06-15).format("%-G"), "-123");
+        assert_eq!(date!(10_000-06-15).format("%-G"), "+10000");
+        assert_eq!(date!(10_000-06-15).format("%_G"), "+10000");
+        Ok(())
+    }
+
     #[test]
     fn parse() -> crate::Result<()> {
         assert_eq!(Date::parse("2019-01-02", "%F"), Ok(date!(2019-01-02)));
@@ -2157,6 +3211,101 @@ mod test {
         assert_eq!(Date::parse("-1234-01-02", "%F"), Ok(date!(-1234-01-02)));
         assert_eq!(Date::parse("-12345-01-02", "%F"), Ok(date!(-12345-01-02)));
         assert!(Date::parse("-123456-01-02", "%F").is_err());
+        assert_eq!(Date::parse("  -12-001", "%_Y-%j"), Ok(date!(-12-001)));
+        assert_eq!(
+            Date::parse("   -1-W24-1", "%_G-W%V-%u"),
+            Ok(date!(-1-06-14))
+        );
+        assert_eq!(Date::parse("-1-W24-1", "%-G-W%V-%u"), Ok(date!(-1-06-14)));
+        assert_eq!(date!(-1-06-14).format("%_G-W%V-%u"), "   -1-W24-1");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_duplicate_specifiers() -> crate::Result<()> {
+        // Repeated components are allowed when their values agree.
+        assert_eq!(
+            Date::parse("2021-2021-005", "%Y-%Y-%j"),
+            Ok(date!(2021-005))
+        );
+        assert_eq!(
+            Date::parse("2021-01-05 2021", "%F %Y"),
+            Ok(date!(2021-01-05))
+        );
+
+        assert_eq!(
+            Date::parse("2021-2022-005", "%Y-%Y-%j"),
+            Err(crate::ParseError::ConflictingComponent("year"))
+        );
+        assert_eq!(
+            Date::parse("2021-01-05 06", "%F %d"),
+            Err(crate::ParseError::ConflictingComponent("day of month"))
+        );
+
+        // Partial years are combined with the full year.
+        assert_eq!(Date::parse("20 21-005", "%C %y-%j"), Ok(date!(2021-005)));
+        Ok(())
+    }
+
+    #[test]
+    fn space_padded_day() -> crate::Result<()> {
+        assert_eq!(date!(2021-01-05).format("%e"), " 5");
+        assert_eq!(date!(2021-01-25).format("%e"), "25");
+        assert_eq!(date!(2021-01-05).format("%b %e %Y"), "Jan  5 2021");
+        assert_eq!(date!(2021-01-05).format("%-e"), "5");
+        assert_eq!(date!(2021-01-05).format("%0e"), "05");
+
+        assert_eq!(Date::parse("2021-01- 5", "%Y-%m-%e"), Ok(date!(2021-01-05)));
+        assert_eq!(Date::parse("2021-01-05", "%Y-%m-%e"), Ok(date!(2021-01-05)));
+        assert_eq!(Date::parse("2021-01-5", "%Y-%m-%e"), Ok(date!(2021-01-05)));
+        assert_eq!(Date::parse("2021-01-25", "%Y-%m-%e"), Ok(date!(2021-01-25)));
+        assert_eq!(
+            Date::parse("Jan  5 2021", "%b %e %Y"),
+            Ok(date!(2021-01-05))
+        );
+        assert_eq!(
+            Date::parse("2021-01-00", "%Y-%m-%e"),
+            Err(crate::ParseError::InvalidDayOfMonth)
+        );
+        assert_eq!(
+            Date::parse("2021-01-  5", "%Y-%m-%e"),
+            Err(crate::ParseError::InvalidDayOfMonth)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ordinal_date() -> crate::Result<()> {
+        assert_eq!(date!(2021-015).format("%Y-%j"), "2021-015");
+        assert_eq!(date!(2021-001).format("%Y%j"), "2021001");
+        assert_eq!(date!(2020-366).format("%Y-%j"), "2020-366");
+
+        assert_eq!(Date::parse("2021-015", "%Y-%j"), Ok(date!(2021-015)));
+        assert_eq!(Date::parse("2021015", "%Y%j"), Ok(date!(2021-015)));
+        assert_eq!(Date::parse("2020-366", "%Y-%j"), Ok(date!(2020-12-31)));
+        assert!(Date::parse("2021-366", "%Y-%j").is_err());
+        assert_eq!(
+            Date::parse("2021-000", "%Y-%j"),
+            Err(crate::ParseError::InvalidDayOfYear)
+        );
+        assert_eq!(
+            Date::parse("2021-15", "%Y-%j"),
+            Err(crate::ParseError::InvalidDayOfYear)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_expanded_year() -> crate::Result<()> {
+        assert_eq!(
+            Date::parse("+10000-01-01", "%Y-%m-%d"),
+            Ok(date!(10_000-01-01))
+        );
+        assert_eq!(Date::parse("-0001-12-31", "%Y-%m-%d"), Ok(date!(-1-12-31)));
+
+        for &date in &[date!(12_345-06-07), date!(-1-12-31), date!(-12_345-01-01)] {
+            assert_eq!(Date::parse(date.format("%Y-%m-%d"), "%Y-%m-%d"), Ok(date));
+        }
         Ok(())
     }
 
@@ -2167,6 +3316,46 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn format_width() -> crate::Result<()> {
+        assert_eq!(date!(987-06-05).format("%04Y"), "0987");
+        assert_eq!(date!(987-06-05).format("%4Y"), "0987");
+        assert_eq!(date!(5-06-05).format("%04Y-%m-%d"), "0005-06-05");
+        assert_eq!(date!(12_345-01-01).format("%04Y"), "+12345");
+        assert_eq!(date!(2019-06-05).format("%06Y"), "002019");
+        assert_eq!(date!(-1-06-05).format("%05Y"), "-00001");
+        assert_eq!(date!(-12_345-06-05).format("%04Y"), "-12345");
+        assert_eq!(date!(2019-06-05).format("%03d"), "005");
+
+        assert!(crate::util::validate_format_string("%-04Y").is_err());
+        assert!(crate::util::validate_format_string("%00Y").is_err());
+        assert!(crate::util::validate_format_string("%04A").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_width() -> crate::Result<()> {
+        assert_eq!(
+            Date::parse("0987-06-05", "%04Y-%m-%d"),
+            Ok(date!(987-06-05))
+        );
+        assert_eq!(Date::parse("002019-156", "%06Y-%j"), Ok(date!(2019-156)));
+        assert_eq!(Date::parse("2019-0156", "%Y-%04j"), Ok(date!(2019-156)));
+        assert!(Date::parse("987-06-05", "%04Y-%m-%d").is_err());
+
+        // The sign and digit limits are the same as without a width.
+        assert_eq!(Date::parse("-00001-156", "%05Y-%j"), Ok(date!(-1-156)));
+        assert_eq!(Date::parse("+12345-156", "%04Y-%j"), Ok(date!(12_345-156)));
+        assert_eq!(Date::parse("-00000012-156", "%08Y-%j"), Ok(date!(-12-156)));
+        assert!(Date::parse("-0001-156", "%05Y-%j").is_err());
+        assert!(Date::parse("12345-156", "%05Y-%j").is_err());
+        assert!(Date::parse("-1234567-156", "%07Y-%j").is_err());
+        for &date in &[date!(-1-156), date!(-12_345-156), date!(12_345-156)] {
+            assert_eq!(Date::parse(date.format("%05Y-%j"), "%05Y-%j"), Ok(date));
+        }
+        Ok(())
+    }
+
     #[test]
     fn display() -> crate::Result<()> {
         assert_eq!(date!(2019-01-01).to_string(), "2019-01-01");
@@ -2180,9 +3369,44 @@ mod test {
     fn add() -> crate::Result<()> {
         assert_eq!(date!(2019-01-01) + 5.days(), date!(2019-01-06));
         assert_eq!(date!(2019-12-31) + 1.days(), date!(2020-01-01));
+
+        // Only whole days are added, truncating toward zero.
+        assert_eq!(date!(2019-01-01) + 1.5.days(), date!(2019-01-02));
+        assert_eq!(date!(2019-01-01) + (-1.5).days(), date!(2018-12-31));
+        assert_eq!(date!(2019-01-01) + 23.hours(), date!(2019-01-01));
+        assert_eq!(date!(2019-01-01) - 1.5.days(), date!(2018-12-31));
+        assert_eq!(date!(2019-01-01) - (-1.5).days(), date!(2019-01-02));
+        Ok(())
+    }
+
+    #[test]
+    fn checked_add_sub() -> crate::Result<()> {
+        let max = Date::try_from_ymd(MAX_YEAR, 12, 31)?;
+        let min = Date::try_from_ymd(MIN_YEAR, 1, 1)?;
+
+        assert_eq!(
+            date!(2019-01-01).checked_add(1.5.days()),
+            Some(date!(2019-01-02))
+        );
+        assert_eq!(
+            date!(2019-01-01).checked_sub(1.days()),
+            Some(date!(2018-12-31))
+        );
+        assert_eq!(max.checked_add(1.days()), None);
+        assert_eq!(max.checked_add(23.hours()), Some(max));
+        assert_eq!(min.checked_sub(1.days()), None);
+        assert_eq!(min.checked_add((-1).days()), None);
+        assert_eq!(min.checked_sub(Duration::min_value()), None);
+        assert_eq!(max.checked_sub(Duration::max_value()), None);
         Ok(())
     }
 
+    #[test]
+    #[should_panic(expected = "resulting value is out of range")]
+    fn add_overflow() {
+        let _ = Date::try_from_ymd(MAX_YEAR, 12, 31).map(|date| date + 1.days());
+    }
+
     #[test]
     fn add_std() -> crate::Result<()> {
         assert_eq!(date!(2019-01-01) + 5.std_days(), date!(2019-01-06));
@@ -2240,9 +3464,196 @@ mod test {
     fn sub_self() -> crate::Result<()> {
         assert_eq!(date!(2019-01-06) - date!(2019-01-01), 5.days());
         assert_eq!(date!(2020-01-01) - date!(2019-12-31), 1.days());
+        assert_eq!(date!(2019-01-01) - date!(2019-01-06), (-5).days());
+        let (earlier, later) = (date!(1999-03-04), date!(2024-11-02));
+        assert_eq!(earlier + (later - earlier), later);
+        assert_eq!(later - earlier, -(earlier - later));
+        Ok(())
+    }
+
+    #[test]
+    fn month_day_ordinal_sweep() -> crate::Result<()> {
+        for &year in &[2019, 2020] {
+            let mut ordinal = 0;
+            for month in 1..=12 {
+                for day in 1..=days_in_year_month(year, month) {
+                    ordinal += 1;
+                    let date = Date::try_from_ymd(year, month, day)?;
+                    assert_eq!(date.ordinal(), ordinal);
+                    assert_eq!(date.month_day(), (month, day));
+                    assert_eq!(Date::try_from_yo(year, ordinal)?, date);
+                }
+            }
+            assert_eq!(ordinal, crate::days_in_year(year));
+        }
         Ok(())
     }
 
+    #[test]
+    fn calendar_difference() -> crate::Result<()> {
+        let diff = date!(2021-06-15).calendar_difference(date!(2020-02-10));
+        assert_eq!((diff.years(), diff.months(), diff.days()), (1, 4, 5));
+
+        let diff = date!(2020-01-01).calendar_difference(date!(2020-01-01));
+        assert_eq!(diff, CalendarDuration::ZERO);
+        Ok(())
+    }
+
+    #[test]
+    fn calendar_difference_borrow() -> crate::Result<()> {
+        // Day borrow, using the length of January.
+        let diff = date!(2019-03-01).calendar_difference(date!(2019-01-31));
+        assert_eq!((diff.years(), diff.months(), diff.days()), (0, 1, 1));
+        // Day borrow, using the length of February in a leap year.
+        let diff = date!(2020-03-01).calendar_difference(date!(2020-02-28));
+        assert_eq!((diff.years(), diff.months(), diff.days()), (0, 0, 2));
+        // Day and month borrow across a year boundary, using the length of
+        // November.
+        let diff = date!(2020-01-05).calendar_difference(date!(2019-11-20));
+        assert_eq!((diff.years(), diff.months(), diff.days()), (0, 1, 15));
+        // Month borrow only.
+        let diff = date!(2021-02-20).calendar_difference(date!(2020-11-20));
+        assert_eq!((diff.years(), diff.months(), diff.days()), (0, 3, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn calendar_difference_reversed() -> crate::Result<()> {
+        let pairs = [
+            (date!(2021-06-15), date!(2020-02-10)),
+            (date!(2019-03-01), date!(2019-01-31)),
+            (date!(2020-01-05), date!(2019-11-20)),
+        ];
+
+        for &(later, earlier) in &pairs {
+            let forward = later.calendar_difference(earlier);
+            let backward = earlier.calendar_difference(later);
+            assert!(!forward.is_negative());
+            assert!(backward.is_negative());
+            assert_eq!(backward, -forward);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn first_of_month() -> crate::Result<()> {
+        assert_eq!(date!(2019-01-31).first_of_month(), date!(2019-01-01));
+        assert_eq!(date!(2019-12-01).first_of_month(), date!(2019-12-01));
+        assert_eq!(date!(2020-02-29).first_of_month(), date!(2020-02-01));
+        Ok(())
+    }
+
+    #[test]
+    fn month_grid() -> crate::Result<()> {
+        // March 2020 starts on a Sunday.
+        let grid = Date::month_grid(2020, 3, Weekday::Monday)?;
+        assert_eq!(grid.len(), 42);
+        assert_eq!(grid.iter().take_while(|cell| cell.is_none()).count(), 6);
+        assert_eq!(grid[6], Some(date!(2020-03-01)));
+        assert_eq!(grid[36], Some(date!(2020-03-31)));
+        assert_eq!(grid.iter().filter(|cell| cell.is_some()).count(), 31);
+
+        let grid = Date::month_grid(2020, 3, Weekday::Sunday)?;
+        assert_eq!(grid.len(), 35);
+        assert_eq!(grid[0], Some(date!(2020-03-01)));
+
+        // February 2020 starts on a Saturday.
+        let grid = Date::month_grid(2020, 2, Weekday::Monday)?;
+        assert_eq!(grid.len(), 35);
+        assert_eq!(grid.iter().take_while(|cell| cell.is_none()).count(), 5);
+        assert_eq!(grid[5], Some(date!(2020-02-01)));
+        assert_eq!(grid[33], Some(date!(2020-02-29)));
+        assert_eq!(grid[34], None);
+
+        let grid = Date::month_grid(2020, 2, Weekday::Sunday)?;
+        assert_eq!(grid.len(), 35);
+        assert_eq!(grid.iter().take_while(|cell| cell.is_none()).count(), 6);
+
+        // Every row starts on the requested weekday.
+        for (i, cell) in grid.iter().enumerate() {
+            if let Some(date) = cell {
+                assert_eq!(date.weekday().number_days_from_sunday() as usize, i % 7);
+            }
+        }
+
+        // February 2015 fits in exactly four weeks.
+        assert_eq!(Date::month_grid(2015, 2, Weekday::Sunday)?.len(), 28);
+
+        assert!(Date::month_grid(2020, 13, Weekday::Monday).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn add_months_with_policy() -> crate::Result<()> {
+        use MonthAddPolicy::{ClampDay, PreserveEndOfMonth};
+
+        assert_eq!(
+            date!(2019-01-31).add_months_with_policy(1, ClampDay),
+            Some(date!(2019-02-28))
+        );
+        assert_eq!(
+            date!(2020-01-31).add_months_with_policy(1, PreserveEndOfMonth),
+            Some(date!(2020-02-29))
+        );
+        assert_eq!(
+            date!(2019-02-28).add_months_with_policy(1, ClampDay),
+            Some(date!(2019-03-28))
+        );
+        assert_eq!(
+            date!(2019-02-28).add_months_with_policy(1, PreserveEndOfMonth),
+            Some(date!(2019-03-31))
+        );
+        assert_eq!(
+            date!(2019-04-15).add_months_with_policy(-4, PreserveEndOfMonth),
+            Some(date!(2018-12-15))
+        );
+        assert_eq!(
+            date!(2019-04-30).add_months_with_policy(24, PreserveEndOfMonth),
+            Some(date!(2021-04-30))
+        );
+        assert_eq!(
+            date!(2019-01-01).add_months_with_policy(0, ClampDay),
+            Some(date!(2019-01-01))
+        );
+        assert_eq!(
+            Date::try_from_ymd(MAX_YEAR, 12, 1)?.add_months_with_policy(1, ClampDay),
+            None
+        );
+        assert_eq!(
+            Date::try_from_ymd(MIN_YEAR, 1, 1)?.add_months_with_policy(-1, ClampDay),
+            None
+        );
+        assert_eq!(
+            date!(2019-01-01).add_months_with_policy(i32::min_value(), ClampDay),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ord_matches_year_ordinal() {
+        let years = (MIN_YEAR..=MAX_YEAR)
+            .step_by(9_973)
+            .chain(-2..=2)
+            .chain(MAX_YEAR - 1..=MAX_YEAR);
+        let ordinals: &[u16] = &[1, 2, 59, 60, 200, 365, 366];
+        let dates = || {
+            years.clone().flat_map(move |year| {
+                ordinals
+                    .iter()
+                    .filter_map(move |&ordinal| Date::try_from_yo(year, ordinal).ok())
+            })
+        };
+
+        for a in dates().step_by(7) {
+            for b in dates() {
+                let naive = (a.year(), a.ordinal()).cmp(&(b.year(), b.ordinal()));
+                assert_eq!(a.cmp(&b), naive);
+                assert_eq!(a.to_ordinal_value().cmp(&b.to_ordinal_value()), naive);
+            }
+        }
+    }
+
     #[test]
     fn partial_ord() -> crate::Result<()> {
         let first = date!(2019-01-01);
@@ -2264,4 +3675,35 @@ mod test {
         assert_eq!(second.cmp(&first), Ordering::Greater);
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "step")]
+    fn step() -> crate::Result<()> {
+        let mut dates = date!(2021-01-30)..=date!(2021-02-02);
+        assert_eq!(dates.next(), Some(date!(2021-01-30)));
+        assert_eq!(dates.next(), Some(date!(2021-01-31)));
+        assert_eq!(dates.next(), Some(date!(2021-02-01)));
+        assert_eq!(dates.next(), Some(date!(2021-02-02)));
+        assert_eq!(dates.next(), None);
+
+        assert_eq!((date!(2021-02-02)..=date!(2021-01-30)).count(), 0);
+        assert_eq!(
+            (date!(2020-01-01)..date!(2021-01-01)).size_hint(),
+            (366, Some(366))
+        );
+        assert_eq!(
+            (date!(2021-01-01)..=date!(2021-01-31)).rev().nth(1),
+            Some(date!(2021-01-30))
+        );
+
+        let last = Date::from_julian_day(MAX_JULIAN_DAY);
+        let first = Date::from_julian_day(MIN_JULIAN_DAY);
+        assert_eq!(core::iter::Step::forward_checked(last, 1), None);
+        assert_eq!(core::iter::Step::backward_checked(first, 1), None);
+        assert_eq!(
+            core::iter::Step::forward_checked(first, usize::max_value()),
+            None
+        );
+        Ok(())
+    }
 }
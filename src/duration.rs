@@ -4,6 +4,7 @@ use crate::Instant;
 use const_fn::const_fn;
 use core::{
     cmp::Ordering,
+    fmt::{self, Display},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     time::Duration as StdDuration,
 };
@@ -47,6 +48,27 @@ const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
 /// The number of seconds in one week.
 const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
 
+/// A unit of time, as yielded by [`Duration::components`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DurationUnit {
+    #[allow(clippy::missing_docs_in_private_items)]
+    Weeks,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Days,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Hours,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Minutes,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Seconds,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Milliseconds,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Microseconds,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Nanoseconds,
+}
+
 impl Duration {
     /// Equivalent to `0.seconds()`.
     ///
@@ -189,6 +211,93 @@ impl Duration {
         (self.seconds > 0) | (self.nanoseconds > 0)
     }
 
+    /// Compare two durations by their signed magnitude, so that negative
+    /// durations order before zero and positive ones. This is equivalent to
+    /// [`Ord::cmp`], and is provided to parallel `f64::total_cmp` when
+    /// sorting durations converted from floating point values.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// # use core::cmp::Ordering;
+    /// let mut durations = [1.5.seconds(), (-2).seconds(), 0.seconds(), (-0.5).seconds()];
+    /// durations.sort_unstable_by(|a, b| a.total_cmp(b));
+    /// assert_eq!(
+    ///     durations,
+    ///     [(-2).seconds(), (-0.5).seconds(), 0.seconds(), 1.5.seconds()]
+    /// );
+    /// assert_eq!((-1).seconds().total_cmp(&1.nanoseconds()), Ordering::Less);
+    /// ```
+    #[allow(clippy::trivially_copy_pass_by_ref)] // matches `f64::total_cmp`
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+
+    /// Get the smaller of two durations, comparing by signed magnitude.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(1.seconds().min(2.seconds()), 1.seconds());
+    /// assert_eq!(1.seconds().min((-2).seconds()), (-2).seconds());
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn min(self, other: Self) -> Self {
+        if self.const_le(other) {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Get the larger of two durations, comparing by signed magnitude.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(1.seconds().max(2.seconds()), 2.seconds());
+    /// assert_eq!(1.seconds().max((-2).seconds()), 1.seconds());
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn max(self, other: Self) -> Self {
+        if self.const_le(other) {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Restrict the duration to the range `min..=max`, returning whichever
+    /// bound is exceeded, if any.
+    ///
+    /// ```rust
+    /// # use time::prelude::*;
+    /// assert_eq!(5.seconds().clamp(1.seconds(), 3.seconds()), 3.seconds());
+    /// assert_eq!((-5).seconds().clamp((-1).seconds(), 3.seconds()), (-1).seconds());
+    /// assert_eq!(2.seconds().clamp(1.seconds(), 3.seconds()), 2.seconds());
+    /// ```
+    ///
+    /// Panics if `min` is greater than `max`.
+    ///
+    /// ```rust,should_panic
+    /// # use time::prelude::*;
+    /// 2.seconds().clamp(3.seconds(), 1.seconds());
+    /// ```
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "`min` must be less than or equal to `max`");
+        self.max(min).min(max)
+    }
+
+    /// Whether `self` is less than or equal to `other`, usable in a `const fn`.
+    ///
+    /// This is correct because the sign of the nanoseconds always matches the
+    /// sign of the seconds.
+    const fn const_le(self, other: Self) -> bool {
+        (self.seconds < other.seconds)
+            | ((self.seconds == other.seconds) & (self.nanoseconds <= other.nanoseconds))
+    }
+
     /// Get the sign of the duration.
     ///
     /// ```rust
@@ -275,6 +384,18 @@ impl Duration {
         Self::seconds(weeks * SECONDS_PER_WEEK)
     }
 
+    /// Create a new `Duration` with the given number of weeks, returning
+    /// `None` if the number of seconds would overflow.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::checked_weeks(1), Some(1.weeks()));
+    /// assert_eq!(Duration::checked_weeks(i64::max_value()), None);
+    /// ```
+    pub fn checked_weeks(weeks: i64) -> Option<Self> {
+        weeks.checked_mul(SECONDS_PER_WEEK).map(Self::seconds)
+    }
+
     /// Get the number of whole weeks in the duration.
     ///
     /// ```rust
@@ -299,6 +420,18 @@ impl Duration {
         Self::seconds(days * SECONDS_PER_DAY)
     }
 
+    /// Create a new `Duration` with the given number of days, returning
+    /// `None` if the number of seconds would overflow.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::checked_days(1), Some(1.days()));
+    /// assert_eq!(Duration::checked_days(i64::max_value()), None);
+    /// ```
+    pub fn checked_days(days: i64) -> Option<Self> {
+        days.checked_mul(SECONDS_PER_DAY).map(Self::seconds)
+    }
+
     /// Get the number of whole days in the duration.
     ///
     /// ```rust
@@ -323,6 +456,18 @@ impl Duration {
         Self::seconds(hours * SECONDS_PER_HOUR)
     }
 
+    /// Create a new `Duration` with the given number of hours, returning
+    /// `None` if the number of seconds would overflow.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::checked_hours(1), Some(1.hours()));
+    /// assert_eq!(Duration::checked_hours(i64::max_value()), None);
+    /// ```
+    pub fn checked_hours(hours: i64) -> Option<Self> {
+        hours.checked_mul(SECONDS_PER_HOUR).map(Self::seconds)
+    }
+
     /// Get the number of whole hours in the duration.
     ///
     /// ```rust
@@ -347,6 +492,18 @@ impl Duration {
         Self::seconds(minutes * SECONDS_PER_MINUTE)
     }
 
+    /// Create a new `Duration` with the given number of minutes, returning
+    /// `None` if the number of seconds would overflow.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::checked_minutes(1), Some(1.minutes()));
+    /// assert_eq!(Duration::checked_minutes(i64::max_value()), None);
+    /// ```
+    pub fn checked_minutes(minutes: i64) -> Option<Self> {
+        minutes.checked_mul(SECONDS_PER_MINUTE).map(Self::seconds)
+    }
+
     /// Get the number of whole minutes in the duration.
     ///
     /// ```rust
@@ -360,7 +517,8 @@ impl Duration {
         self.whole_seconds() / SECONDS_PER_MINUTE
     }
 
-    /// Create a new `Duration` with the given number of seconds.
+    /// Create a new `Duration` with the given number of seconds. Unlike the
+    /// constructors for larger units, this cannot overflow.
     ///
     /// ```rust
     /// # use time::{Duration, prelude::*};
@@ -373,6 +531,22 @@ impl Duration {
         }
     }
 
+    /// Create a new `Duration` with the given number of seconds. Every `i64`
+    /// is representable, so this always returns `Some`; it is provided for
+    /// consistency with the other checked constructors.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::checked_seconds(1), Some(1.seconds()));
+    /// assert_eq!(
+    ///     Duration::checked_seconds(i64::max_value()),
+    ///     Some(Duration::max_value() - 999_999_999.nanoseconds())
+    /// );
+    /// ```
+    pub const fn checked_seconds(seconds: i64) -> Option<Self> {
+        Some(Self::seconds(seconds))
+    }
+
     /// Get the number of whole seconds in the duration.
     ///
     /// ```rust
@@ -389,16 +563,56 @@ impl Duration {
     /// Creates a new `Duration` from the specified number of seconds
     /// represented as `f64`.
     ///
+    /// Values outside the range of `Duration` saturate, with positive
+    /// infinity becoming [`Duration::max_value`] and negative infinity
+    /// becoming [`Duration::min_value`]. `NaN` is treated as zero. Use
+    /// [`Duration::try_from_seconds_f64`] to detect these cases instead.
+    ///
     /// ```rust
     /// # use time::{Duration, prelude::*};
     /// assert_eq!(Duration::seconds_f64(0.5), 0.5.seconds());
     /// assert_eq!(Duration::seconds_f64(-0.5), -0.5.seconds());
+    /// assert_eq!(Duration::seconds_f64(std::f64::INFINITY), Duration::max_value());
+    /// assert_eq!(Duration::seconds_f64(std::f64::NEG_INFINITY), Duration::min_value());
+    /// assert_eq!(Duration::seconds_f64(std::f64::NAN), Duration::zero());
     /// ```
     pub fn seconds_f64(seconds: f64) -> Self {
-        Self {
+        if seconds.is_nan() {
+            return Self::zero();
+        }
+        Self::try_from_seconds_f64(seconds).unwrap_or_else(|_| {
+            if seconds > 0. {
+                Self::max_value()
+            } else {
+                Self::min_value()
+            }
+        })
+    }
+
+    /// Attempt to create a new `Duration` from the specified number of
+    /// seconds represented as `f64`, returning an error if the value is
+    /// `NaN`, infinite, or otherwise outside the range of `Duration`.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::try_from_seconds_f64(0.5), Ok(0.5.seconds()));
+    /// assert!(Duration::try_from_seconds_f64(std::f64::NAN).is_err());
+    /// assert!(Duration::try_from_seconds_f64(std::f64::INFINITY).is_err());
+    /// assert!(Duration::try_from_seconds_f64(std::f64::NEG_INFINITY).is_err());
+    /// ```
+    pub fn try_from_seconds_f64(seconds: f64) -> Result<Self, error::ConversionRange> {
+        // `NaN` fails both comparisons. The upper bound is exclusive, as it
+        // is rounded up to 2^63 when converted.
+        #[allow(clippy::manual_range_contains)]
+        let in_range = seconds >= i64::min_value() as f64 && seconds < i64::max_value() as f64;
+        if !in_range {
+            return Err(error::ConversionRange::new());
+        }
+
+        Ok(Self {
             seconds: seconds as i64,
             nanoseconds: ((seconds % 1.) * 1_000_000_000.) as i32,
-        }
+        })
     }
 
     /// Get the number of fractional seconds in the duration.
@@ -415,16 +629,56 @@ impl Duration {
     /// Creates a new `Duration` from the specified number of seconds
     /// represented as `f32`.
     ///
+    /// Values outside the range of `Duration` saturate, with positive
+    /// infinity becoming [`Duration::max_value`] and negative infinity
+    /// becoming [`Duration::min_value`]. `NaN` is treated as zero. Use
+    /// [`Duration::try_from_seconds_f32`] to detect these cases instead.
+    ///
     /// ```rust
     /// # use time::{Duration, prelude::*};
     /// assert_eq!(Duration::seconds_f32(0.5), 0.5.seconds());
     /// assert_eq!(Duration::seconds_f32(-0.5), (-0.5).seconds());
+    /// assert_eq!(Duration::seconds_f32(std::f32::INFINITY), Duration::max_value());
+    /// assert_eq!(Duration::seconds_f32(std::f32::NEG_INFINITY), Duration::min_value());
+    /// assert_eq!(Duration::seconds_f32(std::f32::NAN), Duration::zero());
     /// ```
     pub fn seconds_f32(seconds: f32) -> Self {
-        Self {
+        if seconds.is_nan() {
+            return Self::zero();
+        }
+        Self::try_from_seconds_f32(seconds).unwrap_or_else(|_| {
+            if seconds > 0. {
+                Self::max_value()
+            } else {
+                Self::min_value()
+            }
+        })
+    }
+
+    /// Attempt to create a new `Duration` from the specified number of
+    /// seconds represented as `f32`, returning an error if the value is
+    /// `NaN`, infinite, or otherwise outside the range of `Duration`.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::try_from_seconds_f32(0.5), Ok(0.5.seconds()));
+    /// assert!(Duration::try_from_seconds_f32(std::f32::NAN).is_err());
+    /// assert!(Duration::try_from_seconds_f32(std::f32::INFINITY).is_err());
+    /// assert!(Duration::try_from_seconds_f32(std::f32::NEG_INFINITY).is_err());
+    /// ```
+    pub fn try_from_seconds_f32(seconds: f32) -> Result<Self, error::ConversionRange> {
+        // `NaN` fails both comparisons. The upper bound is exclusive, as it
+        // is rounded up to 2^63 when converted.
+        #[allow(clippy::manual_range_contains)]
+        let in_range = seconds >= i64::min_value() as f32 && seconds < i64::max_value() as f32;
+        if !in_range {
+            return Err(error::ConversionRange::new());
+        }
+
+        Ok(Self {
             seconds: seconds as i64,
             nanoseconds: ((seconds % 1.) * 1_000_000_000.) as i32,
-        }
+        })
     }
 
     /// Get the number of fractional seconds in the duration.
@@ -452,6 +706,23 @@ impl Duration {
         }
     }
 
+    /// Create a new `Duration` with the given number of milliseconds. Every
+    /// `i64` is representable, as milliseconds are divided into whole seconds
+    /// rather than multiplied, so this always returns `Some`; it is provided
+    /// for consistency with the other checked constructors.
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(Duration::checked_milliseconds(1), Some(1.milliseconds()));
+    /// assert_eq!(
+    ///     Duration::checked_milliseconds(i64::max_value()).map(Duration::whole_milliseconds),
+    ///     Some(i64::max_value() as i128)
+    /// );
+    /// ```
+    pub const fn checked_milliseconds(milliseconds: i64) -> Option<Self> {
+        Some(Self::milliseconds(milliseconds))
+    }
+
     /// Get the number of whole milliseconds in the duration.
     ///
     /// ```rust
@@ -571,6 +842,86 @@ impl Duration {
         self.nanoseconds
     }
 
+    /// Break the duration down into its non-zero components, from weeks down
+    /// to nanoseconds. Each component has the same sign as the duration.
+    ///
+    /// ```rust
+    /// # use time::{DurationUnit, prelude::*};
+    /// let mut components = (8.days() + 3.minutes() + 5.milliseconds()).components();
+    /// assert_eq!(components.next(), Some((DurationUnit::Weeks, 1)));
+    /// assert_eq!(components.next(), Some((DurationUnit::Days, 1)));
+    /// assert_eq!(components.next(), Some((DurationUnit::Minutes, 3)));
+    /// assert_eq!(components.next(), Some((DurationUnit::Milliseconds, 5)));
+    /// assert_eq!(components.next(), None);
+    /// ```
+    pub fn components(self) -> impl Iterator<Item = (DurationUnit, i64)> {
+        let nanoseconds = self.nanoseconds as i64;
+        let components = [
+            (DurationUnit::Weeks, self.whole_weeks()),
+            (DurationUnit::Days, self.whole_days() % 7),
+            (DurationUnit::Hours, self.whole_hours() % 24),
+            (DurationUnit::Minutes, self.whole_minutes() % 60),
+            (DurationUnit::Seconds, self.seconds % 60),
+            (DurationUnit::Milliseconds, nanoseconds / 1_000_000),
+            (DurationUnit::Microseconds, nanoseconds / 1_000 % 1_000),
+            (DurationUnit::Nanoseconds, nanoseconds % 1_000),
+        ];
+
+        (0..components.len())
+            .map(move |i| components[i])
+            .filter(|&(_, value)| value != 0)
+    }
+
+    /// Split the duration into the whole number of `unit`s it contains and
+    /// the remainder, which is always smaller than one `unit`. Both parts have
+    /// the same sign as the duration.
+    ///
+    /// This is useful for displaying a duration as a (possibly fractional)
+    /// number of a single unit, such as "1.5 hours".
+    ///
+    /// ```rust
+    /// # use time::{DurationUnit, prelude::*};
+    /// assert_eq!(
+    ///     90.minutes().split_at_unit(DurationUnit::Hours),
+    ///     (1, 30.minutes())
+    /// );
+    /// assert_eq!(
+    ///     (-90).minutes().split_at_unit(DurationUnit::Hours),
+    ///     (-1, (-30).minutes())
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This may panic if the whole number of milliseconds, microseconds, or
+    /// nanoseconds does not fit in an `i64`.
+    pub fn split_at_unit(self, unit: DurationUnit) -> (i64, Self) {
+        let (count, seconds_per_unit) = match unit {
+            DurationUnit::Weeks => (self.whole_weeks(), SECONDS_PER_WEEK),
+            DurationUnit::Days => (self.whole_days(), SECONDS_PER_DAY),
+            DurationUnit::Hours => (self.whole_hours(), SECONDS_PER_HOUR),
+            DurationUnit::Minutes => (self.whole_minutes(), SECONDS_PER_MINUTE),
+            DurationUnit::Seconds => (self.whole_seconds(), 1),
+            DurationUnit::Milliseconds => return self.split_at_subsec_unit(1_000_000),
+            DurationUnit::Microseconds => return self.split_at_subsec_unit(1_000),
+            DurationUnit::Nanoseconds => return self.split_at_subsec_unit(1),
+        };
+
+        (count, self - Self::seconds(count * seconds_per_unit))
+    }
+
+    /// Split the duration into the whole number of units of the provided
+    /// number of nanoseconds and the remainder.
+    fn split_at_subsec_unit(self, nanoseconds_per_unit: i128) -> (i64, Self) {
+        let nanoseconds = self.whole_nanoseconds();
+        let count = i64::try_from(nanoseconds / nanoseconds_per_unit)
+            .expect("whole number of units does not fit in an i64");
+        (
+            count,
+            Self::nanoseconds((nanoseconds % nanoseconds_per_unit) as i64),
+        )
+    }
+
     /// Computes `self + rhs`, returning `None` if an overflow occurred.
     ///
     /// ```rust
@@ -639,19 +990,24 @@ impl Duration {
         })
     }
 
-    /// Computes `self / rhs`, returning `None` if `rhs == 0`.
+    /// Computes `self / rhs`, returning `None` if `rhs == 0` or if the result
+    /// would overflow. As with integer division, the result is truncated
+    /// toward zero. Dividing with the `/` operator instead panics if
+    /// `rhs == 0`.
     ///
     /// ```rust
-    /// # use time::prelude::*;
+    /// # use time::{Duration, prelude::*};
     /// assert_eq!(10.seconds().checked_div(2), Some(5.seconds()));
     /// assert_eq!(10.seconds().checked_div(-2), Some((-5).seconds()));
+    /// assert_eq!(1.nanoseconds().checked_div(2), Some(0.seconds()));
     /// assert_eq!(1.seconds().checked_div(0), None);
+    /// assert_eq!(Duration::min_value().checked_div(-1), None);
     /// ```
     ///
     /// This function is `const fn` when using rustc >= 1.46.
     #[const_fn("1.46")]
     pub const fn checked_div(self, rhs: i32) -> Option<Self> {
-        if rhs == 0 {
+        if (rhs == 0) | ((rhs == -1) & (self.seconds == i64::min_value())) {
             return None;
         }
 
@@ -666,6 +1022,29 @@ impl Duration {
         })
     }
 
+    /// Computes `-self`, returning `None` if the result would overflow. The
+    /// only such value is [`Duration::min_value`].
+    ///
+    /// ```rust
+    /// # use time::{Duration, prelude::*};
+    /// assert_eq!(5.seconds().checked_neg(), Some((-5).seconds()));
+    /// assert_eq!(Duration::zero().checked_neg(), Some(Duration::zero()));
+    /// assert_eq!(Duration::min_value().checked_neg(), None);
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn checked_neg(self) -> Option<Self> {
+        if self.seconds == i64::min_value() {
+            None
+        } else {
+            Some(Self {
+                seconds: -self.seconds,
+                nanoseconds: -self.nanoseconds,
+            })
+        }
+    }
+
     /// Runs a closure, returning the duration of time it took to run. The
     /// return value of the closure is provided in the second part of the tuple.
     #[cfg(feature = "std")]
@@ -817,6 +1196,179 @@ impl TryFrom<Duration> for StdDuration {
     }
 }
 
+/// Create a `Duration` from a number of **whole seconds**. To create a
+/// `Duration` from a number of nanoseconds, convert from an `i128` instead.
+///
+/// ```rust
+/// # use time::{Duration, prelude::*};
+/// assert_eq!(Duration::from(60_i64), 1.minutes());
+/// assert_eq!(Duration::from(-1_i64), (-1).seconds());
+/// ```
+impl From<i64> for Duration {
+    fn from(seconds: i64) -> Self {
+        Self::seconds(seconds)
+    }
+}
+
+/// Create a `Duration` from a number of seconds and nanoseconds. Unlike
+/// [`Duration::new`], the nanoseconds may have the opposite sign to the
+/// seconds; the result is normalized so that both components share a sign.
+///
+/// ```rust
+/// # use time::{Duration, prelude::*};
+/// assert_eq!(Duration::from((1, 500_000_000)), 1.5.seconds());
+/// assert_eq!(Duration::from((1, -500_000_000)), 0.5.seconds());
+/// assert_eq!(Duration::from((-1, 1_500_000_000)), 0.5.seconds());
+/// ```
+///
+/// # Panics
+///
+/// This panics if carrying whole seconds out of the nanoseconds overflows the
+/// seconds, which is only possible when they are near `i64::max_value()` or
+/// `i64::min_value()`.
+impl From<(i64, i32)> for Duration {
+    fn from((seconds, nanoseconds): (i64, i32)) -> Self {
+        let mut seconds = seconds
+            .checked_add(nanoseconds as i64 / 1_000_000_000)
+            .expect("overflow when converting to `Duration`");
+        let mut nanoseconds = nanoseconds % 1_000_000_000;
+        if seconds > 0 && nanoseconds < 0 {
+            seconds -= 1;
+            nanoseconds += 1_000_000_000;
+        } else if seconds < 0 && nanoseconds > 0 {
+            seconds += 1;
+            nanoseconds -= 1_000_000_000;
+        }
+        Self {
+            seconds,
+            nanoseconds,
+        }
+    }
+}
+
+/// Obtain the whole seconds and the nanoseconds past the whole seconds. Both
+/// components have the same sign as the `Duration`.
+///
+/// ```rust
+/// # use time::prelude::*;
+/// assert_eq!(<(i64, i32)>::from(1.5.seconds()), (1, 500_000_000));
+/// assert_eq!(<(i64, i32)>::from((-1.5).seconds()), (-1, -500_000_000));
+/// ```
+impl From<Duration> for (i64, i32) {
+    fn from(duration: Duration) -> Self {
+        (duration.whole_seconds(), duration.subsec_nanoseconds())
+    }
+}
+
+/// Attempt to create a `Duration` from a number of **nanoseconds**, failing if
+/// the number of whole seconds does not fit in an `i64`. To create a
+/// `Duration` from a number of seconds, convert from an `i64` instead.
+///
+/// ```rust
+/// # use time::{Duration, prelude::*};
+/// # use standback::convert::TryFrom;
+/// assert_eq!(Duration::try_from(1_500_000_000_i128), Ok(1.5.seconds()));
+/// assert!(Duration::try_from(i128::max_value()).is_err());
+/// ```
+impl TryFrom<i128> for Duration {
+    type Error = error::ConversionRange;
+
+    fn try_from(nanoseconds: i128) -> Result<Self, error::ConversionRange> {
+        #[allow(clippy::manual_range_contains)]
+        let in_range = nanoseconds >= Self::min_value().whole_nanoseconds()
+            && nanoseconds <= Self::max_value().whole_nanoseconds();
+        if in_range {
+            Ok(Self::nanoseconds_i128(nanoseconds))
+        } else {
+            Err(error::ConversionRange::new())
+        }
+    }
+}
+
+/// Write the whole seconds, followed by the fractional part with any trailing
+/// zeros removed.
+fn fmt_seconds(f: &mut fmt::Formatter<'_>, seconds: u64, nanoseconds: u32) -> fmt::Result {
+    write!(f, "{}", seconds)?;
+    if nanoseconds != 0 {
+        let mut fraction = nanoseconds;
+        let mut width = 9;
+        while fraction.rem_euclid(10) == 0 {
+            fraction /= 10;
+            width -= 1;
+        }
+        write!(f, ".{:0width$}", fraction, width = width)?;
+    }
+    Ok(())
+}
+
+/// The default format is an ISO 8601 duration, such as `PT2H3M` or `P1DT0.5S`,
+/// using units no larger than days. The alternate format (`{:#}`) is a compact
+/// form intended for humans, such as `2h3m` or `1d0.5s`. Zero is formatted as
+/// `PT0S` and `0s` respectively. Negative durations have a leading `-` in
+/// both forms.
+///
+/// ```rust
+/// # use time::prelude::*;
+/// let duration = 2.hours() + 3.minutes() + 4.5.seconds();
+/// assert_eq!(duration.to_string(), "PT2H3M4.5S");
+/// assert_eq!(format!("{:#}", duration), "2h3m4.5s");
+/// assert_eq!((-duration).to_string(), "-PT2H3M4.5S");
+/// assert_eq!(format!("{:#}", -duration), "-2h3m4.5s");
+/// ```
+impl Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_negative() {
+            f.write_str("-")?;
+        }
+
+        // `wrapping_abs` is correct here, as `i64::min_value()` is converted to
+        // the correct unsigned value.
+        let seconds = self.seconds.wrapping_abs() as u64;
+        let nanoseconds = self.nanoseconds.abs() as u32;
+        let days = seconds / SECONDS_PER_DAY as u64;
+        let hours = seconds / SECONDS_PER_HOUR as u64 % 24;
+        let minutes = seconds / SECONDS_PER_MINUTE as u64 % 60;
+        let seconds = seconds % SECONDS_PER_MINUTE as u64;
+        let has_seconds = seconds != 0 || nanoseconds != 0;
+
+        if f.alternate() {
+            if days != 0 {
+                write!(f, "{}d", days)?;
+            }
+            if hours != 0 {
+                write!(f, "{}h", hours)?;
+            }
+            if minutes != 0 {
+                write!(f, "{}m", minutes)?;
+            }
+            if has_seconds || self.is_zero() {
+                fmt_seconds(f, seconds, nanoseconds)?;
+                f.write_str("s")?;
+            }
+        } else {
+            f.write_str("P")?;
+            if days != 0 {
+                write!(f, "{}D", days)?;
+            }
+            if hours != 0 || minutes != 0 || has_seconds || self.is_zero() {
+                f.write_str("T")?;
+            }
+            if hours != 0 {
+                write!(f, "{}H", hours)?;
+            }
+            if minutes != 0 {
+                write!(f, "{}M", minutes)?;
+            }
+            if has_seconds || self.is_zero() {
+                fmt_seconds(f, seconds, nanoseconds)?;
+                f.write_str("S")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl Add for Duration {
     type Output = Self;
 
@@ -858,8 +1410,14 @@ impl AddAssign<StdDuration> for Duration {
 impl Neg for Duration {
     type Output = Self;
 
+    /// Negates the duration.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `self` is [`Duration::min_value`], which has no positive
+    /// counterpart. Use [`Duration::checked_neg`] to handle that case instead.
     fn neg(self) -> Self::Output {
-        -1 * self
+        self.checked_neg().expect("overflow when negating duration")
     }
 }
 
@@ -1119,6 +1677,37 @@ mod test {
         assert_eq!(Duration::week(), 604_800.seconds());
     }
 
+    #[test]
+    fn display() {
+        #[cfg(not(feature = "std"))]
+        use alloc::{format, string::ToString};
+
+        let duration = 1.days() + 2.hours() + 3.minutes() + 4.seconds();
+        assert_eq!(duration.to_string(), "P1DT2H3M4S");
+        assert_eq!(format!("{:#}", duration), "1d2h3m4s");
+        assert_eq!((-duration).to_string(), "-P1DT2H3M4S");
+        assert_eq!(format!("{:#}", -duration), "-1d2h3m4s");
+
+        assert_eq!(Duration::zero().to_string(), "PT0S");
+        assert_eq!(format!("{:#}", Duration::zero()), "0s");
+        assert_eq!(2.days().to_string(), "P2D");
+        assert_eq!(format!("{:#}", 2.days()), "2d");
+        assert_eq!((2.hours() + 3.minutes()).to_string(), "PT2H3M");
+        assert_eq!(format!("{:#}", 2.hours() + 3.minutes()), "2h3m");
+        assert_eq!((1.days() + 1.nanoseconds()).to_string(), "P1DT0.000000001S");
+        assert_eq!((-0.5).seconds().to_string(), "-PT0.5S");
+        assert_eq!(format!("{:#}", (-0.5).seconds()), "-0.5s");
+        assert_eq!(format!("{:#}", 1.250.seconds()), "1.25s");
+        assert_eq!(
+            Duration::min_value().to_string(),
+            "-P106751991167300DT15H30M8.999999999S"
+        );
+        assert_eq!(
+            Duration::max_value().to_string(),
+            "P106751991167300DT15H30M7.999999999S"
+        );
+    }
+
     #[test]
     fn is_zero() {
         assert!(!(-1).nanoseconds().is_zero());
@@ -1140,6 +1729,100 @@ mod test {
         assert!(1.seconds().is_positive());
     }
 
+    #[test]
+    fn sign_predicates_are_exclusive() {
+        for &duration in &[
+            1.nanoseconds(),
+            (-1).nanoseconds(),
+            1.5.seconds(),
+            (-1.5).seconds(),
+            Duration::zero(),
+            Duration::max_value(),
+            Duration::min_value(),
+        ] {
+            let count = duration.is_positive() as u8
+                + duration.is_negative() as u8
+                + duration.is_zero() as u8;
+            assert_eq!(count, 1);
+        }
+    }
+
+    #[test]
+    fn total_cmp() {
+        let mut durations = [
+            1.seconds(),
+            (-1).nanoseconds(),
+            Duration::max_value(),
+            (-1.5).seconds(),
+            0.seconds(),
+            1.nanoseconds(),
+            Duration::min_value(),
+            (-1).seconds(),
+            0.5.seconds(),
+        ];
+        durations.sort_unstable_by(Duration::total_cmp);
+        assert_eq!(
+            durations,
+            [
+                Duration::min_value(),
+                (-1.5).seconds(),
+                (-1).seconds(),
+                (-1).nanoseconds(),
+                0.seconds(),
+                1.nanoseconds(),
+                0.5.seconds(),
+                1.seconds(),
+                Duration::max_value(),
+            ]
+        );
+
+        for pair in durations.windows(2) {
+            assert_eq!(pair[0].total_cmp(&pair[1]), Ordering::Less);
+            assert_eq!(pair[1].total_cmp(&pair[0]), Ordering::Greater);
+            assert!(pair[0].as_seconds_f64() <= pair[1].as_seconds_f64());
+        }
+        assert_eq!(
+            1.seconds().total_cmp(&1_000.milliseconds()),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn min_max() {
+        assert_eq!(1.seconds().min(2.seconds()), 1.seconds());
+        assert_eq!(2.seconds().min(1.seconds()), 1.seconds());
+        assert_eq!((-1).seconds().min(1.nanoseconds()), (-1).seconds());
+        assert_eq!((-1.5).seconds().min((-1).seconds()), (-1.5).seconds());
+        assert_eq!(1.seconds().max(2.seconds()), 2.seconds());
+        assert_eq!((-1).seconds().max(1.nanoseconds()), 1.nanoseconds());
+        assert_eq!((-1.5).seconds().max((-1).seconds()), (-1).seconds());
+        assert_eq!(0.5.seconds().max(0.25.seconds()), 0.5.seconds());
+    }
+
+    #[test]
+    fn clamp() {
+        let (min, max) = ((-2).seconds(), 3.seconds());
+        assert_eq!((-5).seconds().clamp(min, max), min);
+        assert_eq!(Duration::min_value().clamp(min, max), min);
+        assert_eq!(5.seconds().clamp(min, max), max);
+        assert_eq!(3.000_000_001.seconds().clamp(min, max), max);
+        assert_eq!((-1.5).seconds().clamp(min, max), (-1.5).seconds());
+        assert_eq!(0.seconds().clamp(min, max), 0.seconds());
+        assert_eq!(min.clamp(min, max), min);
+        assert_eq!(max.clamp(min, max), max);
+
+        let (min, max) = ((-3).seconds(), (-1).seconds());
+        assert_eq!(0.seconds().clamp(min, max), max);
+        assert_eq!((-2).seconds().clamp(min, max), (-2).seconds());
+        assert_eq!(1.seconds().clamp(1.seconds(), 1.seconds()), 1.seconds());
+    }
+
+    #[test]
+    #[should_panic(expected = "`min` must be less than or equal to `max`")]
+    fn clamp_invalid_bounds() {
+        let _ = 0.seconds().clamp(1.seconds(), (-1).seconds());
+    }
+
     #[allow(deprecated)]
     #[test]
     fn sign() {
@@ -1254,6 +1937,45 @@ mod test {
         assert_eq!(Duration::seconds_f64(-0.5), (-0.5).seconds());
     }
 
+    #[test]
+    fn seconds_f64_non_finite() {
+        assert_eq!(Duration::seconds_f64(core::f64::NAN), Duration::zero());
+        assert_eq!(
+            Duration::seconds_f64(core::f64::INFINITY),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::seconds_f64(core::f64::NEG_INFINITY),
+            Duration::min_value()
+        );
+        assert_eq!(Duration::seconds_f64(1e30), Duration::max_value());
+        assert_eq!(Duration::seconds_f64(-1e30), Duration::min_value());
+    }
+
+    #[test]
+    fn try_from_seconds_f64() {
+        assert_eq!(Duration::try_from_seconds_f64(1.5), Ok(1.5.seconds()));
+        assert_eq!(Duration::try_from_seconds_f64(-1.5), Ok((-1.5).seconds()));
+        assert_eq!(Duration::try_from_seconds_f64(0.), Ok(Duration::zero()));
+        assert_eq!(
+            Duration::try_from_seconds_f64(i64::min_value() as f64),
+            Ok(Duration::seconds(i64::min_value()))
+        );
+        for &seconds in &[
+            core::f64::NAN,
+            core::f64::INFINITY,
+            core::f64::NEG_INFINITY,
+            1e30,
+            -1e30,
+        ] {
+            assert_eq!(
+                Duration::try_from_seconds_f64(seconds),
+                Err(error::ConversionRange::new())
+            );
+        }
+        assert!(Duration::try_from_seconds_f64(i64::max_value() as f64).is_err());
+    }
+
     #[test]
     #[allow(clippy::float_cmp)]
     fn as_seconds_f64() {
@@ -1271,6 +1993,45 @@ mod test {
         assert_eq!(Duration::seconds_f32(-0.5), (-0.5).seconds());
     }
 
+    #[test]
+    fn seconds_f32_non_finite() {
+        assert_eq!(Duration::seconds_f32(core::f32::NAN), Duration::zero());
+        assert_eq!(
+            Duration::seconds_f32(core::f32::INFINITY),
+            Duration::max_value()
+        );
+        assert_eq!(
+            Duration::seconds_f32(core::f32::NEG_INFINITY),
+            Duration::min_value()
+        );
+        assert_eq!(Duration::seconds_f32(1e30), Duration::max_value());
+        assert_eq!(Duration::seconds_f32(-1e30), Duration::min_value());
+    }
+
+    #[test]
+    fn try_from_seconds_f32() {
+        assert_eq!(Duration::try_from_seconds_f32(1.5), Ok(1.5.seconds()));
+        assert_eq!(Duration::try_from_seconds_f32(-1.5), Ok((-1.5).seconds()));
+        assert_eq!(Duration::try_from_seconds_f32(0.), Ok(Duration::zero()));
+        assert_eq!(
+            Duration::try_from_seconds_f32(i64::min_value() as f32),
+            Ok(Duration::seconds(i64::min_value()))
+        );
+        for &seconds in &[
+            core::f32::NAN,
+            core::f32::INFINITY,
+            core::f32::NEG_INFINITY,
+            1e30,
+            -1e30,
+        ] {
+            assert_eq!(
+                Duration::try_from_seconds_f32(seconds),
+                Err(error::ConversionRange::new())
+            );
+        }
+        assert!(Duration::try_from_seconds_f32(i64::max_value() as f32).is_err());
+    }
+
     #[test]
     #[allow(clippy::float_cmp)]
     fn as_seconds_f32() {
@@ -1342,6 +2103,127 @@ mod test {
         assert_eq!((-1.000_000_4).seconds().subsec_nanoseconds(), -400);
     }
 
+    #[test]
+    fn components() {
+        let duration = 2.weeks()
+            + 3.days()
+            + 4.hours()
+            + 5.minutes()
+            + 6.seconds()
+            + 7.milliseconds()
+            + 8.microseconds()
+            + 9.nanoseconds();
+        let expected = [
+            (DurationUnit::Weeks, 2),
+            (DurationUnit::Days, 3),
+            (DurationUnit::Hours, 4),
+            (DurationUnit::Minutes, 5),
+            (DurationUnit::Seconds, 6),
+            (DurationUnit::Milliseconds, 7),
+            (DurationUnit::Microseconds, 8),
+            (DurationUnit::Nanoseconds, 9),
+        ];
+        assert_eq!(duration.components().count(), expected.len());
+        for (component, &(unit, value)) in duration.components().zip(expected.iter()) {
+            assert_eq!(component, (unit, value));
+        }
+        for (component, &(unit, value)) in (-duration).components().zip(expected.iter()) {
+            assert_eq!(component, (unit, -value));
+        }
+
+        // Zero components are skipped.
+        let mut components = (1.days() + 1.microseconds()).components();
+        assert_eq!(components.next(), Some((DurationUnit::Days, 1)));
+        assert_eq!(components.next(), Some((DurationUnit::Microseconds, 1)));
+        assert_eq!(components.next(), None);
+        assert_eq!(Duration::zero().components().next(), None);
+    }
+
+    #[test]
+    fn split_at_unit() {
+        assert_eq!(
+            90.minutes().split_at_unit(DurationUnit::Hours),
+            (1, 30.minutes())
+        );
+        assert_eq!(
+            (-90).minutes().split_at_unit(DurationUnit::Hours),
+            (-1, (-30).minutes())
+        );
+        assert_eq!(
+            (-1.5).seconds().split_at_unit(DurationUnit::Seconds),
+            (-1, (-0.5).seconds())
+        );
+        assert_eq!(10.days().split_at_unit(DurationUnit::Weeks), (1, 3.days()));
+        assert_eq!(
+            (1.seconds() + 2.microseconds() + 500.nanoseconds())
+                .split_at_unit(DurationUnit::Microseconds),
+            (1_000_002, 500.nanoseconds())
+        );
+        assert_eq!(
+            (-1.5)
+                .milliseconds()
+                .split_at_unit(DurationUnit::Milliseconds),
+            (-1, (-500).microseconds())
+        );
+        assert_eq!(
+            30.minutes().split_at_unit(DurationUnit::Hours),
+            (0, 30.minutes())
+        );
+        assert_eq!(
+            0.seconds().split_at_unit(DurationUnit::Nanoseconds),
+            (0, 0.seconds())
+        );
+    }
+
+    #[test]
+    fn checked_constructors() {
+        assert_eq!(Duration::checked_weeks(2), Some(2.weeks()));
+        assert_eq!(Duration::checked_days(-2), Some((-2).days()));
+        assert_eq!(Duration::checked_hours(2), Some(2.hours()));
+        assert_eq!(Duration::checked_minutes(-2), Some((-2).minutes()));
+
+        assert_eq!(Duration::checked_weeks(i64::max_value()), None);
+        assert_eq!(Duration::checked_days(i64::min_value()), None);
+        assert_eq!(Duration::checked_hours(i64::max_value()), None);
+        assert_eq!(Duration::checked_minutes(i64::min_value()), None);
+
+        // The largest representable number of minutes.
+        let minutes = i64::max_value() / 60;
+        assert_eq!(
+            Duration::checked_minutes(minutes).map(Duration::whole_minutes),
+            Some(minutes)
+        );
+        assert_eq!(Duration::checked_minutes(minutes + 1), None);
+
+        // Seconds and sub-second units are always representable, as they are
+        // divided into whole seconds rather than multiplied.
+        assert_eq!(Duration::checked_seconds(-2), Some((-2).seconds()));
+        assert_eq!(Duration::checked_milliseconds(2), Some(2.milliseconds()));
+        assert_eq!(
+            Duration::checked_seconds(i64::min_value()).map(Duration::whole_seconds),
+            Some(i64::min_value())
+        );
+        assert_eq!(
+            Duration::checked_milliseconds(i64::max_value()),
+            Some(Duration::new(
+                i64::max_value() / 1_000,
+                (i64::max_value() % 1_000) as i32 * 1_000_000
+            ))
+        );
+        assert_eq!(
+            Duration::checked_milliseconds(i64::min_value()).map(Duration::whole_milliseconds),
+            Some(i64::min_value() as i128)
+        );
+        assert_eq!(
+            Duration::milliseconds(i64::max_value()).whole_milliseconds(),
+            i64::max_value() as i128
+        );
+        assert_eq!(
+            Duration::nanoseconds(i64::min_value()).whole_nanoseconds(),
+            i64::min_value() as i128
+        );
+    }
+
     #[test]
     #[allow(deprecated)]
     fn checked_add() {
@@ -1373,6 +2255,45 @@ mod test {
         assert_eq!(10.seconds().checked_div(2), Some(5.seconds()));
         assert_eq!(10.seconds().checked_div(-2), Some((-5).seconds()));
         assert_eq!(1.seconds().checked_div(0), None);
+
+        // An even division matches the operator.
+        assert_eq!(90.minutes().checked_div(3), Some(30.minutes()));
+        assert_eq!(90.minutes().checked_div(3), Some(90.minutes() / 3));
+
+        // A remainder is truncated toward zero, regardless of sign.
+        assert_eq!(10.nanoseconds().checked_div(3), Some(3.nanoseconds()));
+        assert_eq!((-10).nanoseconds().checked_div(3), Some((-3).nanoseconds()));
+        assert_eq!(10.nanoseconds().checked_div(-3), Some((-3).nanoseconds()));
+        assert_eq!(1.seconds().checked_div(3), Some(333_333_333.nanoseconds()));
+        assert_eq!(1.seconds().checked_div(3), Some(1.seconds() / 3));
+
+        // Division by zero and overflow are reported rather than panicking.
+        assert_eq!(Duration::zero().checked_div(0), None);
+        assert_eq!(Duration::max_value().checked_div(0), None);
+        assert_eq!(Duration::min_value().checked_div(-1), None);
+        assert_eq!(
+            Duration::max_value().checked_div(-1),
+            Some(-Duration::max_value())
+        );
+    }
+
+    #[test]
+    fn checked_neg() {
+        assert_eq!(5.seconds().checked_neg(), Some((-5).seconds()));
+        assert_eq!((-5).seconds().checked_neg(), Some(5.seconds()));
+        assert_eq!(1.5.seconds().checked_neg(), Some((-1.5).seconds()));
+        assert_eq!(0.seconds().checked_neg(), Some(0.seconds()));
+        assert_eq!(
+            Duration::max_value().checked_neg(),
+            Some(Duration::new(-i64::max_value(), -999_999_999))
+        );
+        assert_eq!(Duration::min_value().checked_neg(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn div_by_zero() {
+        let _ = 1.seconds() / 0_i32;
     }
 
     #[test]
@@ -1393,6 +2314,19 @@ mod test {
         assert_eq!(Duration::try_from(1.std_seconds()), Ok(1.seconds()));
     }
 
+    #[test]
+    #[cfg(not(feature = "std"))]
+    fn core_duration_round_trip() {
+        let core_duration = core::time::Duration::new(5, 250_000_000);
+        let duration = Duration::try_from(core_duration);
+        assert_eq!(duration, Ok(5.25.seconds()));
+        assert_eq!(duration.and_then(StdDuration::try_from), Ok(core_duration));
+
+        let max = core::time::Duration::new(u64::max_value(), 999_999_999);
+        assert!(Duration::try_from(max).is_err());
+        assert!(core::time::Duration::try_from((-5.25).seconds()).is_err());
+    }
+
     #[test]
     fn try_to_std_duration() {
         assert_eq!(StdDuration::try_from(0.seconds()), Ok(0.std_seconds()));
@@ -1400,6 +2334,92 @@ mod test {
         assert!(StdDuration::try_from((-1).seconds()).is_err());
     }
 
+    #[test]
+    fn from_i64() {
+        assert_eq!(Duration::from(60_i64), 1.minutes());
+        assert_eq!(Duration::from(0_i64), Duration::zero());
+        assert_eq!(Duration::from(-3_600_i64), (-1).hours());
+        assert_eq!(
+            Duration::from(i64::max_value()),
+            Duration::max_value() - 999_999_999.nanoseconds()
+        );
+    }
+
+    #[test]
+    fn try_from_i128() {
+        assert_eq!(Duration::try_from(1_i128), Ok(1.nanoseconds()));
+        assert_eq!(
+            Duration::try_from(-1_500_000_000_i128),
+            Ok((-1.5).seconds())
+        );
+        assert_eq!(
+            Duration::try_from(60_000_000_000_i128),
+            Ok(Duration::from(60_i64))
+        );
+        assert_eq!(
+            Duration::try_from(Duration::max_value().whole_nanoseconds()),
+            Ok(Duration::max_value())
+        );
+        assert_eq!(
+            Duration::try_from(Duration::min_value().whole_nanoseconds()),
+            Ok(Duration::min_value())
+        );
+        assert!(Duration::try_from(Duration::max_value().whole_nanoseconds() + 1).is_err());
+        assert!(Duration::try_from(Duration::min_value().whole_nanoseconds() - 1).is_err());
+        assert!(Duration::try_from(i128::min_value()).is_err());
+    }
+
+    #[test]
+    fn from_seconds_nanoseconds_tuple() {
+        assert_eq!(Duration::from((1, 500_000_000)), 1.5.seconds());
+        assert_eq!(Duration::from((-1, -500_000_000)), (-1.5).seconds());
+        // Out-of-range nanoseconds carry into the seconds.
+        assert_eq!(
+            Duration::from((1, 2_000_000_001)),
+            3.seconds() + 1.nanoseconds()
+        );
+        assert_eq!(Duration::from((-1, 1_500_000_000)), 0.5.seconds());
+        assert_eq!(Duration::from((2, -1_500_000_000)), 0.5.seconds());
+        // Opposite signs are normalized.
+        let duration = Duration::from((1, -1));
+        assert_eq!(duration, 999_999_999.nanoseconds());
+        assert_eq!(duration.whole_seconds(), 0);
+        assert_eq!(duration.subsec_nanoseconds(), 999_999_999);
+        let duration = Duration::from((-1, 1));
+        assert_eq!(duration, (-999_999_999).nanoseconds());
+        assert_eq!(duration.subsec_nanoseconds(), -999_999_999);
+        assert_eq!(
+            Duration::from((i64::max_value(), 999_999_999)),
+            Duration::max_value()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when converting to `Duration`")]
+    fn from_seconds_nanoseconds_tuple_overflow() {
+        let _ = Duration::from((i64::max_value(), 1_000_000_000));
+    }
+
+    #[test]
+    fn to_seconds_nanoseconds_tuple() {
+        assert_eq!(<(i64, i32)>::from(Duration::zero()), (0, 0));
+        assert_eq!(<(i64, i32)>::from(1.5.seconds()), (1, 500_000_000));
+        for &duration in &[
+            (-1.5).seconds(),
+            (-1).nanoseconds(),
+            Duration::min_value(),
+            Duration::max_value(),
+        ] {
+            let tuple: (i64, i32) = duration.into();
+            assert_eq!(
+                tuple,
+                (duration.whole_seconds(), duration.subsec_nanoseconds())
+            );
+            assert_eq!(Duration::from(tuple), duration);
+        }
+        assert_eq!(<(i64, i32)>::from((-1.5).seconds()), (-1, -500_000_000));
+    }
+
     #[test]
     fn add() {
         assert_eq!(1.seconds() + 1.seconds(), 2.seconds());
@@ -1456,6 +2476,8 @@ mod test {
         assert_eq!(-(1.seconds()), (-1).seconds());
         assert_eq!(-(-1).seconds(), 1.seconds());
         assert_eq!(-(0.seconds()), 0.seconds());
+        #[cfg(feature = "std")]
+        assert_panics!(-Duration::min_value());
     }
 
     #[test]
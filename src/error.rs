@@ -99,6 +99,20 @@ pub struct ComponentRange {
     pub(crate) conditional_range: bool,
 }
 
+impl ComponentRange {
+    /// Get the name of the component that was out of range, such as `"month"`
+    /// or `"day"`.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// let error = Date::try_from_ymd(2019, 13, 1).unwrap_err();
+    /// assert_eq!(error.name(), "month");
+    /// ```
+    pub const fn name(self) -> &'static str {
+        self.name
+    }
+}
+
 impl fmt::Display for ComponentRange {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
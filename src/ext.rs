@@ -1,7 +1,11 @@
 #![allow(trivial_numeric_casts)]
 
 use crate::Duration;
+#[cfg(feature = "std")]
+use crate::OffsetDateTime;
 use core::time::Duration as StdDuration;
+#[cfg(feature = "std")]
+use std::time::Instant as StdInstant;
 
 /// Create `Duration`s from primitive and core numeric types.
 ///
@@ -531,6 +535,41 @@ impl<T: NumericalStdDuration> NumericalStdDurationShort for T {
     }
 }
 
+/// Project monotonic [`std::time::Instant`]s onto wall-clock time.
+///
+/// This trait is implemented for a pair of an `Instant` and an
+/// [`OffsetDateTime`] captured at the same moment. Later (or earlier) instants
+/// can then be converted to the wall-clock time they correspond to, without
+/// being affected by any adjustments made to the system clock in the meantime.
+///
+/// ```rust
+/// # use time::{ext::InstantExt, OffsetDateTime};
+/// # use std::time::{Duration, Instant};
+/// let anchor = (Instant::now(), OffsetDateTime::now_utc());
+/// let later = anchor.0 + Duration::from_secs(5);
+/// assert_eq!(anchor.wall_clock_at(later), anchor.1 + Duration::from_secs(5));
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docs, doc(cfg(feature = "std")))]
+pub trait InstantExt {
+    /// Obtain the wall-clock time corresponding to the provided `Instant`.
+    ///
+    /// The offset of the anchoring `OffsetDateTime` is retained.
+    fn wall_clock_at(&self, later: StdInstant) -> OffsetDateTime;
+}
+
+#[cfg(feature = "std")]
+impl InstantExt for (StdInstant, OffsetDateTime) {
+    fn wall_clock_at(&self, later: StdInstant) -> OffsetDateTime {
+        let (instant, datetime) = *self;
+        if later >= instant {
+            datetime + (later - instant)
+        } else {
+            datetime - (instant - later)
+        }
+    }
+}
+
 #[cfg(test)]
 mod test_numerical_duration {
     use super::{Duration, NumericalDuration};
@@ -691,3 +730,35 @@ mod test_numerical_std_duration_short {
         assert_eq!(2.seconds() - 500.milliseconds(), 1_500.milliseconds());
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod test_instant_ext {
+    use super::InstantExt;
+    use crate::{Date, OffsetDateTime};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn wall_clock_at() -> crate::Result<()> {
+        let datetime: OffsetDateTime = Date::try_from_ymd(2020, 1, 1)?
+            .try_with_hms(12, 0, 0)?
+            .assume_offset(offset!(+1));
+        let start = Instant::now();
+        let later = start + Duration::from_millis(1_500);
+
+        assert_eq!(
+            (start, datetime).wall_clock_at(later),
+            Date::try_from_ymd(2020, 1, 1)?
+                .try_with_hms_milli(12, 0, 1, 500)?
+                .assume_offset(offset!(+1))
+        );
+        assert_eq!(
+            (later, datetime).wall_clock_at(start),
+            Date::try_from_ymd(2020, 1, 1)?
+                .try_with_hms_milli(11, 59, 58, 500)?
+                .assume_offset(offset!(+1))
+        );
+        assert_eq!((start, datetime).wall_clock_at(start), datetime);
+        assert_eq!((start, datetime).wall_clock_at(later).offset(), offset!(+1));
+        Ok(())
+    }
+}
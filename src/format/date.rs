@@ -6,7 +6,8 @@ use crate::{
     error,
     format::{
         parse::{
-            consume_padding, try_consume_digits, try_consume_exact_digits, try_consume_first_match,
+            consume_padding, try_consume_char, try_consume_digits, try_consume_exact_digits,
+            try_consume_first_match,
         },
         Padding, ParseResult, ParsedItems,
     },
@@ -137,12 +138,14 @@ pub(crate) fn fmt_C(f: &mut Formatter<'_>, date: Date, padding: Padding) -> fmt:
 
 /// Year divided by 100 and truncated to integer (`00`-`999`)
 pub(crate) fn parse_C(items: &mut ParsedItems, s: &mut &str, padding: Padding) -> ParseResult<()> {
-    let padding_length = consume_padding(s, padding, 1);
+    let century = if let Padding::Width(_) = padding {
+        try_consume_exact_digits::<i32>(s, 2, padding)
+    } else {
+        let padding_length = consume_padding(s, padding, 1);
+        try_consume_digits(s, (2 - padding_length)..=(3 - padding_length))
+    };
     items.year = Some(
-        try_consume_digits::<i32, _>(s, (2 - padding_length)..=(3 - padding_length))
-            .ok_or(error::Parse::InvalidYear)?
-            * 100
-            + items.year.unwrap_or(0).rem_euclid(100),
+        century.ok_or(error::Parse::InvalidYear)? * 100 + items.year.unwrap_or(0).rem_euclid(100),
     );
 
     Ok(())
@@ -164,6 +167,20 @@ pub(crate) fn parse_d(items: &mut ParsedItems, s: &mut &str, padding: Padding) -
     Ok(())
 }
 
+/// Day of the month, space-padded (` 1`-`31`)
+///
+/// A leading space is optional, and a zero-padded day is also accepted.
+pub(crate) fn parse_e(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    let _ = try_consume_char(s, ' ');
+    items.day = Some(
+        try_consume_digits(s, 1..=2)
+            .and_then(NonZeroU8::new)
+            .ok_or(error::Parse::InvalidDayOfMonth)?,
+    );
+
+    Ok(())
+}
+
 /// Week-based year, last two digits (`00`-`99`)
 pub(crate) fn fmt_g(f: &mut Formatter<'_>, date: Date, padding: Padding) -> fmt::Result {
     pad!(f, padding, 2, date.iso_year_week().0.rem_euclid(100))
@@ -181,27 +198,12 @@ pub(crate) fn parse_g(items: &mut ParsedItems, s: &mut &str, padding: Padding) -
 
 /// Week-based year
 pub(crate) fn fmt_G(f: &mut Formatter<'_>, date: Date, padding: Padding) -> fmt::Result {
-    let year = date.iso_year_week().0;
-
-    if year >= 10_000 {
-        f.write_str("+")?;
-    }
-
-    pad!(f, padding, 4, year)
+    fmt_year(f, date.iso_year_week().0, padding)
 }
 
 /// Week-based year
 pub(crate) fn parse_G(items: &mut ParsedItems, s: &mut &str, padding: Padding) -> ParseResult<()> {
-    let sign = try_consume_first_match(s, [("+", 1), ("-", -1)].iter().cloned()).unwrap_or(1);
-
-    consume_padding(s, padding, 4);
-
-    items.week_based_year = Some(
-        try_consume_digits(s, 1..=6)
-            .map(|v: i32| sign * v)
-            .ok_or(error::Parse::InvalidYear)?,
-    );
-
+    items.week_based_year = Some(parse_year(s, padding).ok_or(error::Parse::InvalidYear)?);
     Ok(())
 }
 
@@ -237,6 +239,36 @@ pub(crate) fn parse_m(items: &mut ParsedItems, s: &mut &str, padding: Padding) -
     Ok(())
 }
 
+/// Week of the month, where weeks begin on Monday (`1`-`6`)
+pub(crate) fn fmt_o(f: &mut Formatter<'_>, date: Date) -> fmt::Result {
+    write!(f, "{}", date.week_of_month(Weekday::Monday))
+}
+
+/// Week of the month, where weeks begin on Monday (`1`-`6`)
+pub(crate) fn parse_o(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    items.monday_week_of_month = Some(parse_week_of_month(s)?);
+    Ok(())
+}
+
+/// Week of the month, where weeks begin on Sunday (`1`-`6`)
+pub(crate) fn fmt_O(f: &mut Formatter<'_>, date: Date) -> fmt::Result {
+    write!(f, "{}", date.week_of_month(Weekday::Sunday))
+}
+
+/// Week of the month, where weeks begin on Sunday (`1`-`6`)
+pub(crate) fn parse_O(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    items.sunday_week_of_month = Some(parse_week_of_month(s)?);
+    Ok(())
+}
+
+/// Parse a single-digit week of the month (`1`-`6`).
+fn parse_week_of_month(s: &mut &str) -> ParseResult<NonZeroU8> {
+    try_consume_exact_digits(s, 1, Padding::Zero)
+        .filter(|&week| week <= 6)
+        .and_then(NonZeroU8::new)
+        .ok_or(error::Parse::InvalidWeek)
+}
+
 /// ISO weekday (Monday = `1`, Sunday = `7`)
 pub(crate) fn fmt_u(f: &mut Formatter<'_>, date: Date) -> fmt::Result {
     write!(f, "{}", date.weekday().iso_weekday_number())
@@ -337,28 +369,69 @@ pub(crate) fn parse_y(items: &mut ParsedItems, s: &mut &str, padding: Padding) -
 
 /// Full year
 pub(crate) fn fmt_Y(f: &mut Formatter<'_>, date: Date, padding: Padding) -> fmt::Result {
-    let year = date.year();
+    fmt_year(f, date.year(), padding)
+}
+
+/// Full year
+pub(crate) fn parse_Y(items: &mut ParsedItems, s: &mut &str, padding: Padding) -> ParseResult<()> {
+    items.year = Some(parse_year(s, padding).ok_or(error::Parse::InvalidYear)?);
+    Ok(())
+}
 
-    if year >= 10_000 {
-        f.write_str("+")?;
+/// A year, with a sign if it is negative or has more than four digits.
+///
+/// Zeros are placed after the sign, giving the ISO 8601 expanded
+/// representation (ex. `-0001`), while spaces are placed before it so that the
+/// sign remains adjacent to the digits (ex. `   -1`).
+fn fmt_year(f: &mut Formatter<'_>, year: i32, padding: Padding) -> fmt::Result {
+    let sign = if year < 0 {
+        "-"
+    } else if year >= 10_000 {
+        "+"
+    } else {
+        ""
+    };
+    let year = year.abs();
+
+    if padding == Padding::Space {
+        let num_digits = match year {
+            0..=9 => 1,
+            10..=99 => 2,
+            100..=999 => 3,
+            _ => 4,
+        };
+        return write!(f, "{:1$}{2}{3}", "", 4 - num_digits, sign, year);
     }
 
+    f.write_str(sign)?;
     pad!(f, padding, 4, year)
 }
 
-/// Full year
-pub(crate) fn parse_Y(items: &mut ParsedItems, s: &mut &str, padding: Padding) -> ParseResult<()> {
+/// A year as formatted by [`fmt_year`]. Up to four digits are accepted
+/// without a sign, and up to six with one.
+fn parse_year(s: &mut &str, padding: Padding) -> Option<i32> {
+    if padding == Padding::Space {
+        consume_padding(s, padding, 3);
+    }
+
     let (sign, max_digits) =
         try_consume_first_match(s, [("+", (1, 6)), ("-", (-1, 6))].iter().cloned())
             .unwrap_or((1, 4));
 
-    consume_padding(s, padding, 3);
-
-    items.year = Some(
-        try_consume_digits(s, 1..=max_digits)
-            .map(|v: i32| sign * v)
-            .ok_or(error::Parse::InvalidYear)?,
-    );
+    // An explicit width is the minimum number of digits, as the formatted
+    // value is never truncated. Any leading zeros it requires do not count
+    // towards the maximum.
+    let num_digits = if let Padding::Width(width) = padding {
+        let width = usize::from(width);
+        width..=width.max(max_digits)
+    } else {
+        consume_padding(s, padding, 3);
+        1..=max_digits
+    };
+    let year: i32 = try_consume_digits(s, num_digits)?;
+    if year >= 10_i32.pow(max_digits as u32) {
+        return None;
+    }
 
-    Ok(())
+    Some(sign * year)
 }
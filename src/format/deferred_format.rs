@@ -75,6 +75,8 @@ impl Display for DeferredFormat {
                         FormatItem::Specifier(specifier) => {
                             format_specifier(f, self.date, self.time, self.offset, specifier)?
                         }
+                        // Optional sections are always formatted.
+                        FormatItem::OptionalStart | FormatItem::OptionalEnd => {}
                     }
                 }
 
@@ -1,5 +1,6 @@
 //! The `Format` struct and its implementations.
 
+use crate::UtcOffset;
 #[cfg(not(feature = "std"))]
 use alloc::{borrow::ToOwned, string::String};
 
@@ -9,6 +10,14 @@ use alloc::{borrow::ToOwned, string::String};
 #[cfg_attr(__time_02_supports_non_exhaustive, non_exhaustive)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Format {
+    /// The format described in [RFC3339](https://tools.ietf.org/html/rfc3339#section-5.6).
+    ///
+    /// Which deviations from the RFC are accepted when parsing is controlled
+    /// by [`ParseStrictness`]. When formatting, fractional seconds are
+    /// included using the fewest of 3, 6, or 9 digits that represent the value
+    /// exactly, and omitted when the value has no subsecond component. A fixed
+    /// number of digits can be requested with
+    /// [`OffsetDateTime::format_rfc3339_with_precision`](crate::OffsetDateTime::format_rfc3339_with_precision).
     Rfc3339,
     Custom(String),
     #[cfg(not(__time_02_supports_non_exhaustive))]
@@ -16,6 +25,115 @@ pub enum Format {
     __NonExhaustive,
 }
 
+/// How closely input must follow a well-known format when parsing.
+///
+/// This only affects well-known formats such as [`Format::Rfc3339`]. Custom
+/// formats are always matched exactly as written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseStrictness {
+    /// Only accept input that exactly follows the format. For RFC3339, the
+    /// date and time must be separated by `T`, UTC must be written as `Z` or
+    /// a numeric offset, an offset is required, and fractional seconds must
+    /// be separated by a period. As permitted by RFC3339, `T` and `Z` may be
+    /// lowercase.
+    ///
+    /// This is the behavior of the `parse` methods.
+    Strict,
+
+    /// Accept common deviations from the format. For RFC3339, the date and
+    /// time may also be separated by a space, a missing offset is assumed to
+    /// be UTC, the minutes of the offset may be omitted (`+05`), and
+    /// fractional seconds may be separated by a comma (the last two as
+    /// permitted by ISO 8601).
+    ///
+    /// The comma is only accepted by RFC3339 parsing. `%N` in a custom format
+    /// parses the digits alone, so the separator preceding it is written in
+    /// the format string (`%S,%N`) and is matched exactly.
+    ///
+    /// This must be requested explicitly, such as through
+    /// [`OffsetDateTime::parse_with_strictness`](crate::OffsetDateTime::parse_with_strictness).
+    Lenient,
+}
+
+/// The markers indicating whether a time is before or after noon, used when
+/// parsing `%p` and `%P`.
+///
+/// Markers are matched ignoring ASCII case, so [`AmPmMarkers::ENGLISH`]
+/// accepts both `am` and `AM`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmPmMarkers<'a> {
+    /// The marker for times before noon.
+    pub am: &'a str,
+    /// The marker for times at or after noon.
+    pub pm: &'a str,
+}
+
+impl AmPmMarkers<'static> {
+    /// The English markers, `AM` and `PM`.
+    pub const ENGLISH: Self = Self { am: "AM", pm: "PM" };
+}
+
+impl Default for AmPmMarkers<'static> {
+    fn default() -> Self {
+        Self::ENGLISH
+    }
+}
+
+/// The time zone abbreviations recognized when parsing `%Z`, along with the
+/// fixed offset each one represents.
+///
+/// Abbreviations are matched exactly, including case. `UTC` and `GMT` are
+/// always recognized, and may be followed by an offset in the form `±HH:MM`.
+///
+/// Abbreviations listed in `ambiguous` that are not also in `known` are
+/// rejected with [`ParseError::AmbiguousZoneAbbreviation`](crate::ParseError),
+/// rather than being assigned one of their possible meanings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZoneAbbreviations<'a> {
+    /// Abbreviations and the offset they represent.
+    pub known: &'a [(&'a str, UtcOffset)],
+    /// Abbreviations with more than one meaning in common use.
+    pub ambiguous: &'a [&'a str],
+}
+
+impl ZoneAbbreviations<'static> {
+    /// Common abbreviations for Europe, North America, and parts of Asia and
+    /// Australia. `CST`, `IST`, `BST`, and `AST` are treated as ambiguous.
+    pub const DEFAULT: Self = Self {
+        known: &[
+            ("UT", UtcOffset::UTC),
+            ("WET", UtcOffset::UTC),
+            ("WEST", UtcOffset::hours(1)),
+            ("CET", UtcOffset::hours(1)),
+            ("CEST", UtcOffset::hours(2)),
+            ("EET", UtcOffset::hours(2)),
+            ("EEST", UtcOffset::hours(3)),
+            ("MSK", UtcOffset::hours(3)),
+            ("EST", UtcOffset::hours(-5)),
+            ("EDT", UtcOffset::hours(-4)),
+            ("CDT", UtcOffset::hours(-5)),
+            ("MST", UtcOffset::hours(-7)),
+            ("MDT", UtcOffset::hours(-6)),
+            ("PST", UtcOffset::hours(-8)),
+            ("PDT", UtcOffset::hours(-7)),
+            ("AKST", UtcOffset::hours(-9)),
+            ("AKDT", UtcOffset::hours(-8)),
+            ("HST", UtcOffset::hours(-10)),
+            ("JST", UtcOffset::hours(9)),
+            ("KST", UtcOffset::hours(9)),
+            ("AEST", UtcOffset::hours(10)),
+            ("AEDT", UtcOffset::hours(11)),
+        ],
+        ambiguous: &["CST", "IST", "BST", "AST"],
+    };
+}
+
+impl Default for ZoneAbbreviations<'static> {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 // TODO We're only using `AsRef` for back-compatibility. In 0.3, switch this to
 // `Into<Cow<'a, str>>`, which is both broader and avoids unnecessary clones.
 // This will require the addition of a lifetime to the `Format` struct.
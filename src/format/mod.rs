@@ -7,6 +7,7 @@ macro_rules! pad {
             Padding::None => write!($f, "{}", $value),
             Padding::Space => write!($f, concat!("{:", stringify!($width), "}"), $value),
             Padding::Zero => write!($f, concat!("{:0", stringify!($width), "}"), $value),
+            Padding::Width(width) => write!($f, "{:01$}", $value, width as usize),
         }
     };
 }
@@ -19,13 +20,14 @@ pub(crate) mod offset;
 pub(crate) mod parse;
 pub(crate) mod parse_items;
 pub(crate) mod time;
+pub(crate) mod timestamp;
 pub(crate) mod well_known;
 
 use crate::{Date, Time, UtcOffset};
 use core::fmt::{self, Formatter};
 pub(crate) use deferred_format::DeferredFormat;
 #[allow(unreachable_pub)] // rust-lang/rust#64762
-pub use format::Format;
+pub use format::{AmPmMarkers, Format, ParseStrictness, ZoneAbbreviations};
 pub(crate) use parse::{parse, ParseResult, ParsedItems};
 pub(crate) use parse_items::{parse_fmt_string, try_parse_fmt_string};
 
@@ -38,6 +40,9 @@ pub(crate) enum Padding {
     Space,
     /// Pad to the requisite width using zeros.
     Zero,
+    /// Pad to the provided width using zeros, overriding the requisite width.
+    /// When parsing, exactly this many digits are required.
+    Width(u8),
 }
 
 /// Specifiers are similar to C's `strftime`, with some omissions and changes.
@@ -59,6 +64,7 @@ pub(crate) enum Specifier {
     C { padding: Padding },
     d { padding: Padding },
     D,
+    e { padding: Padding },
     F,
     g { padding: Padding },
     G { padding: Padding },
@@ -67,11 +73,14 @@ pub(crate) enum Specifier {
     j { padding: Padding },
     m { padding: Padding },
     M { padding: Padding },
-    N,
+    N { digits: Option<u8> },
+    o,
+    O,
     p,
     P,
     r,
     R,
+    s,
     S { padding: Padding },
     T,
     u,
@@ -82,10 +91,16 @@ pub(crate) enum Specifier {
     y { padding: Padding },
     Y { padding: Padding },
     z,
+    colon_z,
+    triple_colon_z,
+    hash_z,
+    Z,
+    Plus,
 }
 
 /// Given all the information necessary, write the provided specifier to the
 /// formatter.
+#[allow(clippy::too_many_lines)]
 fn format_specifier(
     f: &mut Formatter<'_>,
     date: Option<Date>,
@@ -146,6 +161,7 @@ fn format_specifier(
             literal!("/");
             specifier!(date::fmt_y(y, Padding::Zero));
         }
+        e { padding } => specifier!(date::fmt_d(e, padding)),
         F => {
             specifier!(date::fmt_Y(Y, Padding::None));
             literal!("-");
@@ -160,7 +176,9 @@ fn format_specifier(
         j { padding } => specifier!(date::fmt_j(j, padding)),
         m { padding } => specifier!(date::fmt_m(m, padding)),
         M { padding } => specifier!(time::fmt_M(M, padding)),
-        N => specifier!(time::fmt_N(N)),
+        N { digits } => specifier!(time::fmt_N(N, digits.unwrap_or(9))),
+        o => specifier!(date::fmt_o(o)),
+        O => specifier!(date::fmt_O(O)),
         p => specifier!(time::fmt_p(p)),
         P => specifier!(time::fmt_P(P)),
         r => {
@@ -177,6 +195,18 @@ fn format_specifier(
             literal!(":");
             specifier!(time::fmt_M(M, Padding::Zero));
         }
+        s => {
+            /// Obtain a value required by `%s`, panicking if not present.
+            fn require<T>(value: Option<T>, kind: &str) -> T {
+                value.unwrap_or_else(|| panic!("Specifier `%s` requires a {} to be present.", kind))
+            }
+
+            timestamp::fmt_s(
+                f,
+                crate::PrimitiveDateTime::new(require(date, "date"), require(time, "time"))
+                    .assume_offset(require(offset, "offset")),
+            )?;
+        }
         S { padding } => specifier!(time::fmt_S(S, padding)),
         T => {
             specifier!(time::fmt_H(H, Padding::None));
@@ -193,6 +223,24 @@ fn format_specifier(
         y { padding } => specifier!(date::fmt_y(y, padding)),
         Y { padding } => specifier!(date::fmt_Y(Y, padding)),
         z => specifier!(offset::fmt_z(z)),
+        colon_z => specifier!(offset::fmt_colon_z(z)),
+        triple_colon_z => specifier!(offset::fmt_triple_colon_z(z)),
+        hash_z => specifier!(offset::fmt_hash_z(z)),
+        Z => specifier!(offset::fmt_Z(Z)),
+        Plus => {
+            specifier!(date::fmt_Y(Y, Padding::Zero));
+            literal!("-");
+            specifier!(date::fmt_m(m, Padding::Zero));
+            literal!("-");
+            specifier!(date::fmt_d(d, Padding::Zero));
+            literal!("T");
+            specifier!(time::fmt_H(H, Padding::Zero));
+            literal!(":");
+            specifier!(time::fmt_M(M, Padding::Zero));
+            literal!(":");
+            specifier!(time::fmt_S(S, Padding::Zero));
+            specifier!(offset::fmt_z(z));
+        }
     }
 
     Ok(())
@@ -206,4 +254,9 @@ pub(crate) enum FormatItem<'a> {
     Literal(&'a str),
     /// A value that needs to be interpreted when formatting.
     Specifier(Specifier),
+    /// The start of a section that is skipped when parsing if it does not
+    /// match the input.
+    OptionalStart,
+    /// The end of an optional section.
+    OptionalEnd,
 }
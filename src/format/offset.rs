@@ -5,10 +5,10 @@
 use crate::{
     error,
     format::{
-        parse::{try_consume_exact_digits, try_consume_first_match},
+        parse::{try_consume_char, try_consume_exact_digits, try_consume_first_match},
         Padding, ParsedItems,
     },
-    ParseResult, UtcOffset,
+    ParseResult, UtcOffset, ZoneAbbreviations,
 };
 use core::fmt::{self, Formatter};
 
@@ -27,15 +27,189 @@ pub(crate) fn fmt_z(f: &mut Formatter<'_>, offset: UtcOffset) -> fmt::Result {
 
 /// UTC offset
 pub(crate) fn parse_z(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
-    let sign = try_consume_first_match(s, [("+", 1), ("-", -1)].iter().cloned())
+    let negative = try_consume_first_match(s, [("+", false), ("-", true)].iter().cloned())
         .ok_or(error::Parse::InvalidOffset)?;
 
-    let hours: i16 =
+    let hours: u8 =
         try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?;
 
-    let minutes: i16 =
+    let minutes: u8 =
         try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?;
 
-    items.offset = UtcOffset::minutes(sign * (hours * 60 + minutes)).into();
+    items.offset = Some(UtcOffset::from_signed_hms(negative, hours, minutes, 0)?);
     Ok(())
 }
+
+/// UTC offset, with a colon separating the hours and minutes
+pub(crate) fn fmt_colon_z(f: &mut Formatter<'_>, offset: UtcOffset) -> fmt::Result {
+    let offset = offset.as_duration();
+
+    write!(
+        f,
+        "{}{:02}:{:02}",
+        if offset.is_negative() { '-' } else { '+' },
+        offset.whole_hours().abs(),
+        (offset.whole_minutes() - 60 * offset.whole_hours()).abs()
+    )
+}
+
+/// UTC offset, with a colon separating the hours and minutes
+pub(crate) fn parse_colon_z(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    let negative = try_consume_first_match(s, [("+", false), ("-", true)].iter().cloned())
+        .ok_or(error::Parse::InvalidOffset)?;
+
+    let hours: u8 =
+        try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?;
+
+    try_consume_char(s, ':').map_err(|_| error::Parse::InvalidOffset)?;
+
+    let minutes: u8 =
+        try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?;
+
+    items.offset = Some(UtcOffset::from_signed_hms(negative, hours, minutes, 0)?);
+    Ok(())
+}
+
+/// UTC offset, with the minutes only present if they are nonzero (`+05` or
+/// `+05:30`)
+pub(crate) fn fmt_triple_colon_z(f: &mut Formatter<'_>, offset: UtcOffset) -> fmt::Result {
+    let is_whole_hours = offset.as_seconds().rem_euclid(3_600) == 0;
+    let offset = offset.as_duration();
+
+    write!(
+        f,
+        "{}{:02}",
+        if offset.is_negative() { '-' } else { '+' },
+        offset.whole_hours().abs(),
+    )?;
+    if is_whole_hours {
+        return Ok(());
+    }
+    write!(
+        f,
+        ":{:02}",
+        (offset.whole_minutes() - 60 * offset.whole_hours()).abs()
+    )
+}
+
+/// UTC offset, with the minutes optional (`+05` or `+05:30`)
+pub(crate) fn parse_triple_colon_z(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    let negative = try_consume_first_match(s, [("+", false), ("-", true)].iter().cloned())
+        .ok_or(error::Parse::InvalidOffset)?;
+
+    let hours: u8 =
+        try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?;
+
+    let minutes: u8 = if try_consume_char(s, ':').is_ok() {
+        try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?
+    } else {
+        0
+    };
+
+    items.offset = Some(UtcOffset::from_signed_hms(negative, hours, minutes, 0)?);
+    Ok(())
+}
+
+/// Military time zone letter (`A`-`Z`, excluding `J`)
+///
+/// Offsets that are not a whole number of hours have no letter, so are
+/// written as `+HHMM` instead.
+pub(crate) fn fmt_hash_z(f: &mut Formatter<'_>, offset: UtcOffset) -> fmt::Result {
+    let hours = offset.as_hours();
+    let letter = match hours {
+        _ if offset.as_seconds() % 3_600 != 0 => None,
+        0 => Some('Z'),
+        1..=9 => Some((b'A' + hours as u8 - 1) as char),
+        10..=12 => Some((b'K' + hours as u8 - 10) as char),
+        -12..=-1 => Some((b'N' + (-hours) as u8 - 1) as char),
+        _ => None,
+    };
+
+    match letter {
+        Some(letter) => write!(f, "{}", letter),
+        None => fmt_z(f, offset),
+    }
+}
+
+/// Military time zone letter (`A`-`Z`, excluding `J`), or `+HHMM` for
+/// offsets that have no letter
+pub(crate) fn parse_hash_z(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    if s.starts_with(&['+', '-'][..]) {
+        return parse_z(items, s);
+    }
+
+    let offset = s
+        .chars()
+        .next()
+        .and_then(UtcOffset::from_military_letter)
+        .ok_or(error::Parse::InvalidOffset)?;
+    *s = &s[1..];
+
+    items.offset = Some(offset);
+    Ok(())
+}
+
+/// Zone abbreviation: `UTC` for a zero offset, otherwise `UTC` followed by the
+/// offset (`UTC+05:30`, or `UTC+05:30:15` if the offset contains seconds)
+pub(crate) fn fmt_Z(f: &mut Formatter<'_>, offset: UtcOffset) -> fmt::Result {
+    if offset == UtcOffset::UTC {
+        f.write_str("UTC")
+    } else {
+        write!(f, "UTC{}", offset.display_iso8601())
+    }
+}
+
+/// Zone abbreviation: `UTC` or `GMT`, optionally followed by an offset in the
+/// form `±HH:MM` or `±HH:MM:SS`, or one of the provided abbreviations
+pub(crate) fn parse_Z(
+    items: &mut ParsedItems,
+    s: &mut &str,
+    zones: ZoneAbbreviations<'_>,
+) -> ParseResult<()> {
+    let len = s
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(s.len());
+    let abbreviation = &s[..len];
+
+    if abbreviation == "UTC" || abbreviation == "GMT" {
+        *s = &s[len..];
+        items.offset = Some(
+            match try_consume_first_match(s, [("+", false), ("-", true)].iter().cloned()) {
+                Some(negative) => parse_Z_offset(s, negative)?,
+                None => UtcOffset::UTC,
+            },
+        );
+        return Ok(());
+    }
+
+    let known = zones.known.iter().find(|&&(name, _)| name == abbreviation);
+    items.offset = match known {
+        Some(&(_, offset)) => Some(offset),
+        None if zones.ambiguous.contains(&abbreviation) => {
+            return Err(error::Parse::AmbiguousZoneAbbreviation)
+        }
+        None => return Err(error::Parse::InvalidOffset),
+    };
+    *s = &s[len..];
+    Ok(())
+}
+
+/// The offset following `UTC` or `GMT`, after its sign, in the form `HH:MM` or
+/// `HH:MM:SS`
+fn parse_Z_offset(s: &mut &str, negative: bool) -> ParseResult<UtcOffset> {
+    let hours: u8 =
+        try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?;
+    try_consume_char(s, ':').map_err(|_| error::Parse::InvalidOffset)?;
+    let minutes: u8 =
+        try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?;
+    // The seconds are only formatted when nonzero.
+    let seconds: u8 = if try_consume_char(s, ':').is_ok() {
+        try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?
+    } else {
+        0
+    };
+
+    Ok(UtcOffset::from_signed_hms(
+        negative, hours, minutes, seconds,
+    )?)
+}
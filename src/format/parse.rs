@@ -3,10 +3,10 @@
 use crate::{
     error,
     format::{parse_fmt_string, well_known, FormatItem, Padding, Specifier},
-    Format, UtcOffset, Weekday,
+    AmPmMarkers, Format, OffsetDateTime, ParseStrictness, UtcOffset, Weekday, ZoneAbbreviations,
 };
 #[cfg(not(feature = "std"))]
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 use core::{
     fmt::{self, Display, Formatter},
     num::{NonZeroU16, NonZeroU8},
@@ -45,6 +45,13 @@ pub enum Error {
     InvalidDayOfYear,
     /// The UTC offset present was not valid.
     InvalidOffset,
+    /// The Unix timestamp present was not valid.
+    InvalidTimestamp,
+    /// The same component was parsed more than once, with differing values.
+    /// The value is a description of the component.
+    ConflictingComponent(&'static str),
+    /// The time zone abbreviation present has more than one meaning.
+    AmbiguousZoneAbbreviation,
     /// There was no character following a `%`.
     MissingFormatSpecifier,
     /// The character following `%` is not valid.
@@ -58,6 +65,8 @@ pub enum Error {
     },
     /// The string ended, but there should be more content.
     UnexpectedEndOfString,
+    /// The string continued after all expected content was parsed.
+    UnexpectedTrailingCharacters,
     /// There was not enough information provided to create the requested type.
     InsufficientInformation,
     /// A component was out of range.
@@ -89,12 +98,18 @@ impl Display for Error {
             InvalidDayOfMonth => f.write_str("invalid day of month"),
             InvalidDayOfYear => f.write_str("invalid day of year"),
             InvalidOffset => f.write_str("invalid offset"),
+            InvalidTimestamp => f.write_str("invalid timestamp"),
+            ConflictingComponent(component) => {
+                write!(f, "conflicting values provided for the {}", component)
+            }
+            AmbiguousZoneAbbreviation => f.write_str("ambiguous time zone abbreviation"),
             MissingFormatSpecifier => f.write_str("missing format specifier after `%`"),
             InvalidFormatSpecifier(c) => write!(f, "invalid format specifier `{}` after `%`", c),
             UnexpectedCharacter { expected, actual } => {
                 write!(f, "expected character `{}`, found `{}`", expected, actual)
             }
             UnexpectedEndOfString => f.write_str("unexpected end of string"),
+            UnexpectedTrailingCharacters => f.write_str("unexpected trailing characters"),
             InsufficientInformation => {
                 f.write_str("insufficient information provided to create the requested type")
             }
@@ -145,6 +160,10 @@ pub(crate) struct ParsedItems {
     pub(crate) sunday_week: Option<u8>,
     /// Week number, counted from the first Monday. May be zero.
     pub(crate) monday_week: Option<u8>,
+    /// Week of the month, where weeks begin on Sunday.
+    pub(crate) sunday_week_of_month: Option<NonZeroU8>,
+    /// Week of the month, where weeks begin on Monday.
+    pub(crate) monday_week_of_month: Option<NonZeroU8>,
     /// Hour in the 12-hour clock.
     pub(crate) hour_12: Option<NonZeroU8>,
     /// Hour in the 24-hour clock.
@@ -159,6 +178,9 @@ pub(crate) struct ParsedItems {
     pub(crate) offset: Option<UtcOffset>,
     /// Whether the hour indicated is AM or PM.
     pub(crate) am_pm: Option<AmPm>,
+    /// Whole seconds since the Unix epoch. The components it determines are
+    /// only filled in once parsing is complete, as they depend on the offset.
+    pub(crate) timestamp: Option<i64>,
 }
 
 impl ParsedItems {
@@ -174,6 +196,8 @@ impl ParsedItems {
             iso_week: None,
             sunday_week: None,
             monday_week: None,
+            sunday_week_of_month: None,
+            monday_week_of_month: None,
             hour_12: None,
             hour_24: None,
             minute: None,
@@ -181,7 +205,81 @@ impl ParsedItems {
             nanosecond: None,
             offset: None,
             am_pm: None,
+            timestamp: None,
+        }
+    }
+
+    /// Find a component that was already known in `previous`, but has since
+    /// been changed to a different value. If `ignore_year` is set, the year
+    /// and week-based year are not checked.
+    fn conflicting_component(&self, previous: &Self, ignore_year: bool) -> Option<&'static str> {
+        macro_rules! check {
+            ($($field:ident => $description:literal),* $(,)?) => {
+                $(
+                    if previous.$field.is_some() && self.$field != previous.$field {
+                        return Some($description);
+                    }
+                )*
+            };
+        }
+
+        if !ignore_year {
+            check! {
+                week_based_year => "week-based year",
+                year => "year",
+            }
         }
+        check! {
+            month => "month",
+            day => "day of month",
+            weekday => "day of week",
+            ordinal_day => "day of year",
+            iso_week => "ISO week",
+            sunday_week => "Sunday-based week",
+            monday_week => "Monday-based week",
+            sunday_week_of_month => "Sunday-based week of month",
+            monday_week_of_month => "Monday-based week of month",
+            hour_12 => "12-hour clock hour",
+            hour_24 => "24-hour clock hour",
+            minute => "minute",
+            second => "second",
+            nanosecond => "nanosecond",
+            offset => "UTC offset",
+            am_pm => "AM/PM",
+            timestamp => "Unix timestamp",
+        }
+
+        None
+    }
+
+    /// Fill in the components determined by a timestamp. They are obtained in
+    /// the parsed offset, or UTC if there is none, and must agree with any
+    /// components that were parsed directly.
+    fn apply_timestamp(&mut self, timestamp: i64) -> ParseResult<()> {
+        let offset = self.offset.unwrap_or(UtcOffset::UTC);
+        let datetime = OffsetDateTime::from_unix_timestamp(timestamp).to_offset(offset);
+
+        let mut known = Self::new();
+        known.year = Some(datetime.year());
+        known.month = NonZeroU8::new(datetime.month());
+        known.day = NonZeroU8::new(datetime.day());
+        known.hour_24 = Some(datetime.hour());
+        known.minute = Some(datetime.minute());
+        known.second = Some(datetime.second());
+        known.offset = Some(offset);
+
+        self.year = self.year.or(known.year);
+        self.month = self.month.or(known.month);
+        self.day = self.day.or(known.day);
+        self.hour_24 = self.hour_24.or(known.hour_24);
+        self.minute = self.minute.or(known.minute);
+        self.second = self.second.or(known.second);
+        self.offset = self.offset.or(known.offset);
+
+        self.conflicting_component(&known, false)
+            .map_or(Ok(()), |component| {
+                Err(Error::ConflictingComponent(component))
+            })
     }
 }
 
@@ -285,6 +383,11 @@ pub(crate) fn try_consume_exact_digits<T: FromStr>(
     num_digits: usize,
     padding: Padding,
 ) -> Option<T> {
+    // An explicit width overrides the default number of digits.
+    let num_digits = match padding {
+        Padding::Width(width) => width.into(),
+        _ => num_digits,
+    };
     let pad_size = match padding {
         Padding::Space => consume_padding(s, padding, num_digits - 1),
         _ => 0,
@@ -323,7 +426,7 @@ pub(crate) fn consume_padding(s: &mut &str, padding: Padding, max_chars: usize)
     let pad_char = match padding {
         Padding::Space => ' ',
         Padding::Zero => '0',
-        Padding::None => return 0,
+        Padding::None | Padding::Width(_) => return 0,
     };
 
     let pad_width = s
@@ -335,38 +438,128 @@ pub(crate) fn consume_padding(s: &mut &str, padding: Padding, max_chars: usize)
     pad_width
 }
 
+/// Skip the remainder of an optional section, including any nested sections.
+fn skip_optional_section<'a, I: Iterator<Item = FormatItem<'a>>>(format_items: &mut I) {
+    let mut depth = 1_usize;
+    for item in format_items {
+        match item {
+            FormatItem::OptionalStart => depth += 1,
+            FormatItem::OptionalEnd => {
+                depth -= 1;
+                if depth == 0 {
+                    return;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Attempt to parse the string with the provided format, returning a struct
-/// containing all information found.
-#[allow(clippy::too_many_lines)]
+/// containing all information found. Well-known formats are parsed strictly.
 pub(crate) fn parse(s: &str, format: &Format) -> ParseResult<ParsedItems> {
-    use super::{date, offset, time};
+    parse_with_strictness(s, format, ParseStrictness::Strict)
+}
 
-    // Make a copy of the provided string, letting us mutate as necessary.
-    let mut s = <&str>::clone(&s);
+/// Attempt to parse the string with the provided format, following
+/// well-known formats as closely as `strictness` requires.
+pub(crate) fn parse_with_strictness(
+    s: &str,
+    format: &Format,
+    strictness: ParseStrictness,
+) -> ParseResult<ParsedItems> {
+    parse_with_options(s, format, strictness, None, None)
+}
 
-    let mut items = ParsedItems::new();
+/// Attempt to parse the string with the provided format, using `am_pm` in
+/// place of the default markers for `%p` and `%P` if present.
+pub(crate) fn parse_with_am_pm(
+    s: &str,
+    format: &Format,
+    am_pm: AmPmMarkers<'_>,
+) -> ParseResult<ParsedItems> {
+    parse_with_options(s, format, ParseStrictness::Strict, Some(am_pm), None)
+}
 
-    /// Parse the provided specifier with the given parameters.
-    macro_rules! parse {
-        ($module:ident :: $specifier_fn:ident $( ( $($params:expr),* ) )?) => {
-            $module::$specifier_fn(&mut items, &mut s, $( $($params),* )?)?
-        };
-    }
+/// Attempt to parse the string with the provided format, using `zones` in
+/// place of the default abbreviations for `%Z`.
+pub(crate) fn parse_with_zones(
+    s: &str,
+    format: &Format,
+    zones: ZoneAbbreviations<'_>,
+) -> ParseResult<ParsedItems> {
+    parse_with_options(s, format, ParseStrictness::Strict, None, Some(zones))
+}
 
-    macro_rules! parse_char {
-        ($c:literal) => {
-            try_consume_char(&mut s, $c)?
-        };
-    }
+/// Attempt to parse the string with the provided format and all options
+/// specified.
+#[allow(clippy::too_many_lines)]
+fn parse_with_options(
+    s: &str,
+    format: &Format,
+    strictness: ParseStrictness,
+    am_pm: Option<AmPmMarkers<'_>>,
+    zones: Option<ZoneAbbreviations<'_>>,
+) -> ParseResult<ParsedItems> {
+    use super::{date, offset, time, timestamp};
+
+    // Make a copy of the provided string, letting us mutate as necessary.
+    let mut input = <&str>::clone(&s);
+
+    let mut items = ParsedItems::new();
 
     match &format {
-        Format::Rfc3339 => well_known::rfc3339::parse(&mut items, &mut s)?,
+        Format::Rfc3339 => well_known::rfc3339::parse(&mut items, &mut input, strictness)?,
         Format::Custom(format) => {
-            for item in parse_fmt_string(format) {
+            let mut format_items = parse_fmt_string(format).into_iter().peekable();
+            // The input and items at the start of each optional section that
+            // is currently being parsed, restored if the section fails.
+            let mut optional_sections: Vec<(&str, ParsedItems)> = Vec::new();
+
+            /// Propagate an error, unless it occurred within an optional
+            /// section. In that case, the section is backtracked and skipped.
+            macro_rules! try_parse {
+                ($result:expr) => {
+                    if let Err(err) = $result {
+                        match optional_sections.pop() {
+                            Some((section_input, section_items)) => {
+                                input = section_input;
+                                items = section_items;
+                                skip_optional_section(&mut format_items);
+                                #[allow(clippy::needless_continue)]
+                                continue;
+                            }
+                            None => return Err(err),
+                        }
+                    }
+                };
+            }
+
+            /// Parse the provided specifier with the given parameters.
+            macro_rules! parse {
+                ($module:ident :: $specifier_fn:ident $( ( $($params:expr),* ) )?) => {
+                    try_parse!($module::$specifier_fn(&mut items, &mut input, $( $($params),* )?))
+                };
+            }
+
+            macro_rules! parse_char {
+                ($c:literal) => {
+                    try_parse!(try_consume_char(&mut input, $c))
+                };
+            }
+
+            while let Some(item) = format_items.next() {
                 match item {
-                    FormatItem::Literal(expected) => try_consume_str(&mut s, expected)?,
+                    FormatItem::Literal(expected) => {
+                        try_parse!(try_consume_str(&mut input, expected));
+                    }
+                    FormatItem::OptionalStart => optional_sections.push((input, items)),
+                    FormatItem::OptionalEnd => {
+                        let _ = optional_sections.pop();
+                    }
                     FormatItem::Specifier(specifier) => {
                         use Specifier::*;
+                        let previous = items;
                         match specifier {
                             a => parse!(date::parse_a),
                             A => parse!(date::parse_A),
@@ -396,6 +589,7 @@ pub(crate) fn parse(s: &str, format: &Format) -> ParseResult<ParsedItems> {
                                 parse_char!('/');
                                 parse!(date::parse_y(Padding::Zero));
                             }
+                            e { .. } => parse!(date::parse_e),
                             F => {
                                 parse!(date::parse_Y(Padding::None));
                                 parse_char!('-');
@@ -410,9 +604,17 @@ pub(crate) fn parse(s: &str, format: &Format) -> ParseResult<ParsedItems> {
                             j { padding } => parse!(date::parse_j(padding)),
                             M { padding } => parse!(time::parse_M(padding)),
                             m { padding } => parse!(date::parse_m(padding)),
-                            N => parse!(time::parse_N),
-                            p => parse!(time::parse_p),
-                            P => parse!(time::parse_P),
+                            N { digits } => parse!(time::parse_N(digits)),
+                            o => parse!(date::parse_o),
+                            O => parse!(date::parse_O),
+                            p => match am_pm {
+                                Some(markers) => parse!(time::parse_am_pm_markers(markers)),
+                                None => parse!(time::parse_p),
+                            },
+                            P => match am_pm {
+                                Some(markers) => parse!(time::parse_am_pm_markers(markers)),
+                                None => parse!(time::parse_P),
+                            },
                             r => {
                                 parse!(time::parse_I(Padding::None));
                                 parse_char!(':');
@@ -420,13 +622,24 @@ pub(crate) fn parse(s: &str, format: &Format) -> ParseResult<ParsedItems> {
                                 parse_char!(':');
                                 parse!(time::parse_S(Padding::Zero));
                                 parse_char!(' ');
-                                parse!(time::parse_p);
+                                match am_pm {
+                                    Some(markers) => parse!(time::parse_am_pm_markers(markers)),
+                                    None => parse!(time::parse_p),
+                                }
                             }
                             R => {
                                 parse!(time::parse_H(Padding::None));
                                 parse_char!(':');
                                 parse!(time::parse_M(Padding::Zero));
                             }
+                            s => {
+                                // An explicit fractional part takes precedence.
+                                let allow_fraction = match format_items.peek() {
+                                    Some(FormatItem::Literal(literal)) => !literal.starts_with('.'),
+                                    _ => true,
+                                };
+                                parse!(timestamp::parse_s(allow_fraction));
+                            }
                             S { padding } => parse!(time::parse_S(padding)),
                             T => {
                                 parse!(time::parse_H(Padding::None));
@@ -442,7 +655,40 @@ pub(crate) fn parse(s: &str, format: &Format) -> ParseResult<ParsedItems> {
                             W { padding } => parse!(date::parse_W(padding)),
                             y { padding } => parse!(date::parse_y(padding)),
                             z => parse!(offset::parse_z),
+                            colon_z => parse!(offset::parse_colon_z),
+                            triple_colon_z => parse!(offset::parse_triple_colon_z),
+                            hash_z => parse!(offset::parse_hash_z),
+                            Z => {
+                                let zones = zones.unwrap_or(ZoneAbbreviations::DEFAULT);
+                                parse!(offset::parse_Z(zones));
+                            }
                             Y { padding } => parse!(date::parse_Y(padding)),
+                            Plus => {
+                                parse!(date::parse_Y(Padding::Zero));
+                                parse_char!('-');
+                                parse!(date::parse_m(Padding::Zero));
+                                parse_char!('-');
+                                parse!(date::parse_d(Padding::Zero));
+                                parse_char!('T');
+                                parse!(time::parse_H(Padding::Zero));
+                                parse_char!(':');
+                                parse!(time::parse_M(Padding::Zero));
+                                parse_char!(':');
+                                parse!(time::parse_S(Padding::Zero));
+                                parse!(offset::parse_z);
+                            }
+                        }
+
+                        // Partial years are combined with any year already
+                        // present, so are not considered to conflict with it.
+                        #[allow(clippy::match_like_matches_macro)] // `matches!` requires rustc 1.42
+                        let ignore_year = match specifier {
+                            C { .. } | D | g { .. } | y { .. } => true,
+                            _ => false,
+                        };
+                        if let Some(component) = items.conflicting_component(&previous, ignore_year)
+                        {
+                            return Err(Error::ConflictingComponent(component));
                         }
                     }
                 }
@@ -452,5 +698,8 @@ pub(crate) fn parse(s: &str, format: &Format) -> ParseResult<ParsedItems> {
         Format::__NonExhaustive => unreachable!(),
     }
 
+    if let Some(timestamp) = items.timestamp {
+        items.apply_timestamp(timestamp)?;
+    }
     Ok(items)
 }
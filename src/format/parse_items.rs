@@ -4,6 +4,11 @@ use crate::format::{FormatItem, Padding, Specifier};
 #[cfg(not(feature = "std"))]
 use alloc::{format, string::String, vec::Vec};
 
+/// The specifiers that accept an explicit width, as in `%04Y`.
+const WIDTH_SPECIFIERS: &[char] = &[
+    'C', 'd', 'g', 'G', 'H', 'I', 'j', 'm', 'M', 'S', 'U', 'V', 'W', 'y', 'Y',
+];
+
 /// Parse the formatting string. Panics if not valid.
 pub(crate) fn parse_fmt_string<'a>(s: &'a str) -> Vec<FormatItem<'a>> {
     match try_parse_fmt_string(s) {
@@ -18,6 +23,8 @@ pub(crate) fn try_parse_fmt_string<'a>(s: &'a str) -> Result<Vec<FormatItem<'a>>
     let mut items = Vec::new();
     let mut literal_start = 0;
     let mut chars = s.char_indices().peekable();
+    // The number of optional sections that are currently open.
+    let mut optional_depth = 0_usize;
 
     while let Some((i, c)) = chars.next() {
         /// Push the provided specifier to the list of items.
@@ -28,6 +35,15 @@ pub(crate) fn try_parse_fmt_string<'a>(s: &'a str) -> Result<Vec<FormatItem<'a>>
             }};
         }
 
+        /// Push the provided items to the list, in place of a single specifier
+        /// that is an alias for them.
+        macro_rules! push_alias {
+            ($i:ident, $($item:expr),+ $(,)?) => {{
+                literal_start = $i + 1;
+                $(items.push($item);)+
+            }};
+        }
+
         if c == '%' {
             // Avoid adding unnecessary empty strings.
             if literal_start != i {
@@ -52,10 +68,49 @@ pub(crate) fn try_parse_fmt_string<'a>(s: &'a str) -> Result<Vec<FormatItem<'a>>
                 _ => None,
             };
 
+            // The number of digits for `%N` precedes it, as in `%6N`. The same
+            // applies to chrono's `%f`.
+            let mut lookahead = chars.clone().map(|(_, c)| c);
+            let digits = match (lookahead.next(), lookahead.next()) {
+                (Some(digit @ '1'..='9'), Some(c)) if c == 'N' || c == 'f' => {
+                    let _ = chars.next();
+                    Some(digit as u8 - b'0')
+                }
+                _ => None,
+            };
+
+            // An explicit zero-padded width precedes the specifier, as in `%04Y`.
+            let mut width: Option<u8> = None;
+            while let Some(&(_, digit @ '0'..='9')) = chars.peek() {
+                let _ = chars.next();
+                width = width
+                    .unwrap_or(0)
+                    .checked_mul(10)
+                    .and_then(|width| width.checked_add(digit as u8 - b'0'));
+                if width.is_none() {
+                    return Err(String::from("Width must be less than 256."));
+                }
+            }
+            let padding = match (width, padding) {
+                (None, padding) => padding,
+                (Some(0), _) => return Err(String::from("Width must be nonzero.")),
+                (Some(_), Some(padding)) if padding != Padding::Zero => {
+                    return Err(String::from(
+                        "A width may only be combined with the `0` modifier.",
+                    ))
+                }
+                (Some(width), _) => match chars.peek().map(|v| v.1) {
+                    Some(c) if WIDTH_SPECIFIERS.contains(&c) => Some(Padding::Width(width)),
+                    Some(c) => return Err(format!("Specifier `{}` does not accept a width", c)),
+                    None => None,
+                },
+            };
+
             match chars.next() {
                 Some((i, 'a')) => push_specifier!(i, Specifier::a),
                 Some((i, 'A')) => push_specifier!(i, Specifier::A),
-                Some((i, 'b')) => push_specifier!(i, Specifier::b),
+                // `%h` is an alias used by chrono.
+                Some((i, c)) if c == 'b' || c == 'h' => push_specifier!(i, Specifier::b),
                 Some((i, 'B')) => push_specifier!(i, Specifier::B),
                 Some((i, 'c')) => push_specifier!(i, Specifier::c),
                 Some((i, 'C')) => push_specifier!(
@@ -71,6 +126,12 @@ pub(crate) fn try_parse_fmt_string<'a>(s: &'a str) -> Result<Vec<FormatItem<'a>>
                     }
                 ),
                 Some((i, 'D')) => push_specifier!(i, Specifier::D),
+                Some((i, 'e')) => push_specifier!(
+                    i,
+                    Specifier::e {
+                        padding: padding.unwrap_or(Padding::Space)
+                    }
+                ),
                 Some((i, 'F')) => push_specifier!(i, Specifier::F),
                 Some((i, 'g')) => push_specifier!(
                     i,
@@ -114,11 +175,15 @@ pub(crate) fn try_parse_fmt_string<'a>(s: &'a str) -> Result<Vec<FormatItem<'a>>
                         padding: padding.unwrap_or(Padding::Zero)
                     }
                 ),
-                Some((i, 'N')) => push_specifier!(i, Specifier::N),
+                // `%f` is an alias used by chrono.
+                Some((i, c)) if c == 'N' || c == 'f' => push_specifier!(i, Specifier::N { digits }),
+                Some((i, 'o')) => push_specifier!(i, Specifier::o),
+                Some((i, 'O')) => push_specifier!(i, Specifier::O),
                 Some((i, 'p')) => push_specifier!(i, Specifier::p),
                 Some((i, 'P')) => push_specifier!(i, Specifier::P),
                 Some((i, 'r')) => push_specifier!(i, Specifier::r),
                 Some((i, 'R')) => push_specifier!(i, Specifier::R),
+                Some((i, 's')) => push_specifier!(i, Specifier::s),
                 Some((i, 'S')) => push_specifier!(
                     i,
                     Specifier::S {
@@ -159,7 +224,115 @@ pub(crate) fn try_parse_fmt_string<'a>(s: &'a str) -> Result<Vec<FormatItem<'a>>
                     }
                 ),
                 Some((i, 'z')) => push_specifier!(i, Specifier::z),
+                Some((i, 'Z')) => push_specifier!(i, Specifier::Z),
+                Some((_, ':')) => match chars.next() {
+                    Some((i, 'z')) => push_specifier!(i, Specifier::colon_z),
+                    Some((_, ':')) => match (chars.next(), chars.next()) {
+                        (Some((_, ':')), Some((i, 'z'))) => {
+                            push_specifier!(i, Specifier::triple_colon_z);
+                        }
+                        _ => return Err(String::from("Expected `::z` after `%:`.")),
+                    },
+                    Some((_, c)) => return Err(format!("Invalid specifier `:{}`", c)),
+                    None => return Err(String::from("Cannot end formatting with `%:`.")),
+                },
+                Some((_, '#')) => match chars.next() {
+                    Some((i, 'z')) => push_specifier!(i, Specifier::hash_z),
+                    Some((_, c)) => return Err(format!("Invalid specifier `#{}`", c)),
+                    None => return Err(String::from("Cannot end formatting with `%#`.")),
+                },
+                Some((i, '+')) => push_specifier!(i, Specifier::Plus),
+                // Aliases for chrono's specifiers, expanded to their equivalents.
+                Some((_, '.')) => match (chars.next(), chars.next()) {
+                    (Some((_, digit @ '1'..='9')), Some((i, 'f'))) => push_alias!(
+                        i,
+                        FormatItem::Literal("."),
+                        FormatItem::Specifier(Specifier::N {
+                            digits: Some(digit as u8 - b'0')
+                        }),
+                    ),
+                    _ => return Err(String::from("Expected a digit and `f` after `%.`.")),
+                },
+                Some((i, 'k')) => push_specifier!(
+                    i,
+                    Specifier::H {
+                        padding: padding.unwrap_or(Padding::Space)
+                    }
+                ),
+                Some((i, 'l')) => push_specifier!(
+                    i,
+                    Specifier::I {
+                        padding: padding.unwrap_or(Padding::Space)
+                    }
+                ),
+                Some((i, 'n')) => push_alias!(i, FormatItem::Literal("\n")),
+                Some((i, 't')) => push_alias!(i, FormatItem::Literal("\t")),
+                Some((i, 'v')) => push_alias!(
+                    i,
+                    FormatItem::Specifier(Specifier::e {
+                        padding: Padding::Space
+                    }),
+                    FormatItem::Literal("-"),
+                    FormatItem::Specifier(Specifier::b),
+                    FormatItem::Literal("-"),
+                    FormatItem::Specifier(Specifier::Y {
+                        padding: Padding::Zero
+                    }),
+                ),
+                Some((i, 'x')) => push_alias!(
+                    i,
+                    FormatItem::Specifier(Specifier::m {
+                        padding: Padding::Zero
+                    }),
+                    FormatItem::Literal("/"),
+                    FormatItem::Specifier(Specifier::d {
+                        padding: Padding::Zero
+                    }),
+                    FormatItem::Literal("/"),
+                    FormatItem::Specifier(Specifier::y {
+                        padding: Padding::Zero
+                    }),
+                ),
+                Some((i, 'X')) => push_alias!(
+                    i,
+                    FormatItem::Specifier(Specifier::H {
+                        padding: Padding::Zero
+                    }),
+                    FormatItem::Literal(":"),
+                    FormatItem::Specifier(Specifier::M {
+                        padding: Padding::Zero
+                    }),
+                    FormatItem::Literal(":"),
+                    FormatItem::Specifier(Specifier::S {
+                        padding: Padding::Zero
+                    }),
+                ),
                 Some((i, '%')) => literal_start = i,
+                Some((i, '(')) => {
+                    literal_start = i + 1;
+                    optional_depth += 1;
+                    items.push(FormatItem::OptionalStart);
+                }
+                Some((i, ')')) => {
+                    if optional_depth == 0 {
+                        return Err(String::from("Unmatched `%)` without a preceding `%(`."));
+                    }
+                    literal_start = i + 1;
+                    optional_depth -= 1;
+                    items.push(FormatItem::OptionalEnd);
+                }
+                Some((i, '[')) => {
+                    // Everything up to the next `]` is literal, including `%`.
+                    let end = match s[i + 1..].find(']') {
+                        Some(len) => i + 1 + len,
+                        None => return Err(String::from("Unterminated literal block `%[`.")),
+                    };
+                    if end != i + 1 {
+                        items.push(FormatItem::Literal(&s[i + 1..end]));
+                    }
+                    let _ = chars.by_ref().find(|&(j, _)| j == end);
+                    literal_start = end + 1;
+                }
                 Some((_, c)) => return Err(format!("Invalid specifier `{}`", c)),
                 None => {
                     return Err(String::from(
@@ -171,6 +344,10 @@ pub(crate) fn try_parse_fmt_string<'a>(s: &'a str) -> Result<Vec<FormatItem<'a>>
         }
     }
 
+    if optional_depth != 0 {
+        return Err(String::from("Unterminated optional section `%(`."));
+    }
+
     if literal_start < s.len() {
         items.push(FormatItem::Literal(&s[literal_start..]));
     }
@@ -11,7 +11,7 @@ use crate::{
         },
         Padding, ParseResult, ParsedItems,
     },
-    Time,
+    AmPmMarkers, Time,
 };
 use core::{
     fmt::{self, Formatter},
@@ -58,15 +58,44 @@ pub(crate) fn parse_M(items: &mut ParsedItems, s: &mut &str, padding: Padding) -
     Ok(())
 }
 
-/// Subsecond nanoseconds. Always 9 digits
-pub(crate) fn fmt_N(f: &mut Formatter<'_>, time: Time) -> fmt::Result {
-    write!(f, "{:09}", time.nanosecond)
+/// Subsecond nanoseconds, truncated to the given number of digits (`1`-`9`)
+pub(crate) fn fmt_N(f: &mut Formatter<'_>, time: Time, digits: u8) -> fmt::Result {
+    write!(
+        f,
+        "{:0width$}",
+        time.nanosecond / 10_u32.pow(9 - digits as u32),
+        width = digits as usize
+    )
 }
 
-/// Subsecond nanoseconds. Always 9 digits
-pub(crate) fn parse_N(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
-    items.nanosecond =
-        Some(try_consume_exact_digits(s, 9, Padding::None).ok_or(error::Parse::InvalidNanosecond)?);
+/// Subsecond nanoseconds. When the number of digits (`1`-`9`) is provided,
+/// exactly that many are required. Otherwise, any number of digits is
+/// accepted, with those beyond the ninth being truncated.
+pub(crate) fn parse_N(
+    items: &mut ParsedItems,
+    s: &mut &str,
+    digits: Option<u8>,
+) -> ParseResult<()> {
+    if let Some(digits) = digits {
+        items.nanosecond = Some(
+            try_consume_exact_digits::<u32>(s, digits as usize, Padding::Zero)
+                .ok_or(error::Parse::InvalidNanosecond)?
+                * 10_u32.pow(9 - digits as u32),
+        );
+        return Ok(());
+    }
+
+    let num_digits = s.bytes().take_while(u8::is_ascii_digit).count();
+    if num_digits == 0 {
+        return Err(error::Parse::InvalidNanosecond);
+    }
+    let num_digits_used = core::cmp::min(num_digits, 9);
+
+    // At most 9 decimal digits will always fit in a u32.
+    #[allow(clippy::unwrap_used)]
+    let nanos = s[..num_digits_used].parse::<u32>().unwrap();
+    items.nanosecond = Some(nanos * 10_u32.pow(9 - num_digits_used as u32));
+    *s = &s[num_digits..];
     Ok(())
 }
 
@@ -106,6 +135,33 @@ pub(crate) fn parse_P(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()>
     Ok(())
 }
 
+/// am/pm, using the provided markers
+pub(crate) fn parse_am_pm_markers(
+    items: &mut ParsedItems,
+    s: &mut &str,
+    markers: AmPmMarkers<'_>,
+) -> ParseResult<()> {
+    /// Consume `marker` if `s` starts with it, ignoring ASCII case.
+    fn try_consume_marker(s: &mut &str, marker: &str) -> bool {
+        match s.get(..marker.len()) {
+            Some(prefix) if !marker.is_empty() && prefix.eq_ignore_ascii_case(marker) => {
+                *s = &s[marker.len()..];
+                true
+            }
+            _ => false,
+        }
+    }
+
+    items.am_pm = Some(if try_consume_marker(s, markers.am) {
+        AM
+    } else if try_consume_marker(s, markers.pm) {
+        PM
+    } else {
+        return Err(error::Parse::InvalidAmPm);
+    });
+    Ok(())
+}
+
 /// Seconds, zero-padded (`00`-`59`)
 pub(crate) fn fmt_S(f: &mut Formatter<'_>, time: Time, padding: Padding) -> fmt::Result {
     pad!(f, padding, 2, time.second())
@@ -0,0 +1,63 @@
+//! Formatting helpers for a Unix timestamp.
+
+use crate::{
+    error,
+    format::{
+        parse::{try_consume_char, try_consume_digits},
+        ParseResult, ParsedItems,
+    },
+    Duration, OffsetDateTime,
+};
+use core::fmt::{self, Formatter};
+
+/// Number of seconds since the Unix epoch
+pub(crate) fn fmt_s(f: &mut Formatter<'_>, datetime: OffsetDateTime) -> fmt::Result {
+    write!(f, "{}", datetime.timestamp())
+}
+
+/// Number of seconds since the Unix epoch
+///
+/// If `allow_fraction` is set, a fractional part of up to nine digits may
+/// follow the whole seconds, separated by a `.`.
+pub(crate) fn parse_s(
+    items: &mut ParsedItems,
+    s: &mut &str,
+    allow_fraction: bool,
+) -> ParseResult<()> {
+    let is_negative = try_consume_char(s, '-').is_ok();
+    let seconds: i64 = try_consume_digits(s, 1..).ok_or(error::Parse::InvalidTimestamp)?;
+
+    let nanoseconds = if allow_fraction && s.starts_with('.') {
+        *s = &s[1..];
+        let start_len = s.len();
+        let fraction: i32 = try_consume_digits(s, 1..=9).ok_or(error::Parse::InvalidTimestamp)?;
+        fraction * 10_i32.pow(9 - (start_len - s.len()) as u32)
+    } else {
+        0
+    };
+
+    let duration = if is_negative {
+        Duration::new(-seconds, -nanoseconds)
+    } else {
+        Duration::new(seconds, nanoseconds)
+    };
+    let datetime = OffsetDateTime::unix_epoch()
+        .checked_add(duration)
+        .ok_or(error::Parse::InvalidTimestamp)?;
+
+    // The whole seconds are rounded towards negative infinity, as the
+    // subsecond nanoseconds are always positive.
+    let mut timestamp = datetime.timestamp();
+    if is_negative && nanoseconds != 0 {
+        timestamp -= 1;
+    }
+    items.timestamp = Some(timestamp);
+
+    // The fractional part is stored with the other subsecond values, so that
+    // it is checked against them. When it is parsed separately, that
+    // specifier sets it instead.
+    if allow_fraction {
+        items.nanosecond = Some(datetime.nanosecond());
+    }
+    Ok(())
+}
@@ -9,10 +9,8 @@ use crate::{
         },
         time, Padding, ParsedItems,
     },
-    DeferredFormat, ParseResult,
+    DeferredFormat, ParseResult, ParseStrictness,
 };
-#[cfg(not(feature = "std"))]
-use alloc::string::String;
 use core::fmt::{self, Formatter};
 #[allow(unused_imports)]
 use standback::prelude::*;
@@ -20,7 +18,7 @@ use standback::prelude::*;
 /// The format as specified by RFC3339.
 pub(crate) mod rfc3339 {
     use super::*;
-    use crate::{error, UtcOffset};
+    use crate::{error, Date, Time, UtcOffset};
 
     /// Format `df` according to the RFC3339 specification.
     pub(crate) fn fmt(df: &DeferredFormat, f: &mut Formatter<'_>) -> fmt::Result {
@@ -34,6 +32,20 @@ pub(crate) mod rfc3339 {
         #[allow(clippy::unwrap_used)]
         let offset = df.offset().unwrap();
 
+        fmt_components(f, date, time, offset, None)
+    }
+
+    /// Format the components according to the RFC3339 specification, with
+    /// the given number of fractional digits (`0`-`9`). When no precision is
+    /// provided, the fewest of 0, 3, 6, or 9 digits that exactly represent the
+    /// subsecond nanoseconds are used.
+    pub(crate) fn fmt_components(
+        f: &mut Formatter<'_>,
+        date: Date,
+        time: Time,
+        offset: UtcOffset,
+        precision: Option<u8>,
+    ) -> fmt::Result {
         date::fmt_Y(f, date, Padding::Zero)?;
         f.write_str("-")?;
         date::fmt_m(f, date, Padding::Zero)?;
@@ -45,6 +57,24 @@ pub(crate) mod rfc3339 {
         time::fmt_M(f, time, Padding::Zero)?;
         f.write_str(":")?;
         time::fmt_S(f, time, Padding::Zero)?;
+
+        let nanosecond = time.nanosecond();
+        let digits = precision.unwrap_or_else(|| {
+            if nanosecond == 0 {
+                0
+            } else if nanosecond.rem_euclid(1_000_000) == 0 {
+                3
+            } else if nanosecond.rem_euclid(1_000) == 0 {
+                6
+            } else {
+                9
+            }
+        });
+        if digits != 0 {
+            f.write_str(".")?;
+            time::fmt_N(f, time, digits)?;
+        }
+
         write!(
             f,
             "{:+03}:{:02}",
@@ -55,8 +85,13 @@ pub(crate) mod rfc3339 {
         Ok(())
     }
 
-    /// Parse `s` as specified by RFC3339.
-    pub(crate) fn parse(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+    /// Parse `s` as specified by RFC3339, accepting the deviations permitted
+    /// by `strictness`.
+    pub(crate) fn parse(
+        items: &mut ParsedItems,
+        s: &mut &str,
+        strictness: ParseStrictness,
+    ) -> ParseResult<()> {
         items.year = try_consume_exact_digits::<i32>(s, 4, Padding::None)
             .ok_or(error::Parse::InvalidYear)?
             .into();
@@ -64,54 +99,170 @@ pub(crate) mod rfc3339 {
         date::parse_m(items, s, Padding::Zero)?;
         try_consume_char(s, '-')?;
         date::parse_d(items, s, Padding::Zero)?;
-        try_consume_char_case_insensitive(s, 'T')?;
+        // RFC3339 permits the separator and UTC designator to be lowercase.
+        match strictness {
+            ParseStrictness::Strict => try_consume_char_case_insensitive(s, 'T'),
+            ParseStrictness::Lenient => {
+                try_consume_char_case_insensitive(s, 'T').or_else(|_| try_consume_char(s, ' '))
+            }
+        }?;
         time::parse_H(items, s, Padding::Zero)?;
         try_consume_char(s, ':')?;
         time::parse_M(items, s, Padding::Zero)?;
         try_consume_char(s, ':')?;
         time::parse_S(items, s, Padding::Zero)?;
 
-        if try_consume_char(s, '.').is_ok() {
-            let num_digits = s.chars().take_while(char::is_ascii_digit).count();
-            if num_digits == 0 {
-                return Err(error::Parse::InvalidNanosecond);
+        // ISO 8601 permits a comma as the decimal separator.
+        let has_fraction = match strictness {
+            ParseStrictness::Strict => try_consume_char(s, '.'),
+            ParseStrictness::Lenient => {
+                try_consume_char(s, '.').or_else(|_| try_consume_char(s, ','))
             }
-            let num_digits_used = core::cmp::min(num_digits, 9);
-
-            let nanos_raw: String = s.chars().take(num_digits_used).collect();
-            // At most 9 decimal digits will always fit in a u32.
-            // `num_digits_used` is at most 9, which can safely be cast.
-            #[allow(clippy::unwrap_used)]
-            let nanos = nanos_raw.parse::<u32>().unwrap() * 10_u32.pow(9 - num_digits_used as u32);
-            items.nanosecond = Some(nanos);
-            *s = &s[num_digits..];
         }
+        .is_ok();
+        if has_fraction {
+            time::parse_N(items, s, None)?;
+        }
+
+        items.offset = Some(if strictness == ParseStrictness::Lenient && s.is_empty() {
+            UtcOffset::UTC
+        } else {
+            parse_offset(s, strictness)?.0
+        });
+
+        Ok(())
+    }
 
+    /// Parse an RFC3339 offset (`Z` or `±HH:MM`) from `s`. The returned
+    /// boolean indicates whether the offset was `-00:00`, which RFC3339 uses
+    /// to signal that the local offset is unknown.
+    pub(crate) fn parse_offset(
+        s: &mut &str,
+        strictness: ParseStrictness,
+    ) -> ParseResult<(UtcOffset, bool)> {
         if try_consume_char_case_insensitive(s, 'Z').is_ok() {
-            items.offset = Some(UtcOffset::UTC);
+            return Ok((UtcOffset::UTC, false));
+        }
+
+        let offset_sign = match try_consume_first_match(s, [("+", 1), ("-", -1)].iter().cloned()) {
+            Some(sign) => sign,
+            None => {
+                return Err(match s.chars().next() {
+                    Some(actual) => error::Parse::UnexpectedCharacter {
+                        actual,
+                        expected: '+',
+                    },
+                    None => error::Parse::UnexpectedEndOfString,
+                })
+            }
+        };
+        let offset_hour: u8 =
+            try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?;
+        // ISO 8601 permits omitting the minutes (`+05`).
+        let offset_minute: u8 = if strictness == ParseStrictness::Lenient
+            && !s.starts_with(|c: char| c == ':' || c.is_ascii_digit())
+        {
+            0
         } else {
-            let offset_sign =
-                match try_consume_first_match(s, [("+", 1), ("-", -1)].iter().cloned()) {
-                    Some(sign) => sign,
-                    None => {
-                        return Err(match s.chars().next() {
-                            Some(actual) => error::Parse::UnexpectedCharacter {
-                                actual,
-                                expected: '+',
-                            },
-                            None => error::Parse::UnexpectedEndOfString,
-                        })
-                    }
-                };
-            let offset_hour: i16 =
-                try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?;
             try_consume_char(s, ':')?;
-            let offset_minute: i16 =
+            try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?
+        };
+
+        let offset = UtcOffset::from_signed_hms(offset_sign == -1, offset_hour, offset_minute, 0)?;
+        let is_unknown = offset_sign == -1 && offset == UtcOffset::UTC;
+        Ok((offset, is_unknown))
+    }
+}
+
+/// The `timestamptz` text format used by Postgres, such as
+/// `2021-01-01 00:00:00+00`.
+pub(crate) mod sql_timestamptz {
+    use super::*;
+    use crate::{error, format::parse::try_consume_digits, Date, Time, UtcOffset};
+
+    /// Format the components as a Postgres `timestamptz`. The fractional
+    /// second is omitted when zero and otherwise has trailing zeros removed.
+    /// The offset has only as many components as are needed to represent it.
+    pub(crate) fn fmt(
+        f: &mut Formatter<'_>,
+        date: Date,
+        time: Time,
+        offset: UtcOffset,
+    ) -> fmt::Result {
+        date::fmt_Y(f, date, Padding::Zero)?;
+        f.write_str("-")?;
+        date::fmt_m(f, date, Padding::Zero)?;
+        f.write_str("-")?;
+        date::fmt_d(f, date, Padding::Zero)?;
+        f.write_str(" ")?;
+        time::fmt_H(f, time, Padding::Zero)?;
+        f.write_str(":")?;
+        time::fmt_M(f, time, Padding::Zero)?;
+        f.write_str(":")?;
+        time::fmt_S(f, time, Padding::Zero)?;
+
+        let mut nanosecond = time.nanosecond();
+        if nanosecond != 0 {
+            let mut digits = 9;
+            while nanosecond.rem_euclid(10) == 0 {
+                nanosecond /= 10;
+                digits -= 1;
+            }
+            write!(f, ".{:0width$}", nanosecond, width = digits)?;
+        }
+
+        let seconds = offset.as_seconds();
+        let sign = if seconds < 0 { '-' } else { '+' };
+        let seconds = seconds.abs();
+        write!(f, "{}{:02}", sign, seconds / 3_600)?;
+        if seconds.rem_euclid(3_600) != 0 {
+            write!(f, ":{:02}", seconds / 60 % 60)?;
+        }
+        if seconds.rem_euclid(60) != 0 {
+            write!(f, ":{:02}", seconds % 60)?;
+        }
+
+        Ok(())
+    }
+
+    /// Parse `s` as a Postgres `timestamptz`. The offset may consist of the
+    /// hours alone (`+05`), or include minutes and seconds (`+05:30:00`).
+    pub(crate) fn parse(items: &mut ParsedItems, s: &mut &str) -> ParseResult<()> {
+        date::parse_Y(items, s, Padding::Zero)?;
+        try_consume_char(s, '-')?;
+        date::parse_m(items, s, Padding::Zero)?;
+        try_consume_char(s, '-')?;
+        date::parse_d(items, s, Padding::Zero)?;
+        try_consume_char(s, ' ')?;
+        time::parse_H(items, s, Padding::Zero)?;
+        try_consume_char(s, ':')?;
+        time::parse_M(items, s, Padding::Zero)?;
+        try_consume_char(s, ':')?;
+        time::parse_S(items, s, Padding::Zero)?;
+
+        if try_consume_char(s, '.').is_ok() {
+            let start_len = s.len();
+            let fraction: u32 =
+                try_consume_digits(s, 1..=9).ok_or(error::Parse::InvalidNanosecond)?;
+            items.nanosecond = Some(fraction * 10_u32.pow(9 - (start_len - s.len()) as u32));
+        }
+
+        let negative = try_consume_first_match(s, [("+", false), ("-", true)].iter().cloned())
+            .ok_or(error::Parse::InvalidOffset)?;
+        let hours: u8 =
+            try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?;
+        let mut components = [0_u8; 2];
+        for component in &mut components {
+            if try_consume_char(s, ':').is_err() {
+                break;
+            }
+            *component =
                 try_consume_exact_digits(s, 2, Padding::Zero).ok_or(error::Parse::InvalidOffset)?;
-            items.offset = Some(UtcOffset::minutes(
-                offset_sign * (offset_hour * 60 + offset_minute),
-            ));
         }
+        let [minutes, seconds] = components;
+        items.offset = Some(UtcOffset::from_signed_hms(
+            negative, hours, minutes, seconds,
+        )?);
 
         Ok(())
     }
@@ -11,7 +11,8 @@
 #![doc(hidden)]
 #![allow(missing_debug_implementations, missing_copy_implementations)]
 
-use crate::{days_in_year, is_leap_year, Weekday};
+use crate::{days_in_year, is_leap_year, util::DAYS_BEFORE_MONTH_COMMON_LEAP, Weekday};
+use const_fn::const_fn;
 
 pub struct Time;
 
@@ -44,37 +45,43 @@ impl Date {
 
     // reduce duplication
     pub(crate) const fn from_ymd_unchecked(year: i32, month: u8, day: u8) -> crate::Date {
-        /// Cumulative days through the beginning of a month in both common and
-        /// leap years.
-        const DAYS_CUMULATIVE_COMMON_LEAP: [[u16; 12]; 2] = [
-            [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334],
-            [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335],
-        ];
-
         Date::from_yo_unchecked(
             year,
-            DAYS_CUMULATIVE_COMMON_LEAP[is_leap_year(year) as usize][month as usize - 1]
+            DAYS_BEFORE_MONTH_COMMON_LEAP[is_leap_year(year) as usize][month as usize - 1]
                 + day as u16,
         )
     }
 
     // reduce duplication
-    pub(crate) fn from_iso_ywd_unchecked(year: i32, week: u8, weekday: Weekday) -> crate::Date {
-        let ordinal = week as u16 * 7 + weekday.iso_weekday_number() as u16
+    //
+    // Week 1 is the week containing January 4, so the Monday of week 1 falls
+    // on ordinal `4 - (iso_weekday_number(January 4) - 1)`, which may be in
+    // the previous year. The ordinal is computed as a signed value, as it is
+    // negative for some dates in week 1.
+    #[const_fn("1.46")]
+    pub(crate) const fn from_iso_ywd_unchecked(
+        year: i32,
+        week: u8,
+        weekday: Weekday,
+    ) -> crate::Date {
+        let ordinal = week as i16 * 7 + weekday.iso_weekday_number() as i16
             - (Self::from_yo_unchecked(year, 4)
                 .weekday()
-                .iso_weekday_number() as u16
+                .iso_weekday_number() as i16
                 + 3);
 
         if ordinal < 1 {
-            return Self::from_yo_unchecked(year - 1, ordinal + days_in_year(year - 1));
+            return Self::from_yo_unchecked(
+                year - 1,
+                (ordinal + days_in_year(year - 1) as i16) as u16,
+            );
         }
 
-        let days_in_cur_year = days_in_year(year);
+        let days_in_cur_year = days_in_year(year) as i16;
         if ordinal > days_in_cur_year {
-            Self::from_yo_unchecked(year + 1, ordinal - days_in_cur_year)
+            Self::from_yo_unchecked(year + 1, (ordinal - days_in_cur_year) as u16)
         } else {
-            Self::from_yo_unchecked(year, ordinal)
+            Self::from_yo_unchecked(year, ordinal as u16)
         }
     }
 }
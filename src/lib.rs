@@ -18,6 +18,19 @@
 //! Of the structs that are usable, some methods may only be enabled due a
 //! reliance on `Instant`. These will be indicated in the documentation.
 //!
+//! Determining the local UTC offset requires the system's time zone database,
+//! so `UtcOffset::local_offset_at`, `UtcOffset::try_local_offset_at`,
+//! `UtcOffset::current_local_offset`, `UtcOffset::try_current_local_offset`,
+//! and the methods on `OffsetDateTime` that build on them are likewise only
+//! available with the standard library. All other methods on `UtcOffset`,
+//! including construction, conversion, formatting, and parsing, are available
+//! with `#![no_std]`.
+//!
+//! Without the standard library, there is no system clock to read. Platforms
+//! that have one can register it with `time::set_time_source`, which makes
+//! `OffsetDateTime::now_utc` available. When the `std` feature is enabled,
+//! `set_time_source` does not exist and the system clock is always used.
+//!
 //! ## `serde`
 //!
 //! [Serde](https://github.com/serde-rs/serde) support is behind a feature flag.
@@ -84,6 +97,30 @@
 //! time = { version = "0.2", features = ["panicking-api"] }
 //! ```
 //!
+//! ## `step`
+//!
+//! Implements the unstable `core::iter::Step` trait for `Date`, allowing
+//! ranges such as `start..=end` to be iterated over one day at a time. This
+//! requires a nightly compiler and is not enabled by default. As the trait is
+//! unstable, this feature is exempt from the usual stability guarantees.
+//!
+//! ```toml
+//! [dependencies]
+//! time = { version = "0.2", features = ["step"] }
+//! ```
+//!
+//! ## `test-clock`
+//!
+//! Adds the `time::testing` module, which allows the value returned by
+//! `OffsetDateTime::now_utc` (and everything built on it) to be fixed within a
+//! test. This implies the `std` feature and is not enabled by default. It is
+//! intended for use as a dev-dependency.
+//!
+//! ```toml
+//! [dev-dependencies]
+//! time = { version = "0.2", features = ["test-clock"] }
+//! ```
+//!
 //! # Formatting
 //!
 //! Time's formatting behavior is based on `strftime` in C, though it is
@@ -101,19 +138,24 @@
 //! | `%C`      | Year divided by 100 and truncated to integer (`00`-`99`)               | `20`                       |
 //! | `%d`      | Day of the month, zero-padded (`01`-`31`)                              | `23`                       |
 //! | `%D`      | Short MM/DD/YY date, equivalent to `%-m/%d/%y`                         | `8/23/01`                  |
+//! | `%e`      | Day of the month, space-padded (` 1`-`31`)                             | `23`                       |
 //! | `%F`      | Short YYYY-MM-DD date, equivalent to `%-Y-%m-%d`                       | `2001-08-23`               |
 //! | `%g`      | Week-based year, last two digits (`00`-`99`)                           | `01`                       |
-//! | `%G`      | Week-based year                                                        | `2001`                     |
+//! | `%G`      | Week-based year, signed as for `%Y`                                    | `2001`                     |
 //! | `%H`      | Hour in 24h format (`00`-`23`)                                         | `14`                       |
 //! | `%I`      | Hour in 12h format (`01`-`12`)                                         | `02`                       |
 //! | `%j`      | Day of the year (`001`-`366`)                                          | `235`                      |
 //! | `%m`      | Month as a decimal number (`01`-`12`)                                  | `08`                       |
 //! | `%M`      | Minute (`00`-`59`)                                                     | `55`                       |
-//! | `%N`      | Subsecond nanoseconds. Always 9 digits                                 | `012345678`                |
+//! | `%N`      | Subsecond nanoseconds. Always 9 digits; any number when parsing        | `012345678`                |
+//! | `%3N`     | Subsecond digits, truncated to the given width (`%1N`-`%9N`)           | `012`                      |
+//! | `%o`      | Week of the month where week 1 contains the 1st, starting on Monday    | `4`                        |
+//! | `%O`      | Week of the month where week 1 contains the 1st, starting on Sunday    | `4`                        |
 //! | `%p`      | `am` or `pm` designation                                               | `pm`                       |
 //! | `%P`      | `AM` or `PM` designation                                               | `PM`                       |
 //! | `%r`      | 12-hour clock time, equivalent to `%-I:%M:%S %p`                       | `2:55:02 pm`               |
 //! | `%R`      | 24-hour HH:MM time, equivalent to `%-H:%M`                             | `14:55`                    |
+//! | `%s`      | Number of seconds since the Unix epoch                                 | `998578502`                |
 //! | `%S`      | Second (`00`-`59`)                                                     | `02`                       |
 //! | `%T`      | 24-hour clock time with seconds, equivalent to `%-H:%M:%S`             | `14:55:02`                 |
 //! | `%u`      | ISO 8601 weekday as number with Monday as 1 (`1`-`7`)                  | `4`                        |
@@ -122,9 +164,16 @@
 //! | `%w`      | Weekday as a decimal number with Sunday as 0 (`0`-`6`)                 | `4`                        |
 //! | `%W`      | Week number with the first Monday as the start of week one (`00`-`53`) | `34`                       |
 //! | `%y`      | Year, last two digits (`00`-`99`)                                      | `01`                       |
-//! | `%Y`      | Full year, including `+` if ≥10,000                                    | `2001`                     |
+//! | `%Y`      | Full year, including `-` if negative and `+` if ≥10,000                | `2001`                     |
 //! | `%z`      | ISO 8601 offset from UTC in timezone (+HHMM)                           | `+0100`                    |
+//! | `%:z`     | ISO 8601 offset from UTC in timezone, with a colon (+HH:MM)            | `+01:00`                   |
+//! | `%:::z`   | ISO 8601 offset from UTC, with minutes only if nonzero (+HH or +HH:MM) | `+01`                      |
+//! | `%#z`     | Military time zone letter, or +HHMM if the offset has no letter        | `A`                        |
+//! | `%Z`      | `UTC` for a zero offset, otherwise `UTC` followed by the offset        | `UTC+01:00`                |
+//! | `%+`      | Full date, time, and offset, equivalent to `%Y-%m-%dT%H:%M:%S%z`       | `2001-08-23T14:55:02+0100` |
 //! | `%%`      | Literal `%`                                                            | `%`                        |
+//! | `%[...]`  | The enclosed text verbatim, which may contain `%` but not `]`          | `%[50%]` => `50%`          |
+//! | `%(...%)` | An optional section, skipped when parsing if it does not match         | `%(:%S%)` => `:05`         |
 //!
 //! ## Modifiers
 //!
@@ -139,8 +188,73 @@
 //! | `-` (dash)       | No padding      | `%-d` => `5`  |
 //! | `_` (underscore) | Pad with spaces | `%_d` => ` 5` |
 //! | `0`              | Pad with zeros  | `%0d` => `05` |
+//!
+//! The sign of `%Y` and `%G` is not counted towards their width. Zeros are
+//! placed after the sign (`-0001`) and spaces before it (`   -1`).
+//!
+//! A numeric width may also be provided after the `%` (optionally following
+//! the `0` modifier), which zero-pads the value to at least that many digits,
+//! overriding the default width. For example, `%04Y` formats the year 987 as
+//! `0987` and `%03H` formats 5 o'clock as `005`. When parsing, exactly that
+//! many digits are required. A width is only accepted by `%C`, `%d`, `%g`,
+//! `%G`, `%H`, `%I`, `%j`, `%m`, `%M`, `%S`, `%U`, `%V`, `%W`, `%y`, and `%Y`.
+//!
+//! ## chrono compatibility
+//!
+//! To ease migration from [chrono](https://docs.rs/chrono), the following
+//! specifiers are accepted as aliases for their equivalents in this crate.
+//!
+//! | Alias     | Behavior                                                    |
+//! |-----------|-------------------------------------------------------------|
+//! | `%f`      | Subsecond nanoseconds, equivalent to `%N`                   |
+//! | `%3f`     | Subsecond digits, equivalent to `%3N` (also `%1f`-`%9f`)    |
+//! | `%.3f`    | A period followed by subsecond digits, equivalent to `.%3N` |
+//! | `%h`      | Abbreviated month name, equivalent to `%b`                  |
+//! | `%k`      | Hour in 24h format, space-padded, equivalent to `%_H`       |
+//! | `%l`      | Hour in 12h format, space-padded, equivalent to `%_I`       |
+//! | `%n`      | Literal newline                                             |
+//! | `%t`      | Literal tab                                                 |
+//! | `%v`      | Equivalent to `%e-%b-%Y`                                    |
+//! | `%x`      | Equivalent to `%m/%d/%y`                                    |
+//! | `%X`      | Equivalent to `%H:%M:%S`                                    |
+//!
+//! Format strings from chrono that only use these aliases and the specifiers
+//! shared by both crates produce the same output. Some specifiers exist in both
+//! crates with different behavior, and are _not_ adjusted: `%p` and `%P` are
+//! swapped, `%D`, `%R`, `%T`, and `%c` do not pad the leading value, `%+` has
+//! no fractional seconds or colon in the offset, `%Z` is always `UTC` based,
+//! `%#z` is a military time zone letter, and `%:::z` includes the minutes
+//! when they are nonzero. chrono's `%.f` (a fraction of varying length) is not
+//! supported.
+//!
+//! ## Parsing
+//!
+//! When parsing a time, the hour must be present. If the format does not
+//! contain the minute, second, or subsecond nanoseconds, they default to zero.
+//! This applies equally when parsing a `Time`, `PrimitiveDateTime`, or
+//! `OffsetDateTime`, so `%H:%M` is sufficient to parse `14:30`.
+//!
+//! Components that may or may not be present can be placed in an optional
+//! section. If the contents of `%(...%)` do not match the input, the section
+//! is skipped and parsing continues after it, so `%H:%M%(:%S%)` parses both
+//! `14:30` and `14:30:05`. Optional sections may be nested, and are always
+//! included when formatting.
+//!
+//! A component may be provided by more than one specifier, such as `%F` and
+//! `%Y` both providing the year. This is permitted as long as every
+//! occurrence agrees; differing values result in a
+//! `ParseError::ConflictingComponent`. The partial years `%C`, `%g`, and `%y`
+//! combine with any year already parsed, so never conflict with it. The date
+//! and time given by `%s` are compared in the parsed offset, or in UTC if
+//! there is none, so `%F %T %z (%s)` parses its own output.
+//!
+//! When parsing, `%Z` accepts `UTC` or `GMT` (optionally followed by an offset)
+//! as well as the abbreviations in `ZoneAbbreviations::DEFAULT`, such as `EST`
+//! and `PST`. Abbreviations with several meanings, such as `CST`, are rejected
+//! unless a different table is provided via `parse_with_zones`.
 
 #![cfg_attr(docs, feature(doc_cfg))]
+#![cfg_attr(feature = "step", feature(step_trait))]
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(
     anonymous_parameters,
@@ -359,6 +473,11 @@ macro_rules! date {
     };
 }
 
+/// The `CalendarDuration` struct and its associated `impl`s.
+mod calendar_duration;
+#[cfg(feature = "std")]
+#[cfg_attr(docs, doc(cfg(feature = "std")))]
+pub mod clock;
 /// The `Date` struct and its associated `impl`s.
 mod date;
 /// The `Duration` struct and its associated `impl`s.
@@ -372,6 +491,8 @@ mod format;
 #[cfg(feature = "std")]
 mod instant;
 pub mod internals;
+/// Policies for adding months to a date.
+mod month_add_policy;
 /// The `OffsetDateTime` struct and its associated `impl`s.
 mod offset_date_time;
 /// The `PrimitiveDateTime` struct and its associated `impl`s.
@@ -383,26 +504,40 @@ mod rand;
 pub mod serde;
 /// The `Sign` struct and its associated `impl`s.
 mod sign;
+#[cfg(feature = "test-clock")]
+#[cfg_attr(docs, doc(cfg(feature = "test-clock")))]
+pub mod testing;
 /// The `Time` struct and its associated `impl`s.
 mod time_mod;
+/// A user-provided source of the current time.
+#[cfg(not(feature = "std"))]
+mod time_source;
 /// The `UtcOffset` struct and its associated `impl`s.
 mod utc_offset;
 pub mod util;
 /// Days of the week.
 mod weekday;
+/// Sets of days of the week.
+mod weekday_set;
 
+pub use calendar_duration::CalendarDuration;
+#[cfg(feature = "std")]
+pub use clock::clock_resolution;
 pub use date::Date;
-pub use duration::Duration;
+pub use duration::{Duration, DurationUnit};
 pub use error::{
     ComponentRange as ComponentRangeError, ConversionRange as ConversionRangeError, Error,
     IndeterminateOffset as IndeterminateOffsetError, Parse as ParseError,
 };
+#[cfg(feature = "std")]
+pub use ext::InstantExt;
 pub use ext::{NumericalDuration, NumericalStdDuration, NumericalStdDurationShort};
 pub(crate) use format::DeferredFormat;
-pub use format::Format;
 use format::ParseResult;
+pub use format::{AmPmMarkers, Format, ParseStrictness, ZoneAbbreviations};
 #[cfg(feature = "std")]
 pub use instant::Instant;
+pub use month_add_policy::MonthAddPolicy;
 pub use offset_date_time::OffsetDateTime;
 pub use primitive_date_time::PrimitiveDateTime;
 #[allow(deprecated)]
@@ -426,6 +561,45 @@ use standback::prelude::*;
 /// # }
 /// ```
 pub use time_macros::date;
+/// Construct a format description with a statically known value.
+///
+/// The resulting expression is a `&'static str`, so it can be used in `const`
+/// or `static` declarations and anywhere a runtime format string is accepted.
+///
+/// The description is validated at compile-time. An error will be raised if
+/// it contains an unknown specifier or ends with a lone `%`.
+///
+/// ```rust
+/// # use time::{date, format_description};
+/// const FORMAT: &str = format_description!("%Y-%m-%d");
+/// assert_eq!(date!(2020-01-02).format(FORMAT), "2020-01-02");
+/// assert_eq!(format_description!("%-d%%"), "%-d%%");
+/// assert_eq!(format_description!("%[50%]"), "%[50%]");
+/// ```
+///
+/// ```rust,compile_fail
+/// # use time::format_description;
+/// let _ = format_description!("%Y-%Q");
+/// ```
+///
+/// An explicit width must be nonzero, less than 256, and may only be combined
+/// with the `0` modifier.
+///
+/// ```rust,compile_fail
+/// # use time::format_description;
+/// let _ = format_description!("%00Y");
+/// ```
+///
+/// ```rust,compile_fail
+/// # use time::format_description;
+/// let _ = format_description!("%0300Y");
+/// ```
+///
+/// ```rust,compile_fail
+/// # use time::format_description;
+/// let _ = format_description!("%-4Y");
+/// ```
+pub use time_macros::format_description;
 /// Construct a [`UtcOffset`](crate::UtcOffset) with a statically known value.
 ///
 /// The resulting expression can be used in `const` or `static` declarations.
@@ -476,9 +650,14 @@ pub use time_macros::offset;
 /// ```
 pub use time_macros::time;
 pub use time_mod::Time;
+#[cfg(not(feature = "std"))]
+pub use time_source::set_time_source;
 pub use utc_offset::UtcOffset;
-pub use util::{days_in_year, is_leap_year, validate_format_string, weeks_in_year};
+pub use util::{
+    days_in_year, days_in_year_month, is_leap_year, validate_format_string, weeks_in_year,
+};
 pub use weekday::Weekday;
+pub use weekday_set::WeekdaySet;
 
 /// An alias for `Result` with a generic error from the time crate.
 pub type Result<T> = core::result::Result<T, Error>;
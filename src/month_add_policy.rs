@@ -0,0 +1,19 @@
+/// How to choose the day of the month when adding months to a date whose day
+/// does not exist in the resulting month, or which is the last day of its
+/// month.
+///
+/// Used by [`Date::add_months_with_policy`](crate::Date::add_months_with_policy)
+/// and the equivalent methods on [`PrimitiveDateTime`](crate::PrimitiveDateTime)
+/// and [`OffsetDateTime`](crate::OffsetDateTime).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MonthAddPolicy {
+    /// Keep the day of the month, clamping it to the last day of the resulting
+    /// month if necessary. January 31 plus one month is the last day of
+    /// February, while February 28 plus one month is March 28.
+    ClampDay,
+
+    /// As with `ClampDay`, except that the last day of a month always maps to
+    /// the last day of the resulting month. February 28, 2019 plus one month
+    /// is March 31.
+    PreserveEndOfMonth,
+}
@@ -1,13 +1,18 @@
-#[cfg(feature = "std")]
 use crate::error;
 use crate::{
-    format::parse::{parse, ParsedItems},
-    internals, Date, DeferredFormat, Duration, Format, ParseResult, PrimitiveDateTime, Time,
-    UtcOffset, Weekday,
+    format::{
+        parse::{parse, parse_with_strictness, parse_with_zones, ParsedItems},
+        well_known,
+    },
+    internals,
+    primitive_date_time::{MAX_DATETIME, MIN_DATETIME},
+    Date, DeferredFormat, Duration, Format, MonthAddPolicy, ParseResult, ParseStrictness,
+    PrimitiveDateTime, Time, UtcOffset, Weekday, ZoneAbbreviations,
 };
 #[cfg(not(feature = "std"))]
 use alloc::{
     borrow::ToOwned,
+    format,
     string::{String, ToString},
 };
 #[cfg(feature = "std")]
@@ -19,13 +24,24 @@ use core::{
     ops::{Add, AddAssign, Sub, SubAssign},
     time::Duration as StdDuration,
 };
-#[cfg(feature = "std")]
 use standback::convert::TryFrom;
 #[cfg(feature = "serde")]
 use standback::convert::TryInto;
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+/// The number of seconds between the NTP epoch (1900-01-01T00:00:00Z) and the
+/// Unix epoch.
+const NTP_UNIX_EPOCH_OFFSET: i64 = 2_208_988_800;
+
+/// The number of .NET ticks (100 nanosecond intervals) between
+/// 0001-01-01T00:00:00Z and the Unix epoch.
+const DOTNET_UNIX_EPOCH_TICKS: i64 = 621_355_968_000_000_000;
+
+/// The number of .NET ticks at 9999-12-31T23:59:59.9999999Z, the largest value
+/// representable by a .NET `DateTime`.
+const DOTNET_MAX_TICKS: i64 = 3_155_378_975_999_999_999;
+
 /// A [`PrimitiveDateTime`] with a [`UtcOffset`].
 ///
 /// All comparisons are performed using the UTC time.
@@ -93,7 +109,7 @@ impl OffsetDateTime {
     #[cfg(feature = "std")]
     #[cfg_attr(docs, doc(cfg(feature = "std")))]
     pub fn now() -> Self {
-        SystemTime::now().into()
+        Self::now_utc()
     }
 
     /// Create a new `OffsetDateTime` with the current date and time in UTC.
@@ -106,9 +122,28 @@ impl OffsetDateTime {
     #[cfg(feature = "std")]
     #[cfg_attr(docs, doc(cfg(feature = "std")))]
     pub fn now_utc() -> Self {
+        #[cfg(feature = "test-clock")]
+        {
+            if let Some(now) = crate::testing::now_utc() {
+                return now;
+            }
+        }
+
         SystemTime::now().into()
     }
 
+    /// Create a new `OffsetDateTime` with the current date and time in UTC,
+    /// as reported by the source registered with
+    /// [`set_time_source`](crate::set_time_source).
+    ///
+    /// # Panics
+    ///
+    /// This panics if no time source has been registered.
+    #[cfg(not(feature = "std"))]
+    pub fn now_utc() -> Self {
+        crate::time_source::now_utc().expect("no time source has been registered")
+    }
+
     /// Create a new `OffsetDateTime` with the current date and time in the
     /// local offset.
     ///
@@ -171,6 +206,156 @@ impl OffsetDateTime {
         }
     }
 
+    /// Check whether two values represent instants no further apart than
+    /// `tolerance`, regardless of their offsets.
+    ///
+    /// This is useful when comparing values that have been through a lossy
+    /// conversion, such as a serialization format that truncates to
+    /// milliseconds. A negative tolerance is never satisfied.
+    ///
+    /// ```rust
+    /// # use time::{date, time, offset, prelude::*};
+    /// let datetime = date!(2021-01-01).with_time(time!(12:00)).assume_utc();
+    /// assert!(datetime.approx_eq(datetime + 1.milliseconds(), 1.seconds()));
+    /// assert!(!datetime.approx_eq(datetime - 2.seconds(), 1.seconds()));
+    /// assert!(datetime.approx_eq(datetime.to_offset(offset!(+1)), 0.seconds()));
+    /// ```
+    pub fn approx_eq(self, other: Self, tolerance: Duration) -> bool {
+        (self - other).abs() <= tolerance
+    }
+
+    /// Round the instant down to a multiple of `increment`, preserving the
+    /// offset. Multiples are counted from the Unix epoch in UTC, so the grid
+    /// is the same regardless of the offset.
+    ///
+    /// ```rust
+    /// # use time::{date, time, offset, prelude::*};
+    /// let datetime = date!(2021-01-01).with_time(time!(12:07:30)).assume_offset(offset!(+5:30));
+    /// assert_eq!(
+    ///     datetime.floor_to(15.minutes()),
+    ///     date!(2021-01-01).with_time(time!(12:00)).assume_offset(offset!(+5:30))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `increment` is not positive or if the result is out of
+    /// range.
+    pub fn floor_to(self, increment: Duration) -> Self {
+        self.snap_to(increment, |remainder, _| -remainder)
+    }
+
+    /// Round the instant up to a multiple of `increment`, preserving the
+    /// offset. Multiples are counted from the Unix epoch in UTC, so the grid
+    /// is the same regardless of the offset.
+    ///
+    /// ```rust
+    /// # use time::{date, time, offset, prelude::*};
+    /// let datetime = date!(2021-01-01).with_time(time!(12:07:30)).assume_offset(offset!(+5:30));
+    /// assert_eq!(
+    ///     datetime.ceil_to(15.minutes()),
+    ///     date!(2021-01-01).with_time(time!(12:15)).assume_offset(offset!(+5:30))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `increment` is not positive or if the result is out of
+    /// range.
+    pub fn ceil_to(self, increment: Duration) -> Self {
+        self.snap_to(increment, |remainder, increment| {
+            if remainder == 0 {
+                0
+            } else {
+                increment - remainder
+            }
+        })
+    }
+
+    /// Round the instant to the nearest multiple of `increment`, preserving
+    /// the offset. Multiples are counted from the Unix epoch in UTC, so the
+    /// grid is the same regardless of the offset. Values exactly halfway
+    /// between two multiples are rounded up.
+    ///
+    /// ```rust
+    /// # use time::{date, time, offset, prelude::*};
+    /// let datetime = date!(2021-01-01).with_time(time!(12:07:29)).assume_offset(offset!(+5:30));
+    /// assert_eq!(
+    ///     datetime.round_to(15.minutes()),
+    ///     date!(2021-01-01).with_time(time!(12:00)).assume_offset(offset!(+5:30))
+    /// );
+    /// assert_eq!(
+    ///     (datetime + 1.seconds()).round_to(15.minutes()),
+    ///     date!(2021-01-01).with_time(time!(12:15)).assume_offset(offset!(+5:30))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `increment` is not positive or if the result is out of
+    /// range.
+    pub fn round_to(self, increment: Duration) -> Self {
+        self.snap_to(increment, |remainder, increment| {
+            if remainder * 2 >= increment {
+                increment - remainder
+            } else {
+                -remainder
+            }
+        })
+    }
+
+    /// Move the instant by the number of nanoseconds returned by `adjustment`,
+    /// which is given the nanoseconds past the previous multiple of
+    /// `increment` as well as `increment` itself.
+    fn snap_to(self, increment: Duration, adjustment: impl Fn(i128, i128) -> i128) -> Self {
+        assert!(increment.is_positive(), "increment must be positive");
+        let increment = increment.whole_nanoseconds();
+        let remainder = self
+            .duration_since_epoch()
+            .whole_nanoseconds()
+            .rem_euclid(increment);
+        self.checked_add(Duration::nanoseconds_i128(adjustment(remainder, increment)))
+            .expect("resulting value is out of range")
+    }
+
+    /// Replace the wall clock time, keeping the date and offset as they are
+    /// in the stored offset. Unless the time is unchanged, this always
+    /// results in a different instant.
+    ///
+    /// The date kept is the local one, which may differ from the date in UTC.
+    /// To instead set the time of day in UTC, first convert the value with
+    /// `to_offset(offset!(UTC))`, and convert back afterwards if necessary.
+    ///
+    /// ```rust
+    /// # use time::{date, offset, time};
+    /// let datetime = date!(2021-01-01)
+    ///     .with_time(time!(23:00))
+    ///     .assume_offset(offset!(-5));
+    ///
+    /// // The local date and offset are retained.
+    /// assert_eq!(
+    ///     datetime.set_local_time(time!(9:00)),
+    ///     date!(2021-01-01)
+    ///         .with_time(time!(9:00))
+    ///         .assume_offset(offset!(-5))
+    /// );
+    ///
+    /// // Setting the time in UTC gives a different result, as the UTC date is
+    /// // already January 2.
+    /// assert_eq!(
+    ///     datetime
+    ///         .to_offset(offset!(UTC))
+    ///         .set_local_time(time!(9:00))
+    ///         .to_offset(offset!(-5)),
+    ///     date!(2021-01-02)
+    ///         .with_time(time!(4:00))
+    ///         .assume_offset(offset!(-5))
+    /// );
+    /// ```
+    pub fn set_local_time(self, time: Time) -> Self {
+        self.date().with_time(time).assume_offset(self.offset)
+    }
+
     /// Midnight, 1 January, 1970 (UTC).
     ///
     /// ```rust
@@ -207,6 +392,31 @@ impl OffsetDateTime {
         OffsetDateTime::unix_epoch() + Duration::seconds(timestamp)
     }
 
+    /// Create an `OffsetDateTime` from the amount of time elapsed since the
+    /// Unix epoch. Unlike [`OffsetDateTime::from_unix_timestamp`], sub-second
+    /// precision is preserved.
+    ///
+    /// ```rust
+    /// # use time::{date, time, OffsetDateTime, prelude::*};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_duration_since_epoch(1_546_300_800.5.seconds()),
+    ///     Ok(date!(2019-01-01)
+    ///         .with_time(time!(0:00:00.5))
+    ///         .assume_utc()),
+    /// );
+    /// assert!(OffsetDateTime::from_duration_since_epoch(i64::max_value().seconds()).is_err());
+    /// ```
+    pub fn from_duration_since_epoch(duration: Duration) -> Result<Self, error::ComponentRange> {
+        // Round towards negative infinity, so that values just before the
+        // earliest representable instant are rejected.
+        let seconds = duration.whole_seconds() - (duration.subsec_nanoseconds() < 0) as i64;
+        ensure_value_in_range!(
+            seconds in MIN_DATETIME.assume_utc().timestamp()
+                => MAX_DATETIME.assume_utc().timestamp()
+        );
+        Ok(OffsetDateTime::unix_epoch() + duration)
+    }
+
     /// Get the `UtcOffset`.
     ///
     /// ```rust
@@ -254,6 +464,188 @@ impl OffsetDateTime {
         (self - Self::unix_epoch()).whole_seconds()
     }
 
+    /// Get the amount of time elapsed since the Unix epoch, preserving
+    /// sub-second precision. Instants before the epoch are represented by a
+    /// negative duration.
+    ///
+    /// ```rust
+    /// # use time::{date, time, prelude::*};
+    /// assert_eq!(
+    ///     date!(1970-01-01)
+    ///         .with_time(time!(0:00:01.5))
+    ///         .assume_utc()
+    ///         .duration_since_epoch(),
+    ///     1.5.seconds(),
+    /// );
+    /// assert_eq!(
+    ///     date!(1969-12-31)
+    ///         .with_time(time!(23:59:59.5))
+    ///         .assume_utc()
+    ///         .duration_since_epoch(),
+    ///     (-0.5).seconds(),
+    /// );
+    /// ```
+    pub fn duration_since_epoch(self) -> Duration {
+        self - Self::unix_epoch()
+    }
+
+    /// Get a key identifying the instant represented by the `OffsetDateTime`,
+    /// given as the whole seconds since the Unix epoch and the nanoseconds past
+    /// that second. The nanoseconds are always in the range `0..1_000_000_000`,
+    /// so instants before the epoch have their seconds rounded towards negative
+    /// infinity.
+    ///
+    /// The key does not depend on the offset, so two values representing the
+    /// same instant always produce the same key. This makes it suitable for
+    /// hashing and comparing instants, such as in a `HashMap`, without relying
+    /// on the behavior of the derived traits.
+    ///
+    /// ```rust
+    /// # use time::{date, time, offset};
+    /// let utc = date!(2020-01-01).with_time(time!(12:00)).assume_utc();
+    /// assert_eq!(utc.instant_key(), (1_577_880_000, 0));
+    /// assert_eq!(utc.to_offset(offset!(-5)).instant_key(), utc.instant_key());
+    /// assert_eq!(
+    ///     date!(1969-12-31)
+    ///         .with_time(time!(23:59:59.5))
+    ///         .assume_utc()
+    ///         .instant_key(),
+    ///     (-1, 500_000_000)
+    /// );
+    /// ```
+    pub fn instant_key(self) -> (i128, u32) {
+        let duration = self.duration_since_epoch();
+        let seconds = duration.whole_seconds() as i128;
+        let nanoseconds = duration.subsec_nanoseconds();
+        if nanoseconds < 0 {
+            (seconds - 1, (nanoseconds + 1_000_000_000) as u32)
+        } else {
+            (seconds, nanoseconds as u32)
+        }
+    }
+
+    /// Create an `OffsetDateTime` from an [NTP timestamp](https://en.wikipedia.org/wiki/Network_Time_Protocol#Timestamps),
+    /// given as the whole seconds since 1900-01-01T00:00:00Z and a fraction of
+    /// a second in units of 2<sup>-32</sup> seconds.
+    ///
+    /// NTP timestamps are 32 bits wide, and so roll over every 2<sup>32</sup>
+    /// seconds (about 136 years). The first rollover occurs at
+    /// 2036-02-07T06:28:16Z. As the era is not part of the timestamp, the
+    /// value is always interpreted as being in era 0, spanning from 1900
+    /// until that instant.
+    ///
+    /// ```rust
+    /// # use time::{date, time, OffsetDateTime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_ntp_timestamp(2_208_988_800, 0),
+    ///     OffsetDateTime::unix_epoch(),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_ntp_timestamp(0, 1 << 31),
+    ///     date!(1900-01-01).with_time(time!(0:00:00.5)).assume_utc(),
+    /// );
+    /// ```
+    pub fn from_ntp_timestamp(seconds: u32, fraction: u32) -> Self {
+        let nanoseconds = (fraction as u64 * 1_000_000_000) >> 32;
+        Self::unix_epoch()
+            + Duration::new(seconds as i64 - NTP_UNIX_EPOCH_OFFSET, nanoseconds as i32)
+    }
+
+    /// Get the [NTP timestamp](https://en.wikipedia.org/wiki/Network_Time_Protocol#Timestamps)
+    /// as the whole seconds since 1900-01-01T00:00:00Z and a fraction of a
+    /// second in units of 2<sup>-32</sup> seconds.
+    ///
+    /// The fraction is rounded up, such that passing the result to
+    /// [`OffsetDateTime::from_ntp_timestamp`] returns the original value
+    /// exactly. Values outside of era 0 (beginning at 2036-02-07T06:28:16Z or
+    /// before 1900) wrap around, as they do in the protocol itself.
+    ///
+    /// ```rust
+    /// # use time::{date, time, OffsetDateTime};
+    /// assert_eq!(
+    ///     OffsetDateTime::unix_epoch().to_ntp_timestamp(),
+    ///     (2_208_988_800, 0),
+    /// );
+    /// assert_eq!(
+    ///     date!(2036-02-07).with_time(time!(6:28:16)).assume_utc().to_ntp_timestamp(),
+    ///     (0, 0),
+    /// );
+    /// ```
+    pub fn to_ntp_timestamp(self) -> (u32, u32) {
+        let seconds = (self.timestamp() + NTP_UNIX_EPOCH_OFFSET).rem_euclid(1 << 32) as u32;
+        // Round up so that the conversion back truncates to the same value.
+        let scaled = self.nanosecond() as u64 * (1 << 32);
+        let mut fraction = scaled / 1_000_000_000;
+        if fraction * 1_000_000_000 < scaled {
+            fraction += 1;
+        }
+        (seconds, fraction as u32)
+    }
+
+    /// Create an `OffsetDateTime` from a number of .NET ticks, being the number
+    /// of 100 nanosecond intervals since 0001-01-01T00:00:00Z. The resulting
+    /// value is in UTC.
+    ///
+    /// Only the range representable by a .NET `DateTime` is accepted, which is
+    /// from `0` (0001-01-01T00:00:00Z) to `3_155_378_975_999_999_999`
+    /// (9999-12-31T23:59:59.9999999Z) inclusive.
+    ///
+    /// ```rust
+    /// # use time::{date, OffsetDateTime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_dotnet_ticks(621_355_968_000_000_000),
+    ///     Ok(OffsetDateTime::unix_epoch())
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_dotnet_ticks(0),
+    ///     Ok(date!(0001-01-01).midnight().assume_utc())
+    /// );
+    /// assert!(OffsetDateTime::from_dotnet_ticks(-1).is_err());
+    /// ```
+    pub fn from_dotnet_ticks(ticks: i64) -> Result<Self, error::ComponentRange> {
+        #![allow(clippy::manual_range_contains)]
+        ensure_value_in_range!(ticks in 0 => DOTNET_MAX_TICKS);
+        Self::from_duration_since_epoch(Duration::nanoseconds_i128(
+            (ticks - DOTNET_UNIX_EPOCH_TICKS) as i128 * 100,
+        ))
+    }
+
+    /// Get the number of .NET ticks, being the number of 100 nanosecond
+    /// intervals since 0001-01-01T00:00:00Z. Any sub-tick precision is
+    /// truncated towards negative infinity.
+    ///
+    /// The value is only meaningful to .NET for instants from 0001-01-01 to
+    /// 9999-12-31 inclusive; instants outside of that range produce values that
+    /// [`OffsetDateTime::from_dotnet_ticks`] rejects.
+    ///
+    /// ```rust
+    /// # use time::{date, time, offset, OffsetDateTime};
+    /// assert_eq!(
+    ///     OffsetDateTime::unix_epoch().to_dotnet_ticks(),
+    ///     621_355_968_000_000_000
+    /// );
+    /// assert_eq!(
+    ///     date!(2021-01-01)
+    ///         .with_time(time!(1:00:00.000_000_150))
+    ///         .assume_offset(offset!(+1))
+    ///         .to_dotnet_ticks(),
+    ///     637_450_560_000_000_001
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if the number of ticks does not fit in an `i64`, which is
+    /// the case for years beyond approximately ±29,000.
+    pub fn to_dotnet_ticks(self) -> i64 {
+        let ticks = self
+            .duration_since_epoch()
+            .whole_nanoseconds()
+            .div_euclid(100)
+            + DOTNET_UNIX_EPOCH_TICKS as i128;
+        i64::try_from(ticks).expect("resulting value is out of range")
+    }
+
     /// Get the `Date` in the stored offset.
     ///
     /// ```rust
@@ -709,89 +1101,764 @@ impl OffsetDateTime {
     pub fn nanosecond(self) -> u32 {
         self.time().nanosecond()
     }
-}
 
-/// Methods that allow formatting the `OffsetDateTime`.
-impl OffsetDateTime {
-    /// Format the `OffsetDateTime` using the provided string.
+    /// Sort a slice of `OffsetDateTime`s by the instant they represent,
+    /// regardless of the offset of each element.
+    ///
+    /// As the `Ord` implementation already compares instants, this is
+    /// equivalent to `slice.sort()`. It exists to make the intent explicit at
+    /// the call site. The sort is stable, so values representing the same
+    /// instant in different offsets retain their relative order.
     ///
     /// ```rust
-    /// # use time::{date};
-    /// assert_eq!(
-    ///     date!(2019-01-02)
-    ///         .midnight()
-    ///         .assume_utc()
-    ///         .format("%F %r %z"),
-    ///     "2019-01-02 12:00:00 am +0000",
-    /// );
+    /// # use time::{date, offset, OffsetDateTime};
+    /// let mut values = [
+    ///     date!(2019-01-01).midnight().assume_offset(offset!(-5)),
+    ///     date!(2019-01-01).midnight().assume_offset(offset!(+5)),
+    ///     date!(2019-01-01).midnight().assume_utc(),
+    /// ];
+    /// OffsetDateTime::sort_by_instant(&mut values);
+    /// assert_eq!(values[0].offset(), offset!(+5));
+    /// assert_eq!(values[1].offset(), offset!(UTC));
+    /// assert_eq!(values[2].offset(), offset!(-5));
     /// ```
-    pub fn format(self, format: impl Into<Format>) -> String {
-        self.lazy_format(format).to_string()
+    pub fn sort_by_instant(slice: &mut [Self]) {
+        slice.sort();
     }
 
-    /// Format the `OffsetDateTime` using the provided string.
+    /// Add the given number of months to the date in the stored offset,
+    /// keeping the time and offset. See [`Date::add_months_with_policy`] for
+    /// details.
     ///
     /// ```rust
-    /// # use time::date;
+    /// # use time::{date, offset, MonthAddPolicy};
     /// assert_eq!(
-    ///     date!(2019-01-02)
+    ///     date!(2019-02-28)
     ///         .midnight()
-    ///         .assume_utc()
-    ///         .lazy_format("%F %r %z")
-    ///         .to_string(),
-    ///     "2019-01-02 12:00:00 am +0000",
+    ///         .assume_offset(offset!(+1))
+    ///         .add_months_with_policy(1, MonthAddPolicy::PreserveEndOfMonth),
+    ///     Some(date!(2019-03-31).midnight().assume_offset(offset!(+1)))
     /// );
     /// ```
-    pub fn lazy_format(self, format: impl Into<Format>) -> impl Display {
-        DeferredFormat::new(format)
-            .with_date(self.date())
-            .with_time(self.time())
-            .with_offset(self.offset())
-            .to_owned()
+    pub fn add_months_with_policy(self, months: i32, policy: MonthAddPolicy) -> Option<Self> {
+        Some(
+            PrimitiveDateTime::new(self.date(), self.time())
+                .add_months_with_policy(months, policy)?
+                .assume_offset(self.offset),
+        )
     }
 
-    /// Attempt to parse an `OffsetDateTime` using the provided string.
+    /// Add the given number of days to the local date, keeping the local time
+    /// and using the system's UTC offset at the resulting moment. Unlike adding
+    /// a [`Duration`], which keeps the offset fixed, the wall clock time is
+    /// preserved when a daylight saving time transition occurs in between.
+    ///
+    /// - If the local time occurs twice, such as when the clock is moved back,
+    ///   one of the two corresponding moments is returned.
+    /// - If the local time does not exist, such as when the clock is moved
+    ///   forward, the result is moved forward by the length of the gap. For
+    ///   example, 02:30 on a day where 02:00 becomes 03:00 results in 03:30.
+    ///
+    /// Returns `None` if the resulting date is out of range.
+    ///
+    /// ```rust,no_run
+    /// # use time::OffsetDateTime;
+    /// let now = OffsetDateTime::now_local();
+    /// let tomorrow = now.add_days_local(1).unwrap();
+    /// assert_eq!(tomorrow.time(), now.time());
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docs, doc(cfg(feature = "std")))]
+    pub fn add_days_local(self, days: i64) -> Option<Self> {
+        self.add_days_with_offset(days, UtcOffset::local_offset_at)
+    }
+
+    /// Add the given number of days to the local date, keeping the local time
+    /// and obtaining the offset for the result from `offset_at`.
+    #[cfg(feature = "std")]
+    fn add_days_with_offset<F: Fn(Self) -> UtcOffset>(
+        self,
+        days: i64,
+        offset_at: F,
+    ) -> Option<Self> {
+        let date = Date::try_from_julian_day(self.date().julian_day().checked_add(days)?).ok()?;
+        let local = date.with_time(self.time()).assume_utc();
+        // The moment at which the local time is reached in the given offset.
+        let in_offset = |offset: UtcOffset| {
+            local
+                .checked_sub(offset.as_duration())?
+                .checked_add_in_offset(Duration::zero(), offset)
+        };
+
+        let first = in_offset(offset_at(in_offset(self.offset)?))?;
+        let offset = offset_at(first);
+        if offset == first.offset {
+            return Some(first);
+        }
+        let second = in_offset(offset)?;
+        if offset_at(second) == offset {
+            return Some(second);
+        }
+
+        // The local time falls within a gap. The earlier of the two offsets
+        // gives the later moment, which is then shown in its actual offset.
+        let later = first.max(second);
+        later.checked_add_in_offset(Duration::zero(), offset_at(later))
+    }
+}
+
+/// Methods for arithmetic that may overflow.
+impl OffsetDateTime {
+    /// Computes `self + duration`, returning `None` if the result, either in
+    /// UTC or in its offset, is outside the representable range.
     ///
     /// ```rust
-    /// # use time::{date, OffsetDateTime, time};
+    /// # use time::{date, Duration, prelude::*};
+    /// let datetime = date!(2019-01-01).midnight().assume_utc();
     /// assert_eq!(
-    ///     OffsetDateTime::parse("2019-01-02 00:00:00 +0000", "%F %T %z"),
-    ///     Ok(date!(2019-01-02).midnight().assume_utc()),
+    ///     datetime.checked_add(1.days()),
+    ///     Some(date!(2019-01-02).midnight().assume_utc())
     /// );
+    /// assert_eq!(datetime.checked_add(Duration::max_value()), None);
+    /// ```
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        self.checked_add_in_offset(duration, self.offset)
+    }
+
+    /// Computes `self - duration`, returning `None` if the result, either in
+    /// UTC or in its offset, is outside the representable range.
+    ///
+    /// ```rust
+    /// # use time::{date, Duration, prelude::*};
+    /// let datetime = date!(2019-01-01).midnight().assume_utc();
     /// assert_eq!(
-    ///     OffsetDateTime::parse("2019-002 23:59:59 +0000", "%Y-%j %T %z"),
-    ///     Ok(date!(2019-002).with_time(time!(23:59:59)).assume_utc()),
+    ///     datetime.checked_sub(1.days()),
+    ///     Some(date!(2018-12-31).midnight().assume_utc())
     /// );
+    /// assert_eq!(datetime.checked_sub(Duration::max_value()), None);
+    /// ```
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        let utc_datetime = self.utc_datetime.checked_sub(duration)?;
+        utc_datetime.checked_add(self.offset.as_duration())?;
+        Some(Self {
+            utc_datetime,
+            offset: self.offset,
+        })
+    }
+
+    /// Computes `self + duration` for a [`core::time::Duration`], returning
+    /// `None` if the result is outside the representable range.
+    ///
+    /// ```rust
+    /// # use time::{date, prelude::*};
+    /// # use core::time::Duration;
+    /// let datetime = date!(2019-01-01).midnight().assume_utc();
     /// assert_eq!(
-    ///     OffsetDateTime::parse("2019-W01-3 12:00:00 pm +0000", "%G-W%V-%u %r %z"),
-    ///     Ok(date!(2019-W01-3).with_time(time!(12:00)).assume_utc()),
+    ///     datetime.checked_add_std(1.std_days()),
+    ///     Some(date!(2019-01-02).midnight().assume_utc())
     /// );
+    /// assert_eq!(datetime.checked_add_std(Duration::from_secs(u64::max_value())), None);
     /// ```
-    pub fn parse(s: impl AsRef<str>, format: impl Into<Format>) -> ParseResult<Self> {
-        Self::try_from_parsed_items(parse(s.as_ref(), &format.into())?)
+    pub fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        self.checked_add(Duration::try_from(duration).ok()?)
     }
 
-    /// Given the items already parsed, attempt to create an `OffsetDateTime`.
-    pub(crate) fn try_from_parsed_items(items: ParsedItems) -> ParseResult<Self> {
-        let offset = UtcOffset::try_from_parsed_items(items)?;
-        Ok(PrimitiveDateTime::try_from_parsed_items(items)?.assume_offset(offset))
+    /// Computes `self - duration` for a [`core::time::Duration`], returning
+    /// `None` if the result is outside the representable range.
+    ///
+    /// ```rust
+    /// # use time::{date, prelude::*};
+    /// # use core::time::Duration;
+    /// let datetime = date!(2019-01-01).midnight().assume_utc();
+    /// assert_eq!(
+    ///     datetime.checked_sub_std(1.std_days()),
+    ///     Some(date!(2018-12-31).midnight().assume_utc())
+    /// );
+    /// assert_eq!(datetime.checked_sub_std(Duration::from_secs(u64::max_value())), None);
+    /// ```
+    pub fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        self.checked_sub(Duration::try_from(duration).ok()?)
     }
-}
 
-impl Display for OffsetDateTime {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {} {}", self.date(), self.time(), self.offset())
+    /// Computes `self + duration` and converts the result to the provided
+    /// offset, returning `None` if either the instant or its representation in
+    /// the new offset is outside the representable range.
+    ///
+    /// ```rust
+    /// # use time::{date, offset, time, Duration, prelude::*};
+    /// let datetime = date!(2019-01-01).with_time(time!(23:00)).assume_offset(offset!(-5));
+    /// assert_eq!(
+    ///     datetime.checked_add_in_offset(3.hours(), offset!(UTC)),
+    ///     Some(date!(2019-01-02).with_time(time!(7:00)).assume_utc())
+    /// );
+    /// assert_eq!(
+    ///     datetime.checked_add_in_offset(Duration::max_value(), offset!(UTC)),
+    ///     None
+    /// );
+    /// ```
+    pub fn checked_add_in_offset(self, duration: Duration, offset: UtcOffset) -> Option<Self> {
+        let utc_datetime = self.utc_datetime.checked_add(duration)?;
+        utc_datetime.checked_add(offset.as_duration())?;
+        Some(Self {
+            utc_datetime,
+            offset,
+        })
     }
-}
 
-impl PartialEq for OffsetDateTime {
-    fn eq(&self, rhs: &Self) -> bool {
-        self.utc_datetime.eq(&rhs.utc_datetime)
+    /// Computes `self + duration`, saturating at the earliest or latest
+    /// instant that is representable both in UTC and in the offset of `self`.
+    /// The offset is retained.
+    ///
+    /// ```rust
+    /// # use time::{date, offset, time, Duration, prelude::*};
+    /// let datetime = date!(2019-01-01).midnight().assume_utc();
+    /// assert_eq!(
+    ///     datetime.saturating_add(1.days()),
+    ///     date!(2019-01-02).midnight().assume_utc()
+    /// );
+    /// let max = date!(+100_000-12-31).with_time(time!(23:59:59.999_999_999));
+    /// assert_eq!(
+    ///     datetime.saturating_add(Duration::max_value()),
+    ///     max.assume_utc(),
+    /// );
+    /// assert_eq!(
+    ///     datetime
+    ///         .to_offset(offset!(+1))
+    ///         .saturating_add(Duration::max_value()),
+    ///     max.assume_offset(offset!(+1)),
+    /// );
+    /// ```
+    pub fn saturating_add(self, duration: Duration) -> Self {
+        self.checked_add(duration)
+            .unwrap_or_else(|| self.saturate(duration.is_negative()))
     }
-}
 
-impl PartialOrd for OffsetDateTime {
-    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+    /// Computes `self - duration`, saturating at the earliest or latest
+    /// instant that is representable both in UTC and in the offset of `self`.
+    /// The offset is retained.
+    ///
+    /// ```rust
+    /// # use time::{date, offset, Duration, prelude::*};
+    /// let datetime = date!(2019-01-01).midnight().assume_utc();
+    /// assert_eq!(
+    ///     datetime.saturating_sub(1.days()),
+    ///     date!(2018-12-31).midnight().assume_utc()
+    /// );
+    /// let min = date!(-100_000-01-01).midnight();
+    /// assert_eq!(
+    ///     datetime.saturating_sub(Duration::max_value()),
+    ///     min.assume_utc(),
+    /// );
+    /// assert_eq!(
+    ///     datetime
+    ///         .to_offset(offset!(-1))
+    ///         .saturating_sub(Duration::max_value()),
+    ///     min.assume_offset(offset!(-1)),
+    /// );
+    /// ```
+    pub fn saturating_sub(self, duration: Duration) -> Self {
+        self.checked_sub(duration)
+            .unwrap_or_else(|| self.saturate(duration.is_positive()))
+    }
+
+    /// Get the instant halfway between `self` and `other`, with nanosecond
+    /// precision. When the exact midpoint falls between two nanoseconds, the
+    /// one closer to `self` is returned. The offset of `self` is retained.
+    ///
+    /// ```rust
+    /// # use time::{date, offset, prelude::*};
+    /// let start = date!(2021-01-01).midnight().assume_utc();
+    /// let end = date!(2021-01-03).midnight().assume_offset(offset!(+1));
+    /// assert_eq!(start.midpoint(end), start + 23.5.hours());
+    /// assert_eq!(start.midpoint(end).offset(), offset!(UTC));
+    /// ```
+    pub fn midpoint(self, other: Self) -> Self {
+        // Halving the difference, unlike halving the sum, cannot overflow.
+        // Division truncates towards zero, which is towards `self`.
+        self + (other - self) / 2
+    }
+
+    /// Get the earliest (if `minimum` is true) or latest instant that is
+    /// representable both in UTC and in the offset of `self`, retaining the
+    /// offset.
+    fn saturate(self, minimum: bool) -> Self {
+        let offset = self.offset.as_duration();
+        let utc_datetime = match (minimum, self.offset.as_seconds()) {
+            (true, seconds) if seconds < 0 => MIN_DATETIME - offset,
+            (true, _) => MIN_DATETIME,
+            (false, seconds) if seconds > 0 => MAX_DATETIME - offset,
+            (false, _) => MAX_DATETIME,
+        };
+        Self {
+            utc_datetime,
+            offset: self.offset,
+        }
+    }
+}
+
+/// Methods that allow formatting the `OffsetDateTime`.
+impl OffsetDateTime {
+    /// Format the `OffsetDateTime` using the provided string.
+    ///
+    /// ```rust
+    /// # use time::{date};
+    /// assert_eq!(
+    ///     date!(2019-01-02)
+    ///         .midnight()
+    ///         .assume_utc()
+    ///         .format("%F %r %z"),
+    ///     "2019-01-02 12:00:00 am +0000",
+    /// );
+    /// ```
+    pub fn format(self, format: impl Into<Format>) -> String {
+        self.lazy_format(format).to_string()
+    }
+
+    /// Format the `OffsetDateTime` as RFC3339, with exactly `digits`
+    /// fractional digits of the second. Additional precision is truncated. A
+    /// precision of zero omits the fractional part entirely.
+    ///
+    /// [`Format::Rfc3339`] instead uses the fewest of 0, 3, 6, or 9 digits that
+    /// represent the value exactly.
+    ///
+    /// ```rust
+    /// # use time::{date, time};
+    /// let datetime = date!(2019-01-02)
+    ///     .with_time(time!(12:00:00.123_456))
+    ///     .assume_utc();
+    /// assert_eq!(
+    ///     datetime.format_rfc3339_with_precision(3),
+    ///     "2019-01-02T12:00:00.123+00:00"
+    /// );
+    /// assert_eq!(
+    ///     datetime.format_rfc3339_with_precision(0),
+    ///     "2019-01-02T12:00:00+00:00"
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `digits` is greater than 9.
+    pub fn format_rfc3339_with_precision(self, digits: u8) -> String {
+        /// Formats an `OffsetDateTime` as RFC3339 with a fixed precision.
+        struct Rfc3339WithPrecision(OffsetDateTime, u8);
+
+        impl Display for Rfc3339WithPrecision {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let Rfc3339WithPrecision(datetime, digits) = *self;
+                well_known::rfc3339::fmt_components(
+                    f,
+                    datetime.date(),
+                    datetime.time(),
+                    datetime.offset(),
+                    Some(digits),
+                )
+            }
+        }
+
+        assert!(
+            digits <= 9,
+            "digits must be in the range 0..=9 (was {})",
+            digits
+        );
+        Rfc3339WithPrecision(self, digits).to_string()
+    }
+
+    /// Format the `OffsetDateTime` as a Postgres `timestamptz`, which
+    /// separates the date and time with a space. The offset is written with
+    /// only as many components as it needs, so a whole number of hours is
+    /// written as just the hours (`+00`).
+    ///
+    /// ```rust
+    /// # use time::{date, time, offset};
+    /// assert_eq!(
+    ///     date!(2021-01-01).midnight().assume_utc().format_sql_timestamptz(),
+    ///     "2021-01-01 00:00:00+00"
+    /// );
+    /// assert_eq!(
+    ///     date!(2021-01-01)
+    ///         .with_time(time!(12:34:56.789))
+    ///         .assume_offset(offset!(+5:30))
+    ///         .format_sql_timestamptz(),
+    ///     "2021-01-01 12:34:56.789+05:30"
+    /// );
+    /// ```
+    pub fn format_sql_timestamptz(self) -> String {
+        /// Formats an `OffsetDateTime` as a Postgres `timestamptz`.
+        struct SqlTimestamptz(OffsetDateTime);
+
+        impl Display for SqlTimestamptz {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let SqlTimestamptz(datetime) = *self;
+                well_known::sql_timestamptz::fmt(
+                    f,
+                    datetime.date(),
+                    datetime.time(),
+                    datetime.offset(),
+                )
+            }
+        }
+
+        SqlTimestamptz(self).to_string()
+    }
+
+    /// Format the `OffsetDateTime` using the provided string.
+    ///
+    /// ```rust
+    /// # use time::date;
+    /// assert_eq!(
+    ///     date!(2019-01-02)
+    ///         .midnight()
+    ///         .assume_utc()
+    ///         .lazy_format("%F %r %z")
+    ///         .to_string(),
+    ///     "2019-01-02 12:00:00 am +0000",
+    /// );
+    /// ```
+    pub fn lazy_format(self, format: impl Into<Format>) -> impl Display {
+        DeferredFormat::new(format)
+            .with_date(self.date())
+            .with_time(self.time())
+            .with_offset(self.offset())
+            .to_owned()
+    }
+
+    /// Attempt to parse an `OffsetDateTime` using the provided string.
+    ///
+    /// ```rust
+    /// # use time::{date, OffsetDateTime, time};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse("2019-01-02 00:00:00 +0000", "%F %T %z"),
+    ///     Ok(date!(2019-01-02).midnight().assume_utc()),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse("2019-002 23:59:59 +0000", "%Y-%j %T %z"),
+    ///     Ok(date!(2019-002).with_time(time!(23:59:59)).assume_utc()),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse("2019-W01-3 12:00:00 pm +0000", "%G-W%V-%u %r %z"),
+    ///     Ok(date!(2019-W01-3).with_time(time!(12:00)).assume_utc()),
+    /// );
+    /// ```
+    pub fn parse(s: impl AsRef<str>, format: impl Into<Format>) -> ParseResult<Self> {
+        Self::try_from_parsed_items(parse(s.as_ref(), &format.into())?)
+    }
+
+    /// Attempt to parse an `OffsetDateTime` using the provided string,
+    /// choosing how closely well-known formats must be followed. `parse` is
+    /// equivalent to using [`ParseStrictness::Strict`].
+    ///
+    /// ```rust
+    /// # use time::{date, Format, OffsetDateTime, ParseStrictness};
+    /// let expected = Ok(date!(2021-01-01).midnight().assume_utc());
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_with_strictness(
+    ///         "2021-01-01T00:00:00Z",
+    ///         Format::Rfc3339,
+    ///         ParseStrictness::Strict
+    ///     ),
+    ///     expected,
+    /// );
+    /// assert!(OffsetDateTime::parse_with_strictness(
+    ///     "2021-01-01 00:00:00z",
+    ///     Format::Rfc3339,
+    ///     ParseStrictness::Strict
+    /// )
+    /// .is_err());
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_with_strictness(
+    ///         "2021-01-01 00:00:00z",
+    ///         Format::Rfc3339,
+    ///         ParseStrictness::Lenient
+    ///     ),
+    ///     expected,
+    /// );
+    /// ```
+    pub fn parse_with_strictness(
+        s: impl AsRef<str>,
+        format: impl Into<Format>,
+        strictness: ParseStrictness,
+    ) -> ParseResult<Self> {
+        Self::try_from_parsed_items(parse_with_strictness(
+            s.as_ref(),
+            &format.into(),
+            strictness,
+        )?)
+    }
+
+    /// Attempt to parse an `OffsetDateTime` using the provided string, with
+    /// `%Z` recognizing the provided abbreviations rather than the default
+    /// ones.
+    ///
+    /// ```rust
+    /// # use time::{date, offset, OffsetDateTime, ZoneAbbreviations};
+    /// let zones = ZoneAbbreviations {
+    ///     known: &[("CST", offset!(+8))],
+    ///     ambiguous: &[],
+    /// };
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_with_zones("2021-01-01 00:00 CST", "%F %H:%M %Z", zones),
+    ///     Ok(date!(2021-01-01).midnight().assume_offset(offset!(+8))),
+    /// );
+    /// ```
+    pub fn parse_with_zones(
+        s: impl AsRef<str>,
+        format: impl Into<Format>,
+        zones: ZoneAbbreviations<'_>,
+    ) -> ParseResult<Self> {
+        Self::try_from_parsed_items(parse_with_zones(s.as_ref(), &format.into(), zones)?)
+    }
+
+    /// Attempt to parse an `OffsetDateTime` using the provided string, ignoring
+    /// any ASCII whitespace surrounding the input. [`parse`] requires the input
+    /// to begin immediately.
+    ///
+    /// [`parse`]: OffsetDateTime::parse
+    ///
+    /// ```rust
+    /// # use time::{date, Format, OffsetDateTime};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_trimmed(" 2021-01-01T00:00:00Z\n", Format::Rfc3339),
+    ///     Ok(date!(2021-01-01).midnight().assume_utc()),
+    /// );
+    /// assert!(OffsetDateTime::parse(" 2021-01-01T00:00:00Z", Format::Rfc3339).is_err());
+    /// ```
+    pub fn parse_trimmed(s: impl AsRef<str>, format: impl Into<Format>) -> ParseResult<Self> {
+        Self::parse(
+            s.as_ref().trim_matches(|c: char| c.is_ascii_whitespace()),
+            format,
+        )
+    }
+
+    /// Attempt to parse a time using the provided string, combining it with the
+    /// provided date and offset. This is useful for input that only contains
+    /// the time, such as timestamps in a log file.
+    ///
+    /// The format is interpreted as in [`Time::parse`]. Any date or offset
+    /// components it contains are parsed, but their values are not used.
+    ///
+    /// ```rust
+    /// # use time::{date, offset, time, OffsetDateTime};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_time_on("14:30:05", "%T", date!(2021-01-01), offset!(+1)),
+    ///     Ok(date!(2021-01-01).with_time(time!(14:30:05)).assume_offset(offset!(+1))),
+    /// );
+    /// ```
+    pub fn parse_time_on(
+        s: impl AsRef<str>,
+        format: impl Into<Format>,
+        date: Date,
+        offset: UtcOffset,
+    ) -> ParseResult<Self> {
+        let time = Time::try_from_parsed_items(parse(s.as_ref(), &format.into())?)?;
+        Ok(date.with_time(time).assume_offset(offset))
+    }
+
+    /// Attempt to parse an `OffsetDateTime` using the provided string,
+    /// rejecting any offset outside the range used in practice (`-12:00` to
+    /// `+14:00` inclusive). This is useful to catch bogus data; [`parse`]
+    /// accepts any offset up to ±23:59:59.
+    ///
+    /// [`parse`]: OffsetDateTime::parse
+    ///
+    /// ```rust
+    /// # use time::{date, offset, OffsetDateTime, ParseError};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_offset_realistic("2019-01-02 00:00:00 +1400", "%F %T %z"),
+    ///     Ok(date!(2019-01-02).midnight().assume_offset(offset!(+14))),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_offset_realistic("2019-01-02 00:00:00 +2000", "%F %T %z"),
+    ///     Err(ParseError::InvalidOffset),
+    /// );
+    /// ```
+    pub fn parse_offset_realistic(
+        s: impl AsRef<str>,
+        format: impl Into<Format>,
+    ) -> ParseResult<Self> {
+        let datetime = Self::parse(s, format)?;
+        let seconds = datetime.offset.as_seconds();
+        #[allow(clippy::manual_range_contains)]
+        let is_realistic = seconds >= -12 * 3_600 && seconds <= 14 * 3_600;
+        if !is_realistic {
+            return Err(error::Parse::InvalidOffset);
+        }
+        Ok(datetime)
+    }
+
+    /// Attempt to parse a datetime using the provided string, where the
+    /// offset may or may not be present in the input. The wall-clock datetime
+    /// is returned alongside the offset, if one was parsed.
+    ///
+    /// This allows the caller to decide how an input without an offset should
+    /// be interpreted, rather than receiving
+    /// [`ParseError::InsufficientInformation`](crate::ParseError::InsufficientInformation).
+    ///
+    /// Well-known formats are parsed with [`ParseStrictness::Strict`], so that
+    /// an offset is never assumed. As RFC3339 requires an offset, input
+    /// without one results in an error.
+    ///
+    /// ```rust
+    /// # use time::{date, offset, OffsetDateTime};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_with_offset_flag("2019-01-02 00:00:00 +0100", "%F %T %z"),
+    ///     Ok((date!(2019-01-02).midnight(), Some(offset!(+1)))),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_with_offset_flag("2019-01-02 00:00:00", "%F %T"),
+    ///     Ok((date!(2019-01-02).midnight(), None)),
+    /// );
+    /// ```
+    pub fn parse_with_offset_flag(
+        s: impl AsRef<str>,
+        format: impl Into<Format>,
+    ) -> ParseResult<(PrimitiveDateTime, Option<UtcOffset>)> {
+        let items = parse_with_strictness(s.as_ref(), &format.into(), ParseStrictness::Strict)?;
+        Ok((
+            PrimitiveDateTime::try_from_parsed_items(items)?,
+            items.offset,
+        ))
+    }
+
+    /// Attempt to parse a Postgres `timestamptz`, as produced by
+    /// [`OffsetDateTime::format_sql_timestamptz`]. The offset may be given as
+    /// the hours alone (`+00`), or with minutes and seconds (`-03:30`).
+    ///
+    /// ```rust
+    /// # use time::{date, time, offset, OffsetDateTime};
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_sql_timestamptz("2021-01-01 00:00:00+00"),
+    ///     Ok(date!(2021-01-01).midnight().assume_utc()),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_sql_timestamptz("2021-01-01 12:34:56.5-03:30"),
+    ///     Ok(date!(2021-01-01)
+    ///         .with_time(time!(12:34:56.5))
+    ///         .assume_offset(offset!(-3:30))),
+    /// );
+    /// ```
+    pub fn parse_sql_timestamptz(s: impl AsRef<str>) -> ParseResult<Self> {
+        let mut items = ParsedItems::new();
+        well_known::sql_timestamptz::parse(&mut items, &mut s.as_ref())?;
+        Self::try_from_parsed_items(items)
+    }
+
+    /// Format the instant as a fixed-width UTC string whose lexicographic
+    /// order matches chronological order, for use as a sort key.
+    ///
+    /// The key is 27 characters long: a six-character year, the month, day,
+    /// `T`, hour, minute, second, nine digits of nanoseconds, and `Z`. Years
+    /// from zero onwards are zero-padded to six digits. A negative year is
+    /// written as `-` followed by five digits holding `100_000 + year`, so
+    /// that earlier years sort first and all negative years sort before
+    /// year zero.
+    ///
+    /// ```rust
+    /// # use time::{date, offset};
+    /// assert_eq!(
+    ///     date!(2021-01-01)
+    ///         .midnight()
+    ///         .assume_offset(offset!(+1))
+    ///         .to_sortable_key(),
+    ///     "0020201231T230000000000000Z"
+    /// );
+    /// assert_eq!(
+    ///     date!(-0001-12-31).midnight().assume_utc().to_sortable_key(),
+    ///     "-999991231T000000000000000Z"
+    /// );
+    /// ```
+    pub fn to_sortable_key(self) -> String {
+        let datetime = self.utc_datetime;
+        let year = datetime.year();
+        let year = if year < 0 {
+            format!("-{:05}", 100_000 + year)
+        } else {
+            format!("{:06}", year)
+        };
+
+        format!(
+            "{}{:02}{:02}T{:02}{:02}{:02}{:09}Z",
+            year,
+            datetime.month(),
+            datetime.day(),
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second(),
+            datetime.nanosecond()
+        )
+    }
+
+    /// Parse a key created by [`OffsetDateTime::to_sortable_key`]. The
+    /// returned value has an offset of UTC.
+    ///
+    /// ```rust
+    /// # use time::{date, OffsetDateTime};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_sortable_key("0020201231T230000000000000Z"),
+    ///     Ok(date!(2020-12-31).with_time(time::time!(23:00)).assume_utc())
+    /// );
+    /// assert!(OffsetDateTime::from_sortable_key("20201231T230000Z").is_err());
+    /// ```
+    pub fn from_sortable_key(key: &str) -> ParseResult<Self> {
+        use crate::format::{
+            parse::{try_consume_char, try_consume_exact_digits},
+            Padding,
+        };
+
+        /// Consume exactly `num_digits` digits, returning `error` if they are
+        /// not present.
+        fn digits<T: core::str::FromStr>(
+            s: &mut &str,
+            num_digits: usize,
+            error: error::Parse,
+        ) -> ParseResult<T> {
+            try_consume_exact_digits(s, num_digits, Padding::Zero).ok_or(error)
+        }
+
+        let mut s = key;
+        let year = if try_consume_char(&mut s, '-').is_ok() {
+            digits::<i32>(&mut s, 5, error::Parse::InvalidYear)? - 100_000
+        } else {
+            digits(&mut s, 6, error::Parse::InvalidYear)?
+        };
+        let month = digits(&mut s, 2, error::Parse::InvalidMonth)?;
+        let day = digits(&mut s, 2, error::Parse::InvalidDayOfMonth)?;
+        try_consume_char(&mut s, 'T')?;
+        let hour = digits(&mut s, 2, error::Parse::InvalidHour)?;
+        let minute = digits(&mut s, 2, error::Parse::InvalidMinute)?;
+        let second = digits(&mut s, 2, error::Parse::InvalidSecond)?;
+        let nanosecond = digits(&mut s, 9, error::Parse::InvalidNanosecond)?;
+        try_consume_char(&mut s, 'Z')?;
+        if !s.is_empty() {
+            return Err(error::Parse::UnexpectedTrailingCharacters);
+        }
+
+        Ok(Date::try_from_ymd(year, month, day)?
+            .with_time(Time::try_from_hms_nano(hour, minute, second, nanosecond)?)
+            .assume_utc())
+    }
+
+    /// Given the items already parsed, attempt to create an `OffsetDateTime`.
+    pub(crate) fn try_from_parsed_items(items: ParsedItems) -> ParseResult<Self> {
+        let offset = UtcOffset::try_from_parsed_items(items)?;
+        Ok(PrimitiveDateTime::try_from_parsed_items(items)?.assume_offset(offset))
+    }
+}
+
+impl Display for OffsetDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.date(), self.time(), self.offset())
+    }
+}
+
+impl PartialEq for OffsetDateTime {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.utc_datetime.eq(&rhs.utc_datetime)
+    }
+}
+
+impl PartialOrd for OffsetDateTime {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
         Some(self.cmp(rhs))
     }
 }
@@ -1051,18 +2118,139 @@ mod test {
     }
 
     #[test]
-    fn unix_epoch() -> crate::Result<()> {
-        assert_eq!(
-            OffsetDateTime::unix_epoch(),
-            date!(1970-01-01).midnight().assume_utc(),
-        );
+    fn to_offset_round_trip() -> crate::Result<()> {
+        let instants = [
+            date!(2020-02-29)
+                .with_time(time!(23:59:59:999_999_999))
+                .assume_utc(),
+            date!(1969-12-31)
+                .with_time(time!(0:00:00:000_000_001))
+                .assume_offset(offset!(+5:30)),
+            date!(2000-01-01)
+                .with_time(time!(12:34:56:123_456_789))
+                .assume_offset(UtcOffset::seconds(-45_296)),
+        ];
+
+        let max = 23 * 3_600 + 59 * 60 + 59;
+        let edges = [-max, -1, 0, 1, max];
+        let offsets = (-max..=max)
+            .step_by(997)
+            .map(UtcOffset::seconds)
+            .chain(edges.iter().map(|&seconds| UtcOffset::seconds(seconds)));
+
+        for offset in offsets {
+            for &odt in &instants {
+                let converted = odt.to_offset(offset);
+                assert_eq!(converted.offset(), offset);
+                assert_eq!(converted, odt);
+                assert_eq!(converted.to_offset(offset!(UTC)), odt);
+                assert_eq!(
+                    converted.to_offset(offset!(UTC)).time(),
+                    odt.to_offset(offset!(UTC)).time()
+                );
+                assert_eq!(converted.timestamp(), odt.timestamp());
+                assert_eq!(converted.nanosecond(), odt.nanosecond());
+
+                // Reassembling the local components yields the same instant.
+                let local = PrimitiveDateTime::new(converted.date(), converted.time());
+                assert_eq!(local.assume_offset(offset), odt);
+                assert_eq!(local.assume_offset(offset) - odt, Duration::zero());
+            }
+        }
         Ok(())
     }
 
     #[test]
-    fn from_unix_timestamp() -> crate::Result<()> {
+    fn approx_eq() -> crate::Result<()> {
+        let datetime = date!(2021-01-01)
+            .with_time(time!(12:00:00:123_456_789))
+            .assume_utc();
+        let truncated = date!(2021-01-01)
+            .with_time(time!(12:00:00:123_000_000))
+            .assume_utc();
+
+        assert!(datetime.approx_eq(datetime + 1.milliseconds(), 1.seconds()));
+        assert!(datetime.approx_eq(datetime - 1.milliseconds(), 1.seconds()));
+        assert!(!datetime.approx_eq(datetime + 2.seconds(), 1.seconds()));
+        assert!(!datetime.approx_eq(datetime - 2.seconds(), 1.seconds()));
+        assert!(datetime.approx_eq(truncated, 1.milliseconds()));
+        assert!(!datetime.approx_eq(truncated, 1.microseconds()));
+        assert!(datetime.approx_eq(datetime + 1.seconds(), 1.seconds()));
+        assert!(datetime.approx_eq(datetime.to_offset(offset!(-5)), Duration::zero()));
+        assert!(!datetime.approx_eq(datetime, (-1).seconds()));
+        Ok(())
+    }
+
+    #[test]
+    fn floor_ceil_round_to() -> crate::Result<()> {
+        // 12:07:30 in +05:30 is 06:37:30 UTC.
+        let datetime = date!(2021-01-01)
+            .with_time(time!(12:07:30))
+            .assume_offset(offset!(+5:30));
+        let floor = datetime.floor_to(15.minutes());
+        assert_eq!(floor.offset(), offset!(+5:30));
+        assert_eq!(floor.time(), time!(12:00));
         assert_eq!(
-            OffsetDateTime::from_unix_timestamp(0),
+            floor.to_offset(offset!(UTC)),
+            date!(2021-01-01).with_time(time!(6:30)).assume_utc()
+        );
+        assert_eq!(floor.timestamp() % (15 * 60), 0);
+        assert_eq!(datetime.ceil_to(15.minutes()).time(), time!(12:15));
+        assert_eq!(datetime.round_to(15.minutes()).time(), time!(12:15));
+        assert_eq!(
+            (datetime - 1.nanoseconds()).round_to(15.minutes()).time(),
+            time!(12:00)
+        );
+
+        // The grid is aligned in UTC rather than in the local offset.
+        let datetime = date!(2021-01-01)
+            .with_time(time!(12:50))
+            .assume_offset(offset!(+5:30));
+        assert_eq!(datetime.floor_to(1.hours()).time(), time!(12:30));
+        assert_eq!(datetime.ceil_to(1.hours()).time(), time!(13:30));
+        assert_eq!(datetime.round_to(1.hours()).time(), time!(12:30));
+
+        // Values on the grid are unchanged.
+        let on_grid = date!(2021-01-01).with_time(time!(6:30)).assume_utc();
+        assert_eq!(on_grid.floor_to(15.minutes()), on_grid);
+        assert_eq!(on_grid.ceil_to(15.minutes()), on_grid);
+        assert_eq!(on_grid.round_to(15.minutes()), on_grid);
+
+        // Instants before the epoch round towards negative infinity.
+        let before_epoch = OffsetDateTime::unix_epoch() - 1.nanoseconds();
+        assert_eq!(
+            before_epoch.floor_to(1.seconds()),
+            OffsetDateTime::unix_epoch() - 1.seconds()
+        );
+        assert_eq!(
+            before_epoch.ceil_to(1.seconds()),
+            OffsetDateTime::unix_epoch()
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn snap_to_non_positive_increment() {
+        let datetime = OffsetDateTime::unix_epoch();
+        assert_panics!(datetime.floor_to(Duration::zero()));
+        assert_panics!(datetime.ceil_to((-1).minutes()));
+        assert_panics!(datetime.round_to((-1).minutes()));
+    }
+
+    #[test]
+    fn unix_epoch() -> crate::Result<()> {
+        assert_eq!(
+            OffsetDateTime::unix_epoch(),
+            date!(1970-01-01).midnight().assume_utc(),
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_unix_timestamp() -> crate::Result<()> {
+        assert_eq!(
+            OffsetDateTime::from_unix_timestamp(0),
             OffsetDateTime::unix_epoch(),
         );
         assert_eq!(
@@ -1072,6 +2260,187 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn duration_since_epoch() -> crate::Result<()> {
+        for &duration in &[
+            0.seconds(),
+            1.nanoseconds(),
+            1_609_459_200.25.seconds(),
+            (-1).nanoseconds(),
+            (-1.5).seconds(),
+            (-86_400).seconds(),
+        ] {
+            let datetime = OffsetDateTime::from_duration_since_epoch(duration)?;
+            assert_eq!(datetime.duration_since_epoch(), duration);
+            assert_eq!(datetime.offset(), offset!(UTC));
+        }
+
+        assert_eq!(
+            OffsetDateTime::from_duration_since_epoch((-1.5).seconds()),
+            Ok(date!(1969-12-31)
+                .with_time(time!(23:59:58:500_000_000))
+                .assume_utc())
+        );
+        assert_eq!(
+            date!(2021-01-01)
+                .midnight()
+                .assume_offset(offset!(+1))
+                .duration_since_epoch(),
+            1_609_455_600.seconds()
+        );
+
+        let min = MIN_DATETIME.assume_utc();
+        let max = MAX_DATETIME.assume_utc();
+        assert_eq!(
+            OffsetDateTime::from_duration_since_epoch(min.duration_since_epoch()),
+            Ok(min)
+        );
+        assert_eq!(
+            OffsetDateTime::from_duration_since_epoch(max.duration_since_epoch()),
+            Ok(max)
+        );
+        assert!(OffsetDateTime::from_duration_since_epoch(
+            min.duration_since_epoch() - 1.nanoseconds()
+        )
+        .is_err());
+        assert!(OffsetDateTime::from_duration_since_epoch(
+            max.duration_since_epoch() + 1.seconds()
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn instant_key() -> crate::Result<()> {
+        let utc = date!(2019-06-15)
+            .with_time(time!(8:30:15:250_000_000))
+            .assume_utc();
+        for &offset in &[offset!(UTC), offset!(+1), offset!(-5:30), offset!(+23:59)] {
+            assert_eq!(utc.to_offset(offset).instant_key(), utc.instant_key());
+        }
+        assert_eq!(utc.instant_key(), (1_560_587_415, 250_000_000));
+
+        assert_eq!(OffsetDateTime::unix_epoch().instant_key(), (0, 0));
+        let before_epoch = OffsetDateTime::unix_epoch() - 1.nanoseconds();
+        assert_eq!(before_epoch.instant_key(), (-1, 999_999_999));
+        assert_eq!(
+            before_epoch.to_offset(offset!(+3)).instant_key(),
+            before_epoch.instant_key()
+        );
+
+        assert_ne!(utc.instant_key(), (utc + 1.nanoseconds()).instant_key());
+        Ok(())
+    }
+
+    #[test]
+    fn ntp_timestamp() -> crate::Result<()> {
+        assert_eq!(
+            OffsetDateTime::from_ntp_timestamp(3_755_289_600, 0),
+            date!(2019-01-01).midnight().assume_utc(),
+        );
+        assert_eq!(
+            OffsetDateTime::from_ntp_timestamp(3_755_289_600, 1 << 30),
+            date!(2019-01-01)
+                .with_time(time!(0:00:00:250_000_000))
+                .assume_utc(),
+        );
+        assert_eq!(
+            date!(2019-01-01)
+                .with_time(time!(0:00:00:250_000_000))
+                .assume_offset(offset!(+1))
+                .to_ntp_timestamp(),
+            (3_755_286_000, 1 << 30),
+        );
+        assert_eq!(
+            OffsetDateTime::from_ntp_timestamp(u32::max_value(), u32::max_value()),
+            date!(2036-02-07)
+                .with_time(time!(6:28:15:999_999_999))
+                .assume_utc(),
+        );
+
+        // The era rolls over in 2036.
+        assert_eq!(
+            date!(2036-02-07)
+                .with_time(time!(6:28:17))
+                .assume_utc()
+                .to_ntp_timestamp(),
+            (1, 0),
+        );
+
+        for &(seconds, fraction) in &[
+            (0, 0),
+            (2_208_988_800, 1),
+            (3_755_289_600, 123_456_789),
+            (u32::max_value(), u32::max_value()),
+        ] {
+            let datetime = OffsetDateTime::from_ntp_timestamp(seconds, fraction);
+            assert_eq!(
+                OffsetDateTime::from_ntp_timestamp(
+                    datetime.to_ntp_timestamp().0,
+                    datetime.to_ntp_timestamp().1
+                ),
+                datetime
+            );
+            // A nanosecond spans a little over four units of the fraction.
+            let (round_seconds, round_fraction) = datetime.to_ntp_timestamp();
+            assert_eq!(round_seconds, seconds);
+            assert!(round_fraction <= fraction && fraction - round_fraction <= 4);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn dotnet_ticks() -> crate::Result<()> {
+        assert_eq!(
+            OffsetDateTime::unix_epoch().to_dotnet_ticks(),
+            621_355_968_000_000_000
+        );
+        assert_eq!(
+            OffsetDateTime::from_dotnet_ticks(621_355_968_000_000_000),
+            Ok(OffsetDateTime::unix_epoch())
+        );
+
+        let min = date!(0001-01-01).midnight().assume_utc();
+        let max = date!(9999-12-31)
+            .with_time(time!(23:59:59:999_999_900))
+            .assume_utc();
+        assert_eq!(min.to_dotnet_ticks(), 0);
+        assert_eq!(max.to_dotnet_ticks(), 3_155_378_975_999_999_999);
+        assert_eq!(OffsetDateTime::from_dotnet_ticks(0), Ok(min));
+        assert_eq!(
+            OffsetDateTime::from_dotnet_ticks(3_155_378_975_999_999_999),
+            Ok(max)
+        );
+        assert!(OffsetDateTime::from_dotnet_ticks(-1).is_err());
+        assert!(OffsetDateTime::from_dotnet_ticks(3_155_378_975_999_999_999 + 1).is_err());
+        assert!(OffsetDateTime::from_dotnet_ticks(i64::max_value()).is_err());
+
+        for &ticks in &[1, 637_450_560_000_000_001, 637_450_560_123_456_789] {
+            assert_eq!(
+                OffsetDateTime::from_dotnet_ticks(ticks).map(OffsetDateTime::to_dotnet_ticks),
+                Ok(ticks)
+            );
+        }
+
+        // The offset is irrelevant, and sub-tick precision is truncated.
+        let datetime = date!(2021-01-01).with_time(time!(12:00)).assume_utc();
+        assert_eq!(
+            datetime.to_offset(offset!(-8)).to_dotnet_ticks(),
+            datetime.to_dotnet_ticks()
+        );
+        assert_eq!(
+            (datetime + 99.nanoseconds()).to_dotnet_ticks(),
+            datetime.to_dotnet_ticks()
+        );
+        assert_eq!(
+            (datetime - 1.nanoseconds()).to_dotnet_ticks(),
+            datetime.to_dotnet_ticks() - 1
+        );
+        // Dates before year 1 produce negative ticks.
+        assert_eq!((min - 100.nanoseconds()).to_dotnet_ticks(), -1);
+        Ok(())
+    }
+
     #[test]
     fn offset() -> crate::Result<()> {
         assert_eq!(
@@ -1328,6 +2697,20 @@ mod test {
             date!(2019-01-02).midnight().assume_utc().format("%F %r %z"),
             "2019-01-02 12:00:00 am +0000",
         );
+        assert_eq!(
+            date!(2019-01-02)
+                .with_time(time!(3:04:05))
+                .assume_utc()
+                .format("%+"),
+            "2019-01-02T03:04:05+0000",
+        );
+        assert_eq!(
+            date!(2019-01-02)
+                .with_time(time!(3:04:05))
+                .assume_offset(offset!(-5:30))
+                .format("%+"),
+            "2019-01-02T03:04:05-0530",
+        );
         Ok(())
     }
 
@@ -1357,7 +2740,1300 @@ mod test {
                 .with_time(time!(08:44:31))
                 .assume_offset(offset!(+02:30)))
         );
+        // An offset is required unless leniency is requested.
+        assert_eq!(
+            OffsetDateTime::parse("2019-01-02T03:04:05", Format::Rfc3339),
+            Err(crate::ParseError::UnexpectedEndOfString)
+        );
+        let lenient =
+            |s| OffsetDateTime::parse_with_strictness(s, Format::Rfc3339, ParseStrictness::Lenient);
+        assert_eq!(
+            lenient("2021-01-01T12:00:00,500Z"),
+            OffsetDateTime::parse("2021-01-01T12:00:00.500Z", Format::Rfc3339),
+        );
+        assert_eq!(
+            lenient("2021-01-01T12:00:00,500Z"),
+            Ok(date!(2021-01-01)
+                .with_time(time!(12:00:00:500_000_000))
+                .assume_utc())
+        );
+        assert!(lenient("2021-01-01T12:00:00,Z").is_err());
+        // In a custom format, the separator is written explicitly.
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 12:00:00,500 +0000", "%F %H:%M:%S,%3N %z"),
+            Ok(date!(2021-01-01)
+                .with_time(time!(12:00:00:500_000_000))
+                .assume_utc())
+        );
+        assert!(
+            OffsetDateTime::parse("2021-01-01 12:00:00,500 +0000", "%F %H:%M:%S.%3N %z").is_err()
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2019-01-02T03:04:05+0000", "%+"),
+            Ok(date!(2019-01-02).with_time(time!(3:04:05)).assume_utc())
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2019-01-02T03:04:05-0530", "%+"),
+            Ok(date!(2019-01-02)
+                .with_time(time!(3:04:05))
+                .assume_offset(offset!(-5:30)))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_order_independent() -> crate::Result<()> {
+        let expected = date!(2021-03-04)
+            .with_time(time!(5:06:07:123_456_789))
+            .assume_offset(offset!(+1:30));
+
+        assert_eq!(
+            OffsetDateTime::parse(
+                "2021-03-04 05:06:07.123456789 +0130 Thu",
+                "%Y-%m-%d %H:%M:%S.%N %z %a"
+            ),
+            Ok(expected)
+        );
+        assert_eq!(
+            OffsetDateTime::parse(
+                "+0130 Thu .123456789 07:06:05 04/03/2021",
+                "%z %a .%N %S:%M:%H %d/%m/%Y"
+            ),
+            Ok(expected)
+        );
+        assert_eq!(
+            OffsetDateTime::parse(
+                "Thursday 123456789 +01:30 05:06:07 pm 2021-063",
+                "%A %N %:z %I:%M:%S %p %Y-%j"
+            ),
+            Ok(expected + 12.hours())
+        );
+        assert_eq!(
+            OffsetDateTime::parse(
+                "+01:30 pm 123456789 Thursday 2021-063 05:06:07",
+                "%:z %p %N %A %Y-%j %I:%M:%S"
+            ),
+            Ok(expected + 12.hours())
+        );
+        assert_eq!(
+            OffsetDateTime::parse(
+                "4 +0130 07 2021-W09 05:06 123456789",
+                "%u %z %S %G-W%V %H:%M %N"
+            ),
+            Ok(expected)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_bare_fraction_separator() -> crate::Result<()> {
+        let noon = date!(2021-01-01).with_time(time!(12:00)).assume_utc();
+
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01T12:00:00.Z", Format::Rfc3339),
+            Err(crate::ParseError::InvalidNanosecond)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01T12:00:00.0Z", Format::Rfc3339),
+            Ok(noon)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 12:00:00. +0000", "%F %T.%1N %z"),
+            Err(crate::ParseError::InvalidNanosecond)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 12:00:00.0 +0000", "%F %T.%1N %z"),
+            Ok(noon)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("1609502400.", "%s"),
+            Err(crate::ParseError::InvalidTimestamp)
+        );
+        assert_eq!(OffsetDateTime::parse("1609502400.0", "%s"), Ok(noon));
+        Ok(())
+    }
+
+    #[test]
+    fn midpoint() -> crate::Result<()> {
+        let start = date!(2021-01-01).midnight().assume_utc();
+        let end = date!(2021-01-03).midnight().assume_utc();
+        assert_eq!(
+            start.midpoint(end),
+            date!(2021-01-02).midnight().assume_utc()
+        );
+        assert_eq!(
+            end.midpoint(start),
+            date!(2021-01-02).midnight().assume_utc()
+        );
+        assert_eq!(start.midpoint(start), start);
+
+        // Ties round towards `self`.
+        let later = start + 1.nanoseconds();
+        assert_eq!(start.midpoint(later), start);
+        assert_eq!(later.midpoint(start), later);
+
+        // The offset of `self` is kept.
+        let offset_start = start.to_offset(offset!(-5));
+        assert_eq!(offset_start.midpoint(end).offset(), offset!(-5));
+        assert_eq!(end.midpoint(offset_start).offset(), offset!(UTC));
+
+        // The full range of representable instants.
+        let min = MIN_DATETIME.assume_utc();
+        let max = MAX_DATETIME.assume_utc();
+        let middle = min.midpoint(max);
+        assert_eq!(middle - min, (max - min) / 2);
+        assert_eq!(max.midpoint(min) - middle, 1.nanoseconds());
+        Ok(())
+    }
+
+    #[test]
+    fn sortable_key() -> crate::Result<()> {
+        let mut datetimes = [
+            MAX_DATETIME.assume_utc(),
+            date!(2021-01-01).midnight().assume_offset(offset!(+1)),
+            date!(2020-12-31).with_time(time!(23:30)).assume_utc(),
+            date!(2021-01-01).midnight().assume_utc(),
+            date!(2021-01-01).with_time(time!(0:00:00:1)).assume_utc(),
+            date!(0000-01-01).midnight().assume_utc(),
+            date!(-0001-12-31).with_time(time!(23:59:59)).assume_utc(),
+            date!(-0001-01-01).midnight().assume_utc(),
+            date!(-0010-06-15).midnight().assume_utc(),
+            date!(-9999-01-01).midnight().assume_utc(),
+            date!(-10000-01-01).midnight().assume_utc(),
+            date!(9999-12-31).midnight().assume_utc(),
+            date!(10000-01-01).midnight().assume_utc(),
+            MIN_DATETIME.assume_utc(),
+        ];
+        datetimes.sort_unstable_by_key(|datetime| datetime.to_sortable_key());
+        for pair in datetimes.windows(2) {
+            assert!(pair[0] < pair[1]);
+        }
+
+        for &datetime in &datetimes {
+            let key = datetime.to_sortable_key();
+            assert_eq!(key.len(), 27);
+            assert_eq!(OffsetDateTime::from_sortable_key(&key), Ok(datetime));
+        }
+        assert_eq!(
+            MIN_DATETIME.assume_utc().to_sortable_key(),
+            "-000000101T000000000000000Z"
+        );
+        assert_eq!(
+            date!(2021-01-01).midnight().assume_utc().to_sortable_key(),
+            "0020210101T000000000000000Z"
+        );
+
+        assert!(OffsetDateTime::from_sortable_key("").is_err());
+        assert!(OffsetDateTime::from_sortable_key("0020211301T000000000000000Z").is_err());
+        assert!(OffsetDateTime::from_sortable_key("0020210101T000000000000000").is_err());
+        assert_eq!(
+            OffsetDateTime::from_sortable_key("0020210101T000000000000000Z0"),
+            Err(crate::ParseError::UnexpectedTrailingCharacters)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn set_local_time() -> crate::Result<()> {
+        let datetime = date!(2021-01-01)
+            .with_time(time!(23:00))
+            .assume_offset(offset!(-5));
+        let updated = datetime.set_local_time(time!(1:30));
+
+        assert_eq!(updated.date(), date!(2021-01-01));
+        assert_eq!(updated.time(), time!(1:30));
+        assert_eq!(updated.offset(), offset!(-5));
+        assert_eq!(datetime - updated, 21.5.hours());
+        assert_eq!(
+            updated,
+            date!(2021-01-01).with_time(time!(6:30)).assume_utc()
+        );
+
+        // The UTC date differs from the local one, so setting the time in UTC
+        // changes the instant differently.
+        assert_eq!(
+            datetime.to_offset(offset!(UTC)).set_local_time(time!(1:30)),
+            date!(2021-01-02).with_time(time!(1:30)).assume_utc()
+        );
+        assert_eq!(datetime.set_local_time(datetime.time()), datetime);
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_specifier() -> crate::Result<()> {
+        let datetime = date!(2021-01-01).midnight().assume_utc();
+        assert_eq!(datetime.format("%s"), "1609459200");
+        assert_eq!(datetime.to_offset(offset!(+1)).format("%s"), "1609459200");
+        assert_eq!((datetime + 500.milliseconds()).format("%s"), "1609459200");
+        assert_eq!(
+            (datetime + 500.milliseconds()).format("%s.%N"),
+            "1609459200.500000000"
+        );
+
+        assert_eq!(OffsetDateTime::parse("1609459200", "%s"), Ok(datetime));
+        assert_eq!(
+            OffsetDateTime::parse("1609459200.5", "%s"),
+            Ok(datetime + 500.milliseconds())
+        );
+        assert_eq!(
+            OffsetDateTime::parse("1609459200.000000001", "%s"),
+            Ok(datetime + 1.nanoseconds())
+        );
+        assert_eq!(
+            OffsetDateTime::parse("1609459200.250000000", "%s.%N"),
+            Ok(datetime + 250.milliseconds())
+        );
+        assert_eq!(
+            OffsetDateTime::parse("-1.5", "%s"),
+            Ok(OffsetDateTime::unix_epoch() - 1_500.milliseconds())
+        );
+        assert_eq!(
+            OffsetDateTime::parse("1609459200.", "%s"),
+            Err(crate::ParseError::InvalidTimestamp)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("x", "%s"),
+            Err(crate::ParseError::InvalidTimestamp)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("99999999999999999", "%s"),
+            Err(crate::ParseError::InvalidTimestamp)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_specifier_with_other_specifiers() -> crate::Result<()> {
+        let datetime = date!(2021-01-01).with_time(time!(12:00)).assume_utc();
+        let format = "%Y-%m-%d (epoch %s)";
+        assert_eq!(datetime.format(format), "2021-01-01 (epoch 1609502400)");
+        assert_eq!(
+            datetime
+                .to_offset(offset!(-5))
+                .format("%F %H:%M %z (epoch %s)"),
+            "2021-01-01 07:00 -0500 (epoch 1609502400)"
+        );
+        assert_eq!(datetime.format("%s%s"), "16095024001609502400");
+        assert_eq!(datetime.format("[%s]"), "[1609502400]");
+
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 (epoch 1609502400)", format),
+            Ok(datetime)
+        );
+        // The timestamp is interpreted in the parsed offset.
+        let local = datetime.to_offset(offset!(-5));
+        let format = "%F %H:%M %z (epoch %s)";
+        assert_eq!(
+            OffsetDateTime::parse(local.format(format), format),
+            Ok(local)
+        );
+        assert_eq!(
+            OffsetDateTime::parse(local.format("%s %z"), "%s %z"),
+            Ok(local)
+        );
+        assert_eq!(
+            OffsetDateTime::parse(local.format("%z %s"), "%z %s"),
+            Ok(local)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 12:00 -0500 (epoch 1609502400)", format),
+            Err(crate::ParseError::ConflictingComponent(
+                "24-hour clock hour"
+            ))
+        );
+        assert_eq!(
+            OffsetDateTime::parse("1609502400 1609502401", "%s %s"),
+            Err(crate::ParseError::ConflictingComponent("Unix timestamp"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn chrono_aliases() -> crate::Result<()> {
+        // The expected values are chrono's output for the same moment.
+        let datetime = date!(2001-07-08)
+            .with_time(time!(0:34:59:026_490_708))
+            .assume_offset(offset!(+9:30));
+
+        assert_eq!(datetime.format("%f"), "026490708");
+        assert_eq!(datetime.format("%3f"), "026");
+        assert_eq!(datetime.format("%6f"), "026490");
+        assert_eq!(datetime.format("%9f"), "026490708");
+        assert_eq!(datetime.format("%.3f"), ".026");
+        assert_eq!(datetime.format("%.6f"), ".026490");
+        assert_eq!(datetime.format("%.9f"), ".026490708");
+        assert_eq!(datetime.format("%h"), "Jul");
+        assert_eq!(datetime.format("%k"), " 0");
+        assert_eq!(datetime.format("%l"), "12");
+        assert_eq!(datetime.format("%n%t"), "\n\t");
+        assert_eq!(datetime.format("%v"), " 8-Jul-2001");
+        assert_eq!(datetime.format("%x"), "07/08/01");
+        assert_eq!(datetime.format("%X"), "00:34:59");
+        assert_eq!(
+            datetime.format("%Y-%m-%d %H:%M:%S%.3f %:z"),
+            "2001-07-08 00:34:59.026 +09:30"
+        );
+        assert_eq!(
+            datetime.format("%a, %d %b %Y %X %z"),
+            "Sun, 08 Jul 2001 00:34:59 +0930"
+        );
+
+        assert_eq!(
+            OffsetDateTime::parse(
+                "2001-07-08 00:34:59.026 +09:30",
+                "%Y-%m-%d %H:%M:%S%.3f %:z"
+            ),
+            Ok(datetime - 490_708.nanoseconds())
+        );
+        assert_eq!(
+            Time::parse("01:05:09.5", "%X%.1f"),
+            Ok(time!(1:05:09:500_000_000))
+        );
+        assert!(crate::util::validate_format_string("%.f").is_err());
+        assert!(crate::util::validate_format_string("%.3").is_err());
+        assert!(crate::util::validate_format_string("%.").is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn add_days_with_offset() -> crate::Result<()> {
+        /// US Eastern time in 2021, with daylight saving time from 2021-03-14
+        /// 02:00 to 2021-11-07 02:00 local time.
+        fn eastern(datetime: OffsetDateTime) -> UtcOffset {
+            let timestamp = datetime.timestamp();
+            #[allow(clippy::manual_range_contains)]
+            let is_dst = timestamp >= 1_615_705_200 && timestamp < 1_636_264_800;
+            if is_dst {
+                UtcOffset::hours(-4)
+            } else {
+                UtcOffset::hours(-5)
+            }
+        }
+
+        let noon = date!(2021-03-13)
+            .with_time(time!(12:00))
+            .assume_offset(offset!(-5));
+
+        assert_eq!(noon.add_days_with_offset(0, eastern), Some(noon));
+        // Across the change to daylight saving time.
+        assert_eq!(
+            noon.add_days_with_offset(1, eastern),
+            Some(
+                date!(2021-03-14)
+                    .with_time(time!(12:00))
+                    .assume_offset(offset!(-4))
+            )
+        );
+        assert_eq!(
+            noon.add_days_with_offset(2, eastern)
+                .and_then(|datetime| datetime.add_days_with_offset(-2, eastern)),
+            Some(noon)
+        );
+        // Across the change back to standard time.
+        assert_eq!(
+            date!(2021-11-06)
+                .with_time(time!(12:00))
+                .assume_offset(offset!(-4))
+                .add_days_with_offset(1, eastern),
+            Some(
+                date!(2021-11-07)
+                    .with_time(time!(12:00))
+                    .assume_offset(offset!(-5))
+            )
+        );
+        // Skipped local times are moved forward by the length of the gap.
+        assert_eq!(
+            date!(2021-03-13)
+                .with_time(time!(2:30))
+                .assume_offset(offset!(-5))
+                .add_days_with_offset(1, eastern),
+            Some(
+                date!(2021-03-14)
+                    .with_time(time!(3:30))
+                    .assume_offset(offset!(-4))
+            )
+        );
+        // Repeated local times keep the local time.
+        let repeated = date!(2021-11-06)
+            .with_time(time!(1:30))
+            .assume_offset(offset!(-4))
+            .add_days_with_offset(1, eastern);
+        assert_eq!(repeated.map(OffsetDateTime::time), Some(time!(1:30)));
+        assert_eq!(repeated.map(OffsetDateTime::date), Some(date!(2021-11-07)));
+
+        assert_eq!(noon.add_days_with_offset(i64::max_value(), eastern), None);
+        assert_eq!(noon.add_days_with_offset(i64::min_value(), eastern), None);
+        Ok(())
+    }
 
+    #[test]
+    fn aggregate_time_specifiers() -> crate::Result<()> {
+        let datetime = date!(2019-01-02)
+            .with_time(time!(14:05:09))
+            .assume_offset(offset!(+1));
+
+        assert_eq!(datetime.format("%F %r %z"), "2019-01-02 2:05:09 pm +0100");
+        assert_eq!(datetime.format("%F %T %z"), "2019-01-02 14:05:09 +0100");
+        assert_eq!(datetime.format("%F %R %z"), "2019-01-02 14:05 +0100");
+        assert_eq!(datetime.format("%r"), datetime.format("%-I:%M:%S %p"));
+        assert_eq!(datetime.format("%T"), datetime.format("%-H:%M:%S"));
+        assert_eq!(datetime.format("%R"), datetime.format("%-H:%M"));
+
+        assert_eq!(
+            OffsetDateTime::parse("2019-01-02 2:05:09 pm +0100", "%F %r %z"),
+            Ok(datetime)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2019-01-02 14:05:09 +0100", "%F %T %z"),
+            Ok(datetime)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2019-01-02 14:05 +0100", "%F %R %z"),
+            Ok(datetime - 9.seconds())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn subsecond_precision() -> crate::Result<()> {
+        let datetime = date!(2021-01-01)
+            .with_time(time!(12:00:00:123_456_000))
+            .assume_utc();
+
+        assert_eq!(datetime.format("%T.%6N"), "12:00:00.123456");
+        assert_eq!(datetime.format("%T.%3N"), "12:00:00.123");
+        assert_eq!(datetime.format("%T.%N"), "12:00:00.123456000");
+        assert_eq!(datetime.format("%T.%1N"), "12:00:00.1");
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 12:00:00.123456 +0000", "%F %T.%6N %z"),
+            Ok(datetime)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 12:00:00.123 +0000", "%F %T.%3N %z"),
+            Ok(datetime - 456.microseconds())
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 12:00:00.12 +0000", "%F %T.%3N %z"),
+            Err(crate::ParseError::InvalidNanosecond)
+        );
+
+        // Without an explicit width, any number of digits is accepted.
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 12:00:00.12 +0000", "%F %T.%N %z"),
+            Ok(datetime - 3_456.microseconds())
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 12:00:00.123456000999 +0000", "%F %T.%N %z"),
+            Ok(datetime)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 12:00:00. +0000", "%F %T.%N %z"),
+            Err(crate::ParseError::InvalidNanosecond)
+        );
+
+        // RFC3339 uses the fewest digits that represent the value exactly.
+        assert_eq!(
+            datetime.format(Format::Rfc3339),
+            "2021-01-01T12:00:00.123456+00:00"
+        );
+        assert_eq!(
+            (datetime - 456.microseconds()).format(Format::Rfc3339),
+            "2021-01-01T12:00:00.123+00:00"
+        );
+        assert_eq!(
+            (datetime + 789.nanoseconds()).format(Format::Rfc3339),
+            "2021-01-01T12:00:00.123456789+00:00"
+        );
+        assert_eq!(
+            date!(2021-01-01)
+                .midnight()
+                .assume_utc()
+                .format(Format::Rfc3339),
+            "2021-01-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            OffsetDateTime::parse(datetime.format(Format::Rfc3339), Format::Rfc3339),
+            Ok(datetime)
+        );
+
+        // The precision can be forced.
+        assert_eq!(
+            datetime.format_rfc3339_with_precision(3),
+            "2021-01-01T12:00:00.123+00:00"
+        );
+        assert_eq!(
+            datetime.format_rfc3339_with_precision(6),
+            "2021-01-01T12:00:00.123456+00:00"
+        );
+        assert_eq!(
+            datetime.format_rfc3339_with_precision(9),
+            "2021-01-01T12:00:00.123456000+00:00"
+        );
+        assert_eq!(
+            datetime.format_rfc3339_with_precision(0),
+            "2021-01-01T12:00:00+00:00"
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "digits must be in the range 0..=9 (was 10)")]
+    fn format_rfc3339_with_invalid_precision() {
+        let _ = OffsetDateTime::unix_epoch().format_rfc3339_with_precision(10);
+    }
+
+    #[test]
+    fn sql_timestamptz() -> crate::Result<()> {
+        let datetime = date!(2021-01-01).with_time(time!(12:34:56));
+
+        for &(s, expected) in &[
+            ("2021-01-01 12:34:56+00", datetime.assume_utc()),
+            (
+                "2021-01-01 12:34:56+05",
+                datetime.assume_offset(offset!(+5)),
+            ),
+            (
+                "2021-01-01 12:34:56-08",
+                datetime.assume_offset(offset!(-8)),
+            ),
+            (
+                "2021-01-01 12:34:56+05:30",
+                datetime.assume_offset(offset!(+5:30)),
+            ),
+            (
+                "2021-01-01 12:34:56-00:25:21",
+                datetime.assume_offset(UtcOffset::seconds(-1_521)),
+            ),
+            (
+                "2021-01-01 12:34:56.123456+00",
+                date!(2021-01-01)
+                    .with_time(time!(12:34:56:123_456_000))
+                    .assume_utc(),
+            ),
+            (
+                "2021-01-01 12:34:56.000000001+00",
+                date!(2021-01-01).with_time(time!(12:34:56:1)).assume_utc(),
+            ),
+        ] {
+            assert_eq!(OffsetDateTime::parse_sql_timestamptz(s), Ok(expected));
+            assert_eq!(expected.format_sql_timestamptz(), s);
+        }
+
+        // Redundant offset components are accepted, but not emitted.
+        assert_eq!(
+            OffsetDateTime::parse_sql_timestamptz("2021-01-01 12:34:56+05:00:00")
+                .map(OffsetDateTime::format_sql_timestamptz),
+            Ok("2021-01-01 12:34:56+05".into())
+        );
+
+        assert_eq!(
+            OffsetDateTime::parse_sql_timestamptz("2021-01-01T12:34:56+00"),
+            Err(crate::ParseError::UnexpectedCharacter {
+                expected: ' ',
+                actual: 'T'
+            })
+        );
+        assert_eq!(
+            OffsetDateTime::parse_sql_timestamptz("2021-01-01 12:34:56"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            OffsetDateTime::parse_sql_timestamptz("2021-01-01 12:34:56+5"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            OffsetDateTime::parse_sql_timestamptz("2021-01-01 12:34:56.+00"),
+            Err(crate::ParseError::InvalidNanosecond)
+        );
+
+        // Each offset component must be in range.
+        assert_eq!(
+            OffsetDateTime::parse_sql_timestamptz("2021-01-01 12:34:56+99"),
+            Err(error::ComponentRange {
+                name: "hours",
+                minimum: 0,
+                maximum: 23,
+                value: 99,
+                conditional_range: false,
+            }
+            .into())
+        );
+        assert!(OffsetDateTime::parse_sql_timestamptz("2021-01-01 12:34:56+05:60").is_err());
+        assert!(OffsetDateTime::parse_sql_timestamptz("2021-01-01 12:34:56+05:30:60").is_err());
+        assert!(OffsetDateTime::parse("2021-01-01 12:34:56 +9900", "%F %T %z").is_err());
+        assert!(OffsetDateTime::parse("2021-01-01 12:34:56 +05:60", "%F %T %:z").is_err());
+        assert!(OffsetDateTime::parse("2021-01-01 12:34:56 UTC+24:00", "%F %T %Z").is_err());
+        assert!(OffsetDateTime::parse("2021-01-01T12:34:56+24:00", Format::Rfc3339).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn zone_abbreviation() -> crate::Result<()> {
+        let datetime = date!(2021-01-01).with_time(time!(14:30));
+
+        assert_eq!(
+            datetime.assume_utc().format("%F %T %Z"),
+            "2021-01-01 14:30:00 UTC"
+        );
+        assert_eq!(
+            datetime.assume_offset(offset!(+5:30)).format("%F %T %Z"),
+            "2021-01-01 14:30:00 UTC+05:30"
+        );
+        assert_eq!(
+            datetime.assume_offset(offset!(-8)).format("%T %Z"),
+            "14:30:00 UTC-08:00"
+        );
+
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 14:30:00 UTC", "%F %T %Z"),
+            Ok(datetime.assume_utc())
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 14:30:00 GMT", "%F %T %Z"),
+            Ok(datetime.assume_utc())
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 14:30:00 UTC+05:30", "%F %T %Z"),
+            Ok(datetime.assume_offset(offset!(+5:30)))
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 14:30:00 UTC+0530", "%F %T %Z"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 14:30:00 UTC+05:30:", "%F %T %Z"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+
+        // Offsets with seconds round-trip.
+        for &offset in &[offset!(+5:30:15), offset!(-0:00:01), offset!(-23:59:59)] {
+            let formatted = datetime.assume_offset(offset).format("%F %T %Z");
+            assert_eq!(
+                OffsetDateTime::parse(&formatted, "%F %T %Z"),
+                Ok(datetime.assume_offset(offset))
+            );
+            assert_eq!(
+                OffsetDateTime::parse(&formatted, "%F %T %Z").map(OffsetDateTime::offset),
+                Ok(offset)
+            );
+        }
+        assert_eq!(
+            datetime.assume_offset(offset!(-0:00:01)).format("%Z"),
+            "UTC-00:00:01"
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 14:30:00 XYZ", "%F %T %Z"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn zone_abbreviation_table() -> crate::Result<()> {
+        let datetime = date!(2021-01-01).with_time(time!(14:30));
+
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 14:30:00 EST", "%F %T %Z"),
+            Ok(datetime.assume_offset(offset!(-5)))
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 14:30:00 PDT", "%F %T %Z"),
+            Ok(datetime.assume_offset(offset!(-7)))
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 14:30:00 CST", "%F %T %Z"),
+            Err(crate::ParseError::AmbiguousZoneAbbreviation)
+        );
+        // Abbreviations are matched in full and exactly.
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 14:30:00 est", "%F %T %Z"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 14:30:00 ESTX", "%F %T %Z"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+
+        let zones = ZoneAbbreviations {
+            known: &[("CST", offset!(-6)), ("EST", offset!(+10))],
+            ambiguous: &[],
+        };
+        assert_eq!(
+            OffsetDateTime::parse_with_zones("2021-01-01 14:30:00 CST", "%F %T %Z", zones),
+            Ok(datetime.assume_offset(offset!(-6)))
+        );
+        assert_eq!(
+            OffsetDateTime::parse_with_zones("2021-01-01 14:30:00 EST", "%F %T %Z", zones),
+            Ok(datetime.assume_offset(offset!(+10)))
+        );
+        // `UTC` and `GMT` are recognized regardless of the table.
+        assert_eq!(
+            OffsetDateTime::parse_with_zones("2021-01-01 14:30:00 GMT", "%F %T %Z", zones),
+            Ok(datetime.assume_utc())
+        );
+        assert_eq!(
+            OffsetDateTime::parse_with_zones("2021-01-01 14:30:00 PST", "%F %T %Z", zones),
+            Err(crate::ParseError::InvalidOffset)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn escaped_literal() -> crate::Result<()> {
+        let datetime = date!(2021-01-07).with_time(time!(14:30)).assume_utc();
+
+        assert_eq!(datetime.format("%[Today is ]%A"), "Today is Thursday");
+        assert_eq!(
+            datetime.format("%[100% of ]%A%[ at ]%R%[ (%Z)]"),
+            "100% of Thursday at 14:30 (%Z)"
+        );
+        assert_eq!(datetime.format("%[]%F"), "2021-01-07");
+
+        assert_eq!(
+            OffsetDateTime::parse("100% of 2021-01-07 at 14:30 UTC", "%[100% of ]%F at %R %Z"),
+            Ok(datetime)
+        );
+        assert_eq!(
+            OffsetDateTime::parse("99% of 2021-01-07 at 14:30 UTC", "%[100% of ]%F at %R %Z"),
+            Err(crate::ParseError::UnexpectedCharacter {
+                expected: '1',
+                actual: '9'
+            })
+        );
+
+        assert!(crate::util::validate_format_string("%[unterminated").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_missing_seconds() -> crate::Result<()> {
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01T14:30+00:00", "%Y-%m-%dT%H:%M%:z"),
+            Ok(date!(2021-01-01).with_time(time!(14:30)).assume_utc())
+        );
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01T14:30-05:00", "%Y-%m-%dT%H:%M%:z"),
+            Ok(date!(2021-01-01)
+                .with_time(time!(14:30))
+                .assume_offset(offset!(-5)))
+        );
+        assert_eq!(
+            date!(2021-01-01)
+                .with_time(time!(14:30))
+                .assume_offset(offset!(-5))
+                .format("%Y-%m-%dT%H:%M%:z"),
+            "2021-01-01T14:30-05:00"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_offset_realistic() -> crate::Result<()> {
+        let parse = |s| OffsetDateTime::parse_offset_realistic(s, "%F %T %z");
+        let midnight = date!(2019-01-02).midnight();
+
+        assert_eq!(
+            parse("2019-01-02 00:00:00 +1400"),
+            Ok(midnight.assume_offset(offset!(+14)))
+        );
+        assert_eq!(
+            parse("2019-01-02 00:00:00 -1200"),
+            Ok(midnight.assume_offset(offset!(-12)))
+        );
+        assert_eq!(
+            parse("2019-01-02 00:00:00 +0000"),
+            Ok(midnight.assume_utc())
+        );
+        assert_eq!(
+            parse("2019-01-02 00:00:00 +2000"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            parse("2019-01-02 00:00:00 +1401"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            parse("2019-01-02 00:00:00 -1201"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+
+        // The permissive parser is unchanged.
+        assert_eq!(
+            OffsetDateTime::parse("2019-01-02 00:00:00 +2000", "%F %T %z"),
+            Ok(midnight.assume_offset(offset!(+20)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_with_offset_flag() -> crate::Result<()> {
+        assert_eq!(
+            OffsetDateTime::parse_with_offset_flag("2019-01-02 03:04:05 -0500", "%F %T %z"),
+            Ok((
+                date!(2019-01-02).with_time(time!(3:04:05)),
+                Some(offset!(-5))
+            ))
+        );
+        assert_eq!(
+            OffsetDateTime::parse_with_offset_flag("2019-01-02 03:04:05", "%F %T"),
+            Ok((date!(2019-01-02).with_time(time!(3:04:05)), None))
+        );
+        assert_eq!(
+            OffsetDateTime::parse_with_offset_flag("2019-01-02T03:04:05Z", Format::Rfc3339),
+            Ok((
+                date!(2019-01-02).with_time(time!(3:04:05)),
+                Some(offset!(UTC))
+            ))
+        );
+        // An offset is never assumed for RFC3339 input that lacks one.
+        assert_eq!(
+            OffsetDateTime::parse_with_offset_flag("2019-01-02T03:04:05", Format::Rfc3339),
+            Err(crate::ParseError::UnexpectedEndOfString)
+        );
+        assert_eq!(
+            OffsetDateTime::parse_with_offset_flag("2019-01-02 +0100", "%F %z"),
+            Err(crate::ParseError::InsufficientInformation)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_with_strictness() -> crate::Result<()> {
+        use ParseStrictness::{Lenient, Strict};
+
+        let expected = date!(2021-01-01)
+            .with_time(time!(12:00:00:500_000_000))
+            .assume_utc();
+        let parse = |s: &str, strictness| {
+            OffsetDateTime::parse_with_strictness(s, Format::Rfc3339, strictness)
+        };
+
+        // Conforming input is accepted either way.
+        assert_eq!(parse("2021-01-01T12:00:00.5Z", Strict), Ok(expected));
+        assert_eq!(parse("2021-01-01T12:00:00.5Z", Lenient), Ok(expected));
+        assert_eq!(parse("2021-01-01T12:00:00.5+00:00", Strict), Ok(expected));
+
+        // RFC3339 permits a lowercase `t` and `z`.
+        for &input in &[
+            "2021-01-01t12:00:00.5z",
+            "2021-01-01t12:00:00.5Z",
+            "2021-01-01T12:00:00.5z",
+        ] {
+            assert_eq!(parse(input, Strict), Ok(expected));
+            assert_eq!(OffsetDateTime::parse(input, Format::Rfc3339), Ok(expected));
+        }
+
+        // Each relaxation is only accepted when lenient.
+        for &input in &[
+            "2021-01-01 12:00:00.5Z",
+            "2021-01-01 12:00:00.5z",
+            "2021-01-01T12:00:00.5",
+            "2021-01-01T12:00:00,5Z",
+            "2021-01-01T12:00:00.5+00",
+        ] {
+            assert!(parse(input, Strict).is_err());
+            assert_eq!(parse(input, Lenient), Ok(expected));
+            // Leniency must be requested explicitly.
+            assert!(OffsetDateTime::parse(input, Format::Rfc3339).is_err());
+        }
+
+        // Some input is never accepted.
+        assert!(parse("2021-01-01_12:00:00Z", Lenient).is_err());
+        assert!(parse("2021-01-01T12:00:00+0000", Lenient).is_err());
+
+        // Custom formats are unaffected.
+        assert_eq!(
+            OffsetDateTime::parse_with_strictness("2021-01-01 00:00:00 +0000", "%F %T %z", Strict)
+                .map(OffsetDateTime::date),
+            Ok(date!(2021-01-01))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_trimmed() -> crate::Result<()> {
+        let expected = Ok(date!(2021-01-01).midnight().assume_utc());
+        for &input in &[
+            " 2021-01-01T00:00:00Z",
+            "2021-01-01T00:00:00Z ",
+            " 2021-01-01T00:00:00Z ",
+            "\t\r\n 2021-01-01T00:00:00Z\n",
+        ] {
+            assert_eq!(
+                OffsetDateTime::parse_trimmed(input, Format::Rfc3339),
+                expected
+            );
+        }
+        assert_eq!(
+            OffsetDateTime::parse_trimmed("  2021-01-01 00:00 +0000  ", "%F %R %z"),
+            expected
+        );
+
+        // Without trimming, leading whitespace is rejected.
+        assert_eq!(
+            OffsetDateTime::parse(" 2021-01-01T00:00:00Z", Format::Rfc3339),
+            Err(crate::ParseError::InvalidYear)
+        );
+        assert!(OffsetDateTime::parse(" 2021-01-01 00:00 +0000", "%F %R %z").is_err());
+        // Only ASCII whitespace is trimmed.
+        assert!(
+            OffsetDateTime::parse_trimmed("\u{a0}2021-01-01T00:00:00Z", Format::Rfc3339).is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_time_on() -> crate::Result<()> {
+        assert_eq!(
+            OffsetDateTime::parse_time_on("14:30:05", "%T", date!(2021-01-01), offset!(UTC)),
+            Ok(date!(2021-01-01).with_time(time!(14:30:05)).assume_utc())
+        );
+        assert_eq!(
+            OffsetDateTime::parse_time_on("2:30 pm", "%-I:%M %p", date!(2020-02-29), offset!(-5)),
+            Ok(date!(2020-02-29)
+                .with_time(time!(14:30))
+                .assume_offset(offset!(-5)))
+        );
+        // Only the time is taken from the input.
+        assert_eq!(
+            OffsetDateTime::parse_time_on(
+                "2019-06-01 14:30 +0200",
+                "%F %R %z",
+                date!(2021-01-01),
+                offset!(UTC)
+            ),
+            Ok(date!(2021-01-01).with_time(time!(14:30)).assume_utc())
+        );
+
+        assert!(
+            OffsetDateTime::parse_time_on("25:00", "%R", date!(2021-01-01), offset!(UTC)).is_err()
+        );
+        assert_eq!(
+            OffsetDateTime::parse_time_on("14", "%R", date!(2021-01-01), offset!(UTC)),
+            Err(crate::ParseError::UnexpectedEndOfString)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parse_hours_only_offset() -> crate::Result<()> {
+        use ParseStrictness::{Lenient, Strict};
+
+        let datetime = date!(2021-01-01).with_time(time!(12:00));
+        let parse = |s: &str, strictness| {
+            OffsetDateTime::parse_with_strictness(s, Format::Rfc3339, strictness)
+        };
+
+        assert_eq!(
+            parse("2021-01-01T12:00:00+05", Lenient),
+            Ok(datetime.assume_offset(offset!(+5)))
+        );
+        assert_eq!(
+            parse("2021-01-01T12:00:00-08", Lenient),
+            Ok(datetime.assume_offset(offset!(-8)))
+        );
+        assert!(parse("2021-01-01T12:00:00+05", Strict).is_err());
+
+        // Minutes that are present must still use the RFC3339 form.
+        assert_eq!(
+            parse("2021-01-01T12:00:00+05:30", Lenient),
+            Ok(datetime.assume_offset(offset!(+5:30)))
+        );
+        assert!(parse("2021-01-01T12:00:00+0530", Lenient).is_err());
+        assert!(parse("2021-01-01T12:00:00+05:3", Lenient).is_err());
+
+        assert_eq!(
+            OffsetDateTime::parse("2021-01-01 12:00:00 +05", "%F %T %:::z"),
+            Ok(datetime.assume_offset(offset!(+5)))
+        );
+        assert_eq!(
+            datetime.assume_offset(offset!(+5)).format("%F %T %:::z"),
+            "2021-01-01 12:00:00 +05"
+        );
+        assert_eq!(
+            datetime.assume_offset(offset!(+5:30)).format("%F %T %:::z"),
+            "2021-01-01 12:00:00 +05:30"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sort_by_instant() -> crate::Result<()> {
+        // Ordered by wall clock time, but not by instant.
+        let mut values = [
+            date!(2019-01-01)
+                .with_time(time!(1:00))
+                .assume_offset(offset!(+3)),
+            date!(2019-01-01)
+                .with_time(time!(2:00))
+                .assume_offset(offset!(+5)),
+            date!(2019-01-01).with_time(time!(3:00)).assume_utc(),
+            date!(2019-01-01)
+                .with_time(time!(4:00))
+                .assume_offset(offset!(-1)),
+            date!(2019-01-01)
+                .with_time(time!(5:00))
+                .assume_offset(offset!(+2)),
+        ];
+        OffsetDateTime::sort_by_instant(&mut values);
+
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+        let expected = [
+            time!(21:00),
+            time!(22:00),
+            time!(3:00),
+            time!(3:00),
+            time!(5:00),
+        ];
+        for (value, &expected) in values.iter().zip(&expected) {
+            assert_eq!(value.to_offset(offset!(UTC)).time(), expected);
+        }
+        // Equal instants retain their relative order.
+        assert_eq!(values[2].offset(), offset!(UTC));
+        assert_eq!(values[3].offset(), offset!(+2));
+        Ok(())
+    }
+
+    #[test]
+    fn checked_and_saturating_at_range_edges() {
+        let min = MIN_DATETIME.assume_utc().to_offset(offset!(+1));
+        let max = MAX_DATETIME.assume_utc().to_offset(offset!(-1));
+
+        // Subtracting past the minimum representable instant.
+        assert_eq!(min.checked_sub(1.nanoseconds()), None);
+        assert_eq!(min.checked_sub(Duration::max_value()), None);
+        assert_eq!(min.checked_add(Duration::min_value()), None);
+        assert_eq!(min.saturating_sub(1.nanoseconds()), min);
+        assert_eq!(min.saturating_sub(Duration::max_value()), min);
+        assert_eq!(min.saturating_add(Duration::min_value()), min);
+        assert_eq!(
+            min.saturating_sub(Duration::max_value()).offset(),
+            offset!(+1)
+        );
+        assert_eq!(min.checked_sub(Duration::zero()), Some(min));
+        assert_eq!(
+            min.checked_add(1.nanoseconds()),
+            Some(min + 1.nanoseconds())
+        );
+
+        // Adding past the maximum representable instant.
+        assert_eq!(max.checked_add(1.nanoseconds()), None);
+        assert_eq!(max.checked_add(Duration::max_value()), None);
+        assert_eq!(max.checked_sub(Duration::min_value()), None);
+        assert_eq!(max.saturating_add(1.nanoseconds()), max);
+        assert_eq!(max.saturating_sub(Duration::min_value()), max);
+        assert_eq!(
+            max.checked_sub(1.nanoseconds()),
+            Some(max - 1.nanoseconds())
+        );
+
+        // A large duration that stays in range.
+        let epoch = OffsetDateTime::unix_epoch();
+        let duration = (MAX_DATETIME.assume_utc() - epoch) - 1.days();
+        assert_eq!(
+            epoch.checked_add(duration),
+            Some(MAX_DATETIME.assume_utc() - 1.days())
+        );
+        assert_eq!(epoch.saturating_sub(duration), epoch - duration);
+    }
+
+    #[test]
+    fn saturating_in_offset() -> crate::Result<()> {
+        let datetime = date!(2021-01-01).midnight();
+
+        for &offset in &[
+            offset!(+1),
+            offset!(-1),
+            offset!(+23:59:59),
+            offset!(-23:59:59),
+        ] {
+            let datetime = datetime.assume_offset(offset);
+
+            // Both the instant and the local datetime stay within range, with
+            // one of them at the limit.
+            let max = datetime.saturating_add(Duration::max_value());
+            let local = max.date().with_time(max.time());
+            assert_eq!(max.offset(), offset);
+            assert!(local <= MAX_DATETIME && max.utc_datetime <= MAX_DATETIME);
+            assert!(local == MAX_DATETIME || max.utc_datetime == MAX_DATETIME);
+            assert_eq!(datetime.saturating_sub(Duration::min_value()), max);
+            assert_eq!(max.checked_add(1.nanoseconds()), None);
+            assert_eq!(max.saturating_add(1.nanoseconds()), max);
+
+            let min = datetime.saturating_sub(Duration::max_value());
+            let local = min.date().with_time(min.time());
+            assert_eq!(min.offset(), offset);
+            assert!(local >= MIN_DATETIME && min.utc_datetime >= MIN_DATETIME);
+            assert!(local == MIN_DATETIME || min.utc_datetime == MIN_DATETIME);
+            assert_eq!(datetime.saturating_add(Duration::min_value()), min);
+            assert_eq!(min.checked_sub(1.nanoseconds()), None);
+            assert_eq!(min.saturating_sub(1.nanoseconds()), min);
+        }
+
+        // When the offset moves the local datetime towards the limit, the
+        // local datetime is at the limit.
+        let max = datetime
+            .assume_offset(offset!(+1))
+            .saturating_add(Duration::max_value());
+        assert_eq!(max.date(), MAX_DATETIME.date());
+        assert_eq!(max.time(), MAX_DATETIME.time());
+        assert_eq!(max.utc_datetime, MAX_DATETIME - 1.hours());
+        let min = datetime
+            .assume_offset(offset!(-1))
+            .saturating_sub(Duration::max_value());
+        assert_eq!(min.date(), MIN_DATETIME.date());
+        assert_eq!(min.time(), MIN_DATETIME.time());
+        assert_eq!(min.utc_datetime, MIN_DATETIME + 1.hours());
+
+        // When the offset moves the local datetime away from the boundary,
+        // the instant itself is the limit.
+        let max = datetime
+            .assume_offset(offset!(-1))
+            .saturating_add(Duration::max_value());
+        assert_eq!(max.utc_datetime, MAX_DATETIME);
+        assert_eq!(max.date(), MAX_DATETIME.date());
+        assert_eq!(max.time(), MAX_DATETIME.time() - 1.hours());
+        let min = datetime
+            .assume_offset(offset!(+1))
+            .saturating_sub(Duration::max_value());
+        assert_eq!(min.utc_datetime, MIN_DATETIME);
+        assert_eq!(min.date(), MIN_DATETIME.date());
+        assert_eq!(min.time(), MIN_DATETIME.time() + 1.hours());
+        Ok(())
+    }
+
+    #[test]
+    fn checked_add_in_offset() -> crate::Result<()> {
+        let new_york = date!(2021-03-01)
+            .with_time(time!(22:30))
+            .assume_offset(offset!(-5));
+        let london = new_york.checked_add_in_offset(3.hours(), offset!(UTC));
+        assert_eq!(
+            london,
+            Some(date!(2021-03-02).with_time(time!(6:30)).assume_utc())
+        );
+        assert_eq!(london.map(OffsetDateTime::offset), Some(offset!(UTC)));
+        assert_eq!(
+            new_york.checked_add_in_offset(-3.hours(), offset!(+1)),
+            Some(
+                date!(2021-03-02)
+                    .with_time(time!(1:30))
+                    .assume_offset(offset!(+1))
+            )
+        );
+
+        // The instant itself overflows.
+        let max = MAX_DATETIME.assume_utc();
+        assert_eq!(
+            max.checked_add_in_offset(1.nanoseconds(), offset!(UTC)),
+            None
+        );
+        assert_eq!(
+            new_york.checked_add_in_offset(Duration::max_value(), offset!(UTC)),
+            None
+        );
+
+        // The instant is representable, but not in the requested offset.
+        assert_eq!(
+            max.checked_add_in_offset(Duration::zero(), offset!(+1)),
+            None
+        );
+        assert_eq!(
+            max.checked_add_in_offset(-1.hours(), offset!(+1)),
+            Some(MAX_DATETIME.assume_offset(offset!(+1)))
+        );
+        let min = MIN_DATETIME.assume_utc();
+        assert_eq!(
+            min.checked_add_in_offset(Duration::zero(), offset!(-1)),
+            None
+        );
+        assert_eq!(
+            min.checked_add_in_offset(1.hours(), offset!(-1)),
+            Some(MIN_DATETIME.assume_offset(offset!(-1)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn checked_add_sub_std() -> crate::Result<()> {
+        let datetime = date!(2021-01-01)
+            .with_time(time!(12:00))
+            .assume_offset(offset!(+2));
+        assert_eq!(
+            datetime.checked_add_std(90.std_minutes()),
+            Some(
+                date!(2021-01-01)
+                    .with_time(time!(13:30))
+                    .assume_offset(offset!(+2))
+            )
+        );
+        assert_eq!(
+            datetime.checked_sub_std(1.5.std_seconds()),
+            Some(
+                date!(2021-01-01)
+                    .with_time(time!(11:59:58:500_000_000))
+                    .assume_offset(offset!(+2))
+            )
+        );
+
+        // Too large to be represented as a `Duration`.
+        let huge = StdDuration::from_secs(u64::max_value());
+        assert_eq!(datetime.checked_add_std(huge), None);
+        assert_eq!(datetime.checked_sub_std(huge), None);
+        // Representable as a `Duration`, but not as a date.
+        let large = StdDuration::from_secs(i64::max_value() as u64);
+        assert_eq!(datetime.checked_add_std(large), None);
+        assert_eq!(datetime.checked_sub_std(large), None);
+
+        let max = MAX_DATETIME.assume_utc();
+        assert_eq!(max.checked_add_std(1.std_nanoseconds()), None);
+        assert_eq!(
+            max.checked_sub_std(1.std_nanoseconds()),
+            Some(max - 1.nanoseconds())
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "resulting value is out of range")]
+    fn sub_underflow_panics() {
+        let _ = MIN_DATETIME.assume_utc() - Duration::max_value();
+    }
+
+    #[test]
+    fn add_months_with_policy() -> crate::Result<()> {
+        let start = date!(2019-02-28)
+            .with_time(time!(8:30))
+            .assume_offset(offset!(-5));
+
+        let clamped = start.add_months_with_policy(1, MonthAddPolicy::ClampDay);
+        let preserved = start.add_months_with_policy(1, MonthAddPolicy::PreserveEndOfMonth);
+        assert_eq!(clamped.map(OffsetDateTime::date), Some(date!(2019-03-28)));
+        assert_eq!(preserved.map(OffsetDateTime::date), Some(date!(2019-03-31)));
+        assert_eq!(clamped.map(OffsetDateTime::time), Some(time!(8:30)));
+        assert_eq!(clamped.map(OffsetDateTime::offset), Some(offset!(-5)));
+
+        // In a leap year, February 28 is not the end of the month.
+        let leap = date!(2020-02-28).midnight().assume_utc();
+        assert_eq!(
+            leap.add_months_with_policy(1, MonthAddPolicy::PreserveEndOfMonth)
+                .map(OffsetDateTime::date),
+            Some(date!(2020-03-28))
+        );
+
+        // The date is taken in the stored offset, not in UTC.
+        let local_end = date!(2019-02-28)
+            .with_time(time!(23:00))
+            .assume_offset(offset!(-5));
+        assert_eq!(
+            local_end
+                .add_months_with_policy(1, MonthAddPolicy::PreserveEndOfMonth)
+                .map(OffsetDateTime::date),
+            Some(date!(2019-03-31))
+        );
         Ok(())
     }
 
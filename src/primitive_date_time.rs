@@ -1,6 +1,9 @@
 use crate::{
+    date::{MAX_YEAR, MIN_YEAR},
     format::parse::{parse, ParsedItems},
-    internals, Date, DeferredFormat, Duration, OffsetDateTime, ParseResult, Time, UtcOffset,
+    internals,
+    time_mod::NANOS_PER_DAY,
+    Date, DeferredFormat, Duration, MonthAddPolicy, OffsetDateTime, ParseResult, Time, UtcOffset,
     Weekday,
 };
 #[cfg(not(feature = "std"))]
@@ -37,6 +40,18 @@ pub struct PrimitiveDateTime {
     pub(crate) time: Time,
 }
 
+/// The earliest representable `PrimitiveDateTime`.
+pub(crate) const MIN_DATETIME: PrimitiveDateTime = PrimitiveDateTime {
+    date: internals::Date::from_yo_unchecked(MIN_YEAR, 1),
+    time: Time::midnight(),
+};
+
+/// The latest representable `PrimitiveDateTime`. `MAX_YEAR` is a leap year.
+pub(crate) const MAX_DATETIME: PrimitiveDateTime = PrimitiveDateTime {
+    date: internals::Date::from_yo_unchecked(MAX_YEAR, 366),
+    time: internals::Time::from_hms_nanos_unchecked(23, 59, 59, 999_999_999),
+};
+
 #[cfg(feature = "serde")]
 impl<'a> serde::Deserialize<'a> for PrimitiveDateTime {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -321,7 +336,10 @@ impl PrimitiveDateTime {
     /// assert_eq!(date!(2019-11-01).midnight().weekday(), Friday);
     /// assert_eq!(date!(2019-12-01).midnight().weekday(), Sunday);
     /// ```
-    pub fn weekday(self) -> Weekday {
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn weekday(self) -> Weekday {
         self.date().weekday()
     }
 
@@ -467,6 +485,121 @@ impl PrimitiveDateTime {
     pub const fn assume_utc(self) -> OffsetDateTime {
         OffsetDateTime::new_assuming_utc(self)
     }
+
+    /// Add the given number of months to the date, keeping the time. See
+    /// [`Date::add_months_with_policy`] for details.
+    ///
+    /// ```rust
+    /// # use time::{date, time, MonthAddPolicy};
+    /// assert_eq!(
+    ///     date!(2019-02-28)
+    ///         .with_time(time!(12:00))
+    ///         .add_months_with_policy(1, MonthAddPolicy::PreserveEndOfMonth),
+    ///     Some(date!(2019-03-31).with_time(time!(12:00)))
+    /// );
+    /// ```
+    pub fn add_months_with_policy(self, months: i32, policy: MonthAddPolicy) -> Option<Self> {
+        Some(Self::new(
+            self.date.add_months_with_policy(months, policy)?,
+            self.time,
+        ))
+    }
+}
+
+/// Methods for arithmetic that may overflow.
+impl PrimitiveDateTime {
+    /// Add the given number of nanoseconds, returning `None` if the result is
+    /// out of range.
+    fn checked_add_nanoseconds(self, nanoseconds: i128) -> Option<Self> {
+        let nanoseconds = self.time.nanoseconds_since_midnight() as i128 + nanoseconds;
+        let days = nanoseconds.div_euclid(NANOS_PER_DAY as i128);
+        let time = nanoseconds.rem_euclid(NANOS_PER_DAY as i128);
+
+        // `days` is bounded by the range of `Duration`, so it fits in an `i64`.
+        let date = Date::try_from_julian_day(self.date.julian_day() + days as i64).ok()?;
+        Some(Self::new(
+            date,
+            Time::from_nanoseconds_since_midnight(time as u64),
+        ))
+    }
+
+    /// Computes `self + duration`, returning `None` if the result is out of
+    /// range.
+    ///
+    /// ```rust
+    /// # use time::{date, time, Duration, prelude::*};
+    /// assert_eq!(
+    ///     date!(2019-01-31).with_time(time!(23:00)).checked_add(2.hours()),
+    ///     Some(date!(2019-02-01).with_time(time!(1:00)))
+    /// );
+    /// assert_eq!(date!(2019-01-01).midnight().checked_add(Duration::max_value()), None);
+    /// ```
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        self.checked_add_nanoseconds(duration.whole_nanoseconds())
+    }
+
+    /// Computes `self - duration`, returning `None` if the result is out of
+    /// range.
+    ///
+    /// ```rust
+    /// # use time::{date, time, Duration, prelude::*};
+    /// assert_eq!(
+    ///     date!(2019-01-01).with_time(time!(1:00)).checked_sub(2.hours()),
+    ///     Some(date!(2018-12-31).with_time(time!(23:00)))
+    /// );
+    /// assert_eq!(date!(2019-01-01).midnight().checked_sub(Duration::max_value()), None);
+    /// ```
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        self.checked_add_nanoseconds(-duration.whole_nanoseconds())
+    }
+
+    /// Computes `self + duration`, saturating at the earliest or latest
+    /// representable datetime.
+    ///
+    /// ```rust
+    /// # use time::{date, time, Duration, prelude::*};
+    /// assert_eq!(
+    ///     date!(2019-01-31).with_time(time!(23:00)).saturating_add(2.hours()),
+    ///     date!(2019-02-01).with_time(time!(1:00))
+    /// );
+    /// assert_eq!(
+    ///     date!(2019-01-01).midnight().saturating_add(Duration::max_value()),
+    ///     date!(+100_000-12-31).with_time(time!(23:59:59.999_999_999)),
+    /// );
+    /// ```
+    pub fn saturating_add(self, duration: Duration) -> Self {
+        self.checked_add(duration).unwrap_or_else(|| {
+            if duration.is_negative() {
+                MIN_DATETIME
+            } else {
+                MAX_DATETIME
+            }
+        })
+    }
+
+    /// Computes `self - duration`, saturating at the earliest or latest
+    /// representable datetime.
+    ///
+    /// ```rust
+    /// # use time::{date, time, Duration, prelude::*};
+    /// assert_eq!(
+    ///     date!(2019-01-01).with_time(time!(1:00)).saturating_sub(2.hours()),
+    ///     date!(2018-12-31).with_time(time!(23:00))
+    /// );
+    /// assert_eq!(
+    ///     date!(2019-01-01).midnight().saturating_sub(Duration::max_value()),
+    ///     date!(-100_000-01-01).midnight(),
+    /// );
+    /// ```
+    pub fn saturating_sub(self, duration: Duration) -> Self {
+        self.checked_sub(duration).unwrap_or_else(|| {
+            if duration.is_negative() {
+                MAX_DATETIME
+            } else {
+                MIN_DATETIME
+            }
+        })
+    }
 }
 
 /// Methods that allow formatting the `PrimitiveDateTime`.
@@ -540,18 +673,8 @@ impl Add<Duration> for PrimitiveDateTime {
     type Output = Self;
 
     fn add(self, duration: Duration) -> Self::Output {
-        let nanos = self.time.nanoseconds_since_midnight() as i64
-            + (duration.whole_nanoseconds() % 86_400_000_000_000) as i64;
-
-        let date_modifier = if nanos < 0 {
-            Duration::days(-1)
-        } else if nanos >= 86_400_000_000_000 {
-            Duration::day()
-        } else {
-            Duration::zero()
-        };
-
-        Self::new(self.date + duration + date_modifier, self.time + duration)
+        self.checked_add(duration)
+            .expect("resulting value is out of range")
     }
 }
 
@@ -588,7 +711,8 @@ impl Sub<Duration> for PrimitiveDateTime {
     type Output = Self;
 
     fn sub(self, duration: Duration) -> Self::Output {
-        self + -duration
+        self.checked_sub(duration)
+            .expect("resulting value is out of range")
     }
 }
 
@@ -1003,6 +1127,78 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn parse_missing_seconds() -> crate::Result<()> {
+        assert_eq!(
+            PrimitiveDateTime::parse("2021-01-01T14:30", "%Y-%m-%dT%H:%M"),
+            Ok(date!(2021-01-01).with_time(time!(14:30)))
+        );
+        assert_eq!(
+            PrimitiveDateTime::parse("2021-01-01 14", "%F %H"),
+            Ok(date!(2021-01-01).with_time(time!(14:00)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn checked_and_saturating() -> crate::Result<()> {
+        // Crossing day, month, and year boundaries.
+        assert_eq!(
+            date!(2019-01-31)
+                .with_time(time!(23:59:59))
+                .checked_add(1.seconds()),
+            Some(date!(2019-02-01).midnight())
+        );
+        assert_eq!(
+            date!(2020-02-28)
+                .with_time(time!(12:00))
+                .checked_add(1.days()),
+            Some(date!(2020-02-29).with_time(time!(12:00)))
+        );
+        assert_eq!(
+            date!(2019-12-31)
+                .with_time(time!(18:00))
+                .checked_add(12.hours()),
+            Some(date!(2020-01-01).with_time(time!(6:00)))
+        );
+        assert_eq!(
+            date!(2019-03-01).midnight().checked_sub(1.nanoseconds()),
+            Some(date!(2019-02-28).with_time(time!(23:59:59:999_999_999)))
+        );
+        assert_eq!(
+            date!(2019-03-01).midnight().checked_add((-1).days()),
+            Some(date!(2019-02-28).midnight())
+        );
+
+        // Overflowing the maximum.
+        assert_eq!(MAX_DATETIME.checked_add(1.nanoseconds()), None);
+        assert_eq!(MAX_DATETIME.checked_sub((-1).nanoseconds()), None);
+        assert_eq!(MAX_DATETIME.saturating_add(1.nanoseconds()), MAX_DATETIME);
+        assert_eq!(
+            MAX_DATETIME.saturating_sub(Duration::min_value()),
+            MAX_DATETIME
+        );
+        assert_eq!(
+            MAX_DATETIME.checked_sub(1.nanoseconds()),
+            Some(MAX_DATETIME - 1.nanoseconds())
+        );
+
+        // Underflowing the minimum.
+        assert_eq!(MIN_DATETIME.checked_sub(1.nanoseconds()), None);
+        assert_eq!(MIN_DATETIME.saturating_sub(1.nanoseconds()), MIN_DATETIME);
+        assert_eq!(
+            MIN_DATETIME.saturating_add(Duration::min_value()),
+            MIN_DATETIME
+        );
+        assert_eq!(
+            date!(2019-01-01)
+                .midnight()
+                .saturating_sub(Duration::max_value()),
+            MIN_DATETIME
+        );
+        Ok(())
+    }
+
     #[test]
     fn add_duration() -> crate::Result<()> {
         assert_eq!(
@@ -0,0 +1,126 @@
+//! Accept either a [Unix timestamp] or an [RFC3339] string when deserializing
+//! an [`OffsetDateTime`], and always serialize it as an RFC3339 string.
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! When deserializing a Unix timestamp, the offset is assumed to be UTC. When
+//! deserializing an RFC3339 string, the offset in the string is preserved.
+//!
+//! ```rust,ignore
+//! use serde_json::json;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct S {
+//!     #[serde(with = "time::serde::flexible")]
+//!     datetime: OffsetDateTime,
+//! }
+//!
+//! let s = S {
+//!     datetime: date!(2021-01-01).midnight().assume_utc(),
+//! };
+//! let v = json!({ "datetime": "2021-01-01T00:00:00+00:00" });
+//! assert_eq!(v, serde_json::to_value(&s)?);
+//! assert_eq!(s, serde_json::from_value(v)?);
+//! assert_eq!(s, serde_json::from_value(json!({ "datetime": 1_609_459_200 }))?);
+//! ```
+//!
+//! [Unix timestamp]: https://en.wikipedia.org/wiki/Unix_time
+//! [RFC3339]: https://tools.ietf.org/html/rfc3339#section-5.6
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use crate::{Duration, Format, OffsetDateTime};
+use core::fmt;
+use serde::{
+    de::{self, Visitor},
+    Deserializer, Serializer,
+};
+#[allow(unused_imports)]
+use standback::prelude::*;
+
+/// Visitor accepting an integer Unix timestamp or an RFC3339 string.
+struct FlexibleVisitor;
+
+impl Visitor<'_> for FlexibleVisitor {
+    type Value = OffsetDateTime;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a Unix timestamp or an RFC3339 formatted string")
+    }
+
+    fn visit_i64<E: de::Error>(self, timestamp: i64) -> Result<OffsetDateTime, E> {
+        OffsetDateTime::from_duration_since_epoch(Duration::seconds(timestamp))
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(timestamp), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, timestamp: u64) -> Result<OffsetDateTime, E> {
+        if timestamp > i64::max_value() as u64 {
+            return Err(E::invalid_value(de::Unexpected::Unsigned(timestamp), &self));
+        }
+        self.visit_i64(timestamp as i64)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<OffsetDateTime, E> {
+        OffsetDateTime::parse(s, Format::Rfc3339).map_err(E::custom)
+    }
+}
+
+pub fn serialize<S: Serializer>(
+    datetime: &OffsetDateTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&datetime.format(Format::Rfc3339))
+}
+
+#[allow(single_use_lifetimes)]
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<OffsetDateTime, D::Error> {
+    deserializer.deserialize_any(FlexibleVisitor)
+}
+
+#[cfg(test)]
+#[rustfmt::skip::macros(date)]
+mod test {
+    use super::*;
+    use serde::de::value::{Error, I64Deserializer, StrDeserializer, U64Deserializer};
+
+    #[test]
+    fn deserialize_integer_and_string() -> crate::Result<()> {
+        let expected = date!(2021-01-01).midnight().assume_utc();
+
+        let from_int: Result<_, Error> = deserialize(I64Deserializer::new(1_609_459_200));
+        let from_uint: Result<_, Error> = deserialize(U64Deserializer::new(1_609_459_200));
+        let from_str: Result<_, Error> = deserialize(StrDeserializer::new("2021-01-01T00:00:00Z"));
+
+        assert_eq!(from_int, Ok(expected));
+        assert_eq!(from_uint, Ok(expected));
+        assert_eq!(from_str, Ok(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_preserves_offset() -> crate::Result<()> {
+        let datetime: Result<_, Error> =
+            deserialize(StrDeserializer::new("2021-01-01T05:30:00+05:30"));
+        assert_eq!(
+            datetime.clone().map(OffsetDateTime::offset),
+            Ok(offset!(+5:30))
+        );
+        assert_eq!(datetime, Ok(date!(2021-01-01).midnight().assume_utc()));
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        let datetime: Result<_, Error> = deserialize(StrDeserializer::new("2021-01-01"));
+        assert!(datetime.is_err());
+        let datetime: Result<_, Error> = deserialize(U64Deserializer::new(u64::max_value()));
+        assert!(datetime.is_err());
+        let datetime: Result<_, Error> = deserialize(I64Deserializer::new(i64::max_value()));
+        assert!(datetime.is_err());
+        let datetime: Result<_, Error> = deserialize(I64Deserializer::new(i64::min_value()));
+        assert!(datetime.is_err());
+        let datetime: Result<_, Error> = deserialize(U64Deserializer::new(1 << 62));
+        assert!(datetime.is_err());
+        let datetime: Result<_, Error> = deserialize(I64Deserializer::new(0));
+        assert_eq!(datetime, Ok(OffsetDateTime::unix_epoch()));
+    }
+}
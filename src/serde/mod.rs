@@ -14,6 +14,7 @@
 
 mod date;
 mod duration;
+pub mod flexible;
 mod primitive_date_time;
 mod sign;
 mod time;
@@ -0,0 +1,105 @@
+//! Utilities for testing code that depends on the current time.
+//!
+//! Setting a fixed time with [`set_now`] causes
+//! [`OffsetDateTime::now_utc`](crate::OffsetDateTime::now_utc) to return it
+//! instead of reading the system clock. Everything built on `now_utc`, such as
+//! [`OffsetDateTime::now_local`](crate::OffsetDateTime::now_local), observes
+//! the same value. The local offset itself is still obtained from the system.
+//!
+//! The fixed time is stored per thread. As the test harness runs each test on
+//! its own thread, tests setting different times do not interfere with one
+//! another.
+//!
+//! Only the thread that called [`set_now`] sees the fixed time. Threads
+//! spawned by the test, including the worker threads of an async runtime,
+//! still read the system clock. A future that is polled on another thread
+//! sees the real time as well.
+
+use crate::{OffsetDateTime, UtcOffset};
+use std::cell::Cell;
+
+#[cfg(__time_02_const_thread_local)]
+thread_local! {
+    /// The fixed time for the current thread, if one has been set.
+    static NOW: Cell<Option<OffsetDateTime>> = const { Cell::new(None) };
+}
+
+#[cfg(not(__time_02_const_thread_local))]
+thread_local! {
+    /// The fixed time for the current thread, if one has been set.
+    static NOW: Cell<Option<OffsetDateTime>> = Cell::new(None);
+}
+
+/// Fix the current time for this thread to the provided value, replacing any
+/// previously set. The value is returned in UTC, regardless of its offset.
+///
+/// ```rust
+/// # use time::{date, testing, OffsetDateTime};
+/// let now = date!(2021-01-01).midnight().assume_utc();
+/// testing::set_now(now);
+/// assert_eq!(OffsetDateTime::now_utc(), now);
+/// ```
+pub fn set_now(now: OffsetDateTime) {
+    NOW.with(|cell| cell.set(Some(now.to_offset(UtcOffset::UTC))));
+}
+
+/// Return to reading the system clock for this thread.
+///
+/// ```rust
+/// # use time::{date, testing, OffsetDateTime};
+/// testing::set_now(date!(2000-01-01).midnight().assume_utc());
+/// testing::clear_now();
+/// assert!(OffsetDateTime::now_utc().year() > 2000);
+/// ```
+pub fn clear_now() {
+    NOW.with(|cell| cell.set(None));
+}
+
+/// Obtain the fixed time for this thread, if one has been set.
+pub(crate) fn now_utc() -> Option<OffsetDateTime> {
+    NOW.with(Cell::get)
+}
+
+#[cfg(test)]
+#[rustfmt::skip::macros(date)]
+mod test {
+    use super::*;
+    use crate::ext::NumericalDuration;
+
+    #[test]
+    fn set_now() -> crate::Result<()> {
+        let now = date!(2021-01-01).with_time(time!(12:00)).assume_utc();
+        super::set_now(now);
+        assert_eq!(OffsetDateTime::now_utc(), now);
+        assert_eq!(OffsetDateTime::now_utc(), now);
+
+        super::set_now(now + 1.days());
+        assert_eq!(OffsetDateTime::now_utc(), now + 1.days());
+
+        // The offset is not retained.
+        super::set_now(now.to_offset(offset!(+5)));
+        assert_eq!(OffsetDateTime::now_utc().offset(), offset!(UTC));
+        assert_eq!(OffsetDateTime::now_utc(), now);
+        assert_eq!(OffsetDateTime::now_local(), now);
+        #[allow(deprecated)]
+        {
+            assert_eq!(OffsetDateTime::now(), now);
+        }
+
+        clear_now();
+        assert!(OffsetDateTime::now_utc() > now + 1.days());
+        Ok(())
+    }
+
+    #[test]
+    fn set_now_is_per_thread() -> crate::Result<()> {
+        let now = date!(2000-01-01).midnight().assume_utc();
+        super::set_now(now);
+        let other = std::thread::spawn(OffsetDateTime::now_utc)
+            .join()
+            .expect("the spawned thread panicked");
+        assert!(other > now);
+        assert_eq!(OffsetDateTime::now_utc(), now);
+        Ok(())
+    }
+}
@@ -2,8 +2,12 @@
 use crate::PrimitiveDateTime;
 use crate::{
     error,
-    format::{parse, parse::AmPm, ParsedItems},
-    DeferredFormat, Duration, ParseResult,
+    format::{
+        parse,
+        parse::{parse_with_am_pm, AmPm},
+        ParsedItems,
+    },
+    AmPmMarkers, DeferredFormat, Duration, ParseResult,
 };
 #[cfg(not(feature = "std"))]
 use alloc::{
@@ -149,6 +153,52 @@ impl Time {
         })
     }
 
+    /// Attempt to create a `Time` from the hour, minute, and second, accepting
+    /// a leap second.
+    ///
+    /// A second of `60` is accepted and normalized to the last representable
+    /// instant of the preceding second, `59.999_999_999`. This keeps the
+    /// result within the same minute and ordered after every other time in
+    /// it. All other components are validated as in [`Time::try_from_hms`].
+    ///
+    /// ```rust
+    /// # use time::{time, Time};
+    /// assert_eq!(
+    ///     Time::try_from_hms_leap(23, 59, 60),
+    ///     Ok(time!(23:59:59.999_999_999))
+    /// );
+    /// assert_eq!(Time::try_from_hms_leap(23, 59, 59), Ok(time!(23:59:59)));
+    /// assert!(Time::try_from_hms_leap(23, 59, 61).is_err());
+    /// assert!(Time::try_from_hms(23, 59, 60).is_err());
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn try_from_hms_leap(
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(hour in 0 => 23);
+        ensure_value_in_range!(minute in 0 => 59);
+        ensure_value_in_range!(second in 0 => 60);
+        if second == 60 {
+            Ok(Self {
+                hour,
+                minute,
+                second: 59,
+                nanosecond: 999_999_999,
+            })
+        } else {
+            Ok(Self {
+                hour,
+                minute,
+                second,
+                nanosecond: 0,
+            })
+        }
+    }
+
     /// Create a `Time` from the hour, minute, second, and millisecond.
     ///
     /// ```rust
@@ -433,6 +483,38 @@ impl Time {
         })
     }
 
+    /// Attempt to create a `Time` from the number of nanoseconds since
+    /// midnight, as returned by [`Time::to_packed`].
+    ///
+    /// ```rust
+    /// # use time::{time, Time};
+    /// assert_eq!(Time::from_packed(0), Ok(time!(0:00)));
+    /// assert_eq!(
+    ///     Time::from_packed(86_399_999_999_999),
+    ///     Ok(time!(23:59:59.999_999_999))
+    /// );
+    /// assert!(Time::from_packed(86_400_000_000_000).is_err()); // That's the next day.
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn from_packed(packed: u64) -> Result<Self, error::ComponentRange> {
+        if packed >= NANOS_PER_DAY {
+            return Err(error::ComponentRange {
+                name: "packed",
+                minimum: 0,
+                maximum: NANOS_PER_DAY as i64 - 1,
+                value: if packed > i64::max_value() as u64 {
+                    i64::max_value()
+                } else {
+                    packed as i64
+                },
+                conditional_range: false,
+            });
+        }
+        Ok(Self::from_nanoseconds_since_midnight(packed))
+    }
+
     /// Create a `Time` representing the current time (UTC).
     ///
     /// ```rust,no_run
@@ -529,6 +611,75 @@ impl Time {
         self.nanosecond
     }
 
+    /// Get the clock hour, minute, and second.
+    ///
+    /// ```rust
+    /// # use time::time;
+    /// assert_eq!(time!(0:00:00).as_hms(), (0, 0, 0));
+    /// assert_eq!(time!(23:59:59.999).as_hms(), (23, 59, 59));
+    /// ```
+    pub const fn as_hms(self) -> (u8, u8, u8) {
+        (self.hour, self.minute, self.second)
+    }
+
+    /// Get the clock hour, minute, second, and nanosecond.
+    ///
+    /// ```rust
+    /// # use time::time;
+    /// assert_eq!(time!(0:00:00).as_hms_nano(), (0, 0, 0, 0));
+    /// assert_eq!(
+    ///     time!(23:59:59.999_999_999).as_hms_nano(),
+    ///     (23, 59, 59, 999_999_999)
+    /// );
+    /// ```
+    pub const fn as_hms_nano(self) -> (u8, u8, u8, u32) {
+        (self.hour, self.minute, self.second, self.nanosecond)
+    }
+
+    /// Get the `Time` as a single integer, being the number of nanoseconds
+    /// since midnight. The value is always less than 86,400,000,000,000, and
+    /// ordering of the packed values matches the ordering of the times.
+    ///
+    /// This is the inverse of [`Time::from_packed`], and is intended for
+    /// compact storage, such as in a columnar format.
+    ///
+    /// ```rust
+    /// # use time::time;
+    /// assert_eq!(time!(0:00).to_packed(), 0);
+    /// assert_eq!(time!(0:00:01.5).to_packed(), 1_500_000_000);
+    /// assert_eq!(
+    ///     time!(23:59:59.999_999_999).to_packed(),
+    ///     86_399_999_999_999
+    /// );
+    /// ```
+    pub const fn to_packed(self) -> u64 {
+        self.nanoseconds_since_midnight()
+    }
+
+    /// Add the `Duration` to the `Time`, returning the resulting time and the
+    /// number of whole days carried. The carry is negative when the result
+    /// falls before midnight of the original day.
+    ///
+    /// Unlike the `+` operator, which discards the carry, this lets callers
+    /// advance an accompanying date.
+    ///
+    /// ```rust
+    /// # use time::{prelude::*, time};
+    /// assert_eq!(time!(12:00).overflowing_add(2.hours()), (time!(14:00), 0));
+    /// assert_eq!(time!(23:00).overflowing_add(90.minutes()), (time!(0:30), 1));
+    /// assert_eq!(time!(0:30).overflowing_add((-90).minutes()), (time!(23:00), -1));
+    /// assert_eq!(time!(0:00).overflowing_add(3.days()), (time!(0:00), 3));
+    /// ```
+    #[allow(clippy::missing_const_for_fn)] // `rem_euclid` is not const on older compilers
+    pub fn overflowing_add(self, duration: Duration) -> (Self, i64) {
+        let nanoseconds = self.nanoseconds_since_midnight() as i128 + duration.whole_nanoseconds();
+        // The number of days is bounded by the range of `Duration`, so it
+        // fits in an `i64`.
+        let days = nanoseconds.div_euclid(NANOS_PER_DAY as i128) as i64;
+        let time = nanoseconds.rem_euclid(NANOS_PER_DAY as i128) as u64;
+        (Self::from_nanoseconds_since_midnight(time), days)
+    }
+
     /// Get the number of nanoseconds since midnight.
     pub(crate) const fn nanoseconds_since_midnight(self) -> u64 {
         self.hour() as u64 * 60 * 60 * 1_000_000_000
@@ -601,6 +752,69 @@ impl Time {
         Self::try_from_parsed_items(parse(s.as_ref(), &format.into())?)
     }
 
+    /// Attempt to parse a `Time` using the provided string, with `%p` and `%P`
+    /// (including within `%r`) matching the provided markers rather than the
+    /// English ones.
+    ///
+    /// ```rust
+    /// # use time::{AmPmMarkers, Time, time};
+    /// let markers = AmPmMarkers { am: "vorm.", pm: "nachm." };
+    /// assert_eq!(
+    ///     Time::parse_with_am_pm("2:30 nachm.", "%-I:%M %p", markers),
+    ///     Ok(time!(14:30))
+    /// );
+    /// assert_eq!(
+    ///     Time::parse_with_am_pm("2:30 pm", "%-I:%M %p", AmPmMarkers::ENGLISH),
+    ///     Ok(time!(14:30))
+    /// );
+    /// ```
+    pub fn parse_with_am_pm(
+        s: impl AsRef<str>,
+        format: impl AsRef<str>,
+        markers: AmPmMarkers<'_>,
+    ) -> ParseResult<Self> {
+        Self::try_from_parsed_items(parse_with_am_pm(s.as_ref(), &format.into(), markers)?)
+    }
+
+    /// Attempt to parse a `Time` using the provided string, additionally
+    /// accepting `24:00:00` as in ISO 8601.
+    ///
+    /// `24:00:00` represents midnight at the end of the day, which is not a
+    /// valid `Time`. It is normalized to `00:00:00`, with the second element of
+    /// the returned tuple set to `true` to indicate that the time belongs to
+    /// the following day. All other times are parsed as with
+    /// [`Time::parse`], which rejects `24:00:00`.
+    ///
+    /// ```rust
+    /// # use time::{Time, time};
+    /// assert!(Time::parse("24:00:00", "%T").is_err());
+    /// assert_eq!(
+    ///     Time::parse_with_day_carry("24:00:00", "%T"),
+    ///     Ok((time!(0:00), true))
+    /// );
+    /// assert_eq!(
+    ///     Time::parse_with_day_carry("23:59:59", "%T"),
+    ///     Ok((time!(23:59:59), false))
+    /// );
+    /// ```
+    pub fn parse_with_day_carry(
+        s: impl AsRef<str>,
+        format: impl AsRef<str>,
+    ) -> ParseResult<(Self, bool)> {
+        let mut items = parse(s.as_ref(), &format.into())?;
+
+        let is_end_of_day = items.hour_24 == Some(24)
+            && items.minute.unwrap_or(0) == 0
+            && items.second.unwrap_or(0) == 0
+            && items.nanosecond.unwrap_or(0) == 0;
+
+        if is_end_of_day {
+            items.hour_24 = Some(0);
+        }
+
+        Ok((Self::try_from_parsed_items(items)?, is_end_of_day))
+    }
+
     /// Given the items already parsed, attempt to create a `Time`.
     pub(crate) fn try_from_parsed_items(items: ParsedItems) -> ParseResult<Self> {
         macro_rules! items {
@@ -681,12 +895,16 @@ impl Display for Time {
 impl Add<Duration> for Time {
     type Output = Self;
 
-    /// Add the sub-day time of the `Duration` to the `Time`. Wraps on overflow.
+    /// Add the sub-day time of the `Duration` to the `Time`. Wraps on overflow,
+    /// discarding any whole days carried. Use
+    /// [`Time::overflowing_add`](crate::Time::overflowing_add) to obtain the
+    /// carry.
     ///
     /// ```rust
     /// # use time::prelude::*;
     /// # use time_macros::time;
     /// assert_eq!(time!(12:00) + 2.hours(), time!(14:00));
+    /// assert_eq!(time!(23:00) + 90.minutes(), time!(0:30));
     /// assert_eq!(time!(0:00:01) + (-2).seconds(), time!(23:59:59));
     /// ```
     fn add(self, duration: Duration) -> Self::Output {
@@ -761,7 +979,9 @@ impl Sub<Duration> for Time {
     type Output = Self;
 
     /// Subtract the sub-day time of the `Duration` from the `Time`. Wraps on
-    /// overflow.
+    /// overflow, discarding any whole days carried. Use
+    /// [`Time::overflowing_add`](crate::Time::overflowing_add) with the
+    /// negated duration to obtain the carry.
     ///
     /// ```rust
     /// # use time::prelude::*;
@@ -895,6 +1115,31 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn packed() -> crate::Result<()> {
+        for &time in &[
+            Time::midnight(),
+            time!(0:00:00:000_000_001),
+            time!(12:34:56:789_012_345),
+            time!(23:59:59:999_999_999),
+        ] {
+            assert_eq!(Time::from_packed(time.to_packed()), Ok(time));
+        }
+        assert_eq!(Time::midnight().to_packed(), 0);
+        assert_eq!(time!(23:59:59:999_999_999).to_packed(), NANOS_PER_DAY - 1);
+        assert!(time!(12:00).to_packed() < time!(12:00:00:000_000_001).to_packed());
+
+        assert_eq!(
+            Time::from_packed(NANOS_PER_DAY).map_err(|err| (err.name, err.value)),
+            Err(("packed", NANOS_PER_DAY as i64))
+        );
+        assert_eq!(
+            Time::from_packed(u64::max_value()).map_err(|err| err.value),
+            Err(i64::max_value())
+        );
+        Ok(())
+    }
+
     #[test]
     fn midnight() -> crate::Result<()> {
         assert_eq!(Time::midnight(), time!(0:00));
@@ -1117,6 +1362,18 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn as_hms() -> crate::Result<()> {
+        let time = time!(13:42:07:123_456_789);
+        assert_eq!(time.as_hms(), (time.hour(), time.minute(), time.second()));
+        assert_eq!(
+            time.as_hms_nano(),
+            (time.hour(), time.minute(), time.second(), time.nanosecond())
+        );
+        assert_eq!(time.as_hms_nano(), (13, 42, 7, 123_456_789));
+        Ok(())
+    }
+
     #[test]
     fn format() -> crate::Result<()> {
         assert_eq!(time!(0:00).format("%T"), "0:00:00");
@@ -1126,6 +1383,51 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn format_width() -> crate::Result<()> {
+        assert_eq!(time!(5:07:09).format("%02H:%02M:%02S"), "05:07:09");
+        assert_eq!(time!(5:07:09).format("%-H"), "5");
+        assert_eq!(time!(5:07:09).format("%03H"), "005");
+        assert_eq!(Time::parse("05:07", "%02H:%M"), Ok(time!(5:07)));
+        assert_eq!(Time::parse("005:07", "%03H:%M"), Ok(time!(5:07)));
+        assert!(Time::parse("5:07", "%02H:%M").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_end_of_day() -> crate::Result<()> {
+        assert!(Time::parse("24:00:00", "%T").is_err());
+        assert!(Time::parse("24:00", "%R").is_err());
+
+        assert_eq!(
+            Time::parse_with_day_carry("24:00:00", "%T"),
+            Ok((time!(0:00), true))
+        );
+        assert_eq!(
+            Time::parse_with_day_carry("24:00", "%R"),
+            Ok((time!(0:00), true))
+        );
+        assert_eq!(
+            Time::parse_with_day_carry("24:00:00.000000000", "%T.%N"),
+            Ok((time!(0:00), true))
+        );
+        assert_eq!(
+            Time::parse_with_day_carry("00:00:00", "%T"),
+            Ok((time!(0:00), false))
+        );
+        assert_eq!(
+            Time::parse_with_day_carry("12:34:56", "%T"),
+            Ok((time!(12:34:56), false))
+        );
+
+        // Only the end of the day itself is accepted.
+        assert!(Time::parse_with_day_carry("24:00:01", "%T").is_err());
+        assert!(Time::parse_with_day_carry("24:01:00", "%T").is_err());
+        assert!(Time::parse_with_day_carry("24:00:00.000000001", "%T.%N").is_err());
+        assert!(Time::parse_with_day_carry("25:00:00", "%T").is_err());
+        Ok(())
+    }
+
     #[test]
     fn parse() -> crate::Result<()> {
         assert_eq!(Time::parse("0:00:00", "%T"), Ok(time!(0:00)));
@@ -1153,6 +1455,68 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn parse_with_am_pm() -> crate::Result<()> {
+        let markers = AmPmMarkers {
+            am: "午前",
+            pm: "午後",
+        };
+        assert_eq!(
+            Time::parse_with_am_pm("12:00 午前", "%I:%M %p", markers),
+            Ok(time!(0:00))
+        );
+        assert_eq!(
+            Time::parse_with_am_pm("12:00 午後", "%I:%M %P", markers),
+            Ok(time!(12:00))
+        );
+        assert_eq!(
+            Time::parse_with_am_pm("11:59:59 午後", "%r", markers),
+            Ok(time!(23:59:59))
+        );
+        assert_eq!(
+            Time::parse_with_am_pm("1:00:00 am", "%r", markers),
+            Err(error::Parse::InvalidAmPm)
+        );
+
+        let markers = AmPmMarkers { am: "a", pm: "p" };
+        assert_eq!(
+            Time::parse_with_am_pm("1:30 P", "%-I:%M %p", markers),
+            Ok(time!(13:30))
+        );
+
+        // The default markers behave as English.
+        for &(input, format) in &[("1:00:00 pm", "%r"), ("1:00 PM", "%-I:%M %P")] {
+            assert_eq!(
+                Time::parse_with_am_pm(input, format, AmPmMarkers::default()),
+                Time::parse(input, format)
+            );
+        }
+        assert_eq!(Time::parse("1:00:00 pm", "%r"), Ok(time!(13:00)));
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_hms_leap() -> crate::Result<()> {
+        assert_eq!(
+            Time::try_from_hms_leap(23, 59, 60),
+            Ok(time!(23:59:59:999_999_999))
+        );
+        assert_eq!(
+            Time::try_from_hms_leap(5, 29, 60),
+            Ok(time!(5:29:59:999_999_999))
+        );
+        assert!(Time::try_from_hms_leap(23, 59, 60)? > time!(23:59:59));
+        assert_eq!(Time::try_from_hms_leap(0, 0, 0), Ok(time!(0:00)));
+        assert_eq!(Time::try_from_hms_leap(12, 34, 56), Ok(time!(12:34:56)));
+        assert!(Time::try_from_hms_leap(23, 59, 61).is_err());
+        assert!(Time::try_from_hms_leap(24, 0, 0).is_err());
+        assert!(Time::try_from_hms_leap(0, 60, 0).is_err());
+
+        // The strict constructor is unchanged.
+        assert!(Time::try_from_hms(23, 59, 60).is_err());
+        Ok(())
+    }
+
     #[test]
     fn parse_missing_seconds() -> crate::Result<()> {
         // Missing seconds defaults to zero.
@@ -1160,6 +1524,38 @@ mod test {
         assert_eq!(Time::parse("23:59", "%H:%M"), Ok(time!(23:59)));
         assert_eq!(Time::parse("12:00 am", "%I:%M %p"), Ok(time!(0:00)));
         assert_eq!(Time::parse("12:00 pm", "%I:%M %p"), Ok(time!(12:00)));
+        assert_eq!(Time::parse("14:30", "%R"), Ok(time!(14:30)));
+        assert_eq!(Time::parse("14:30+00:00", "%H:%M%:z"), Ok(time!(14:30)));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_optional_section() -> crate::Result<()> {
+        let format = "%H:%M%(:%S%)";
+        assert_eq!(Time::parse("14:30", format), Ok(time!(14:30)));
+        assert_eq!(Time::parse("14:30:05", format), Ok(time!(14:30:05)));
+        assert_eq!(time!(14:30:05).format(format), "14:30:05");
+
+        // A partial match leaves the section's fields unset.
+        assert_eq!(Time::parse("14:30:", format), Ok(time!(14:30)));
+        assert_eq!(Time::parse("14:30:5x", format), Ok(time!(14:30)));
+
+        // Nested sections are each optional.
+        let format = "%H:%M%(:%S%(.%N%)%)";
+        assert_eq!(Time::parse("14:30", format), Ok(time!(14:30)));
+        assert_eq!(Time::parse("14:30:05", format), Ok(time!(14:30:05)));
+        assert_eq!(
+            Time::parse("14:30:05.123456789", format),
+            Ok(time!(14:30:05:123_456_789))
+        );
+
+        // Failures outside of an optional section are still reported.
+        assert_eq!(
+            Time::parse("14", "%H:%M%(:%S%)"),
+            Err(crate::ParseError::UnexpectedEndOfString)
+        );
+        assert!(crate::util::validate_format_string("%H%(:%M").is_err());
+        assert!(crate::util::validate_format_string("%H%)").is_err());
         Ok(())
     }
 
@@ -1191,6 +1587,11 @@ mod test {
         assert_eq!(time!(0:00) + 1.minutes(), time!(0:01));
         assert_eq!(time!(0:00) + 1.hours(), time!(1:00));
         assert_eq!(time!(0:00) + 1.days(), time!(0:00));
+
+        // Wrapping past midnight discards the carried day.
+        assert_eq!(time!(23:00) + 90.minutes(), time!(0:30));
+        assert_eq!(time!(23:59:59:999_999_999) + 1.nanoseconds(), time!(0:00));
+        assert_eq!(time!(12:00) + 36.hours(), time!(0:00));
         Ok(())
     }
 
@@ -1221,6 +1622,37 @@ mod test {
         assert_eq!(time!(0:00) - 1.minutes(), time!(23:59));
         assert_eq!(time!(0:00) - 1.hours(), time!(23:00));
         assert_eq!(time!(0:00) - 1.days(), time!(0:00));
+        assert_eq!(time!(0:30) - 90.minutes(), time!(23:00));
+        assert_eq!(time!(12:00) - 36.hours(), time!(0:00));
+        Ok(())
+    }
+
+    #[test]
+    fn overflowing_add() -> crate::Result<()> {
+        assert_eq!(time!(12:00).overflowing_add(1.hours()), (time!(13:00), 0));
+        assert_eq!(time!(23:00).overflowing_add(90.minutes()), (time!(0:30), 1));
+        assert_eq!(
+            time!(0:30).overflowing_add((-90).minutes()),
+            (time!(23:00), -1)
+        );
+        assert_eq!(time!(12:00).overflowing_add(36.hours()), (time!(0:00), 2));
+        assert_eq!(
+            time!(12:00).overflowing_add((-36).hours()),
+            (time!(0:00), -1)
+        );
+        assert_eq!(
+            time!(0:00).overflowing_add(Duration::max_value()).1,
+            Duration::max_value().whole_days()
+        );
+
+        // The time always matches the wrapping operator.
+        for &hours in &[-49, -25, -1, 0, 1, 25, 49] {
+            let duration = hours.hours() + 30.minutes();
+            assert_eq!(
+                time!(12:00).overflowing_add(duration).0,
+                time!(12:00) + duration
+            );
+        }
         Ok(())
     }
 
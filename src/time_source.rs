@@ -0,0 +1,56 @@
+//! A user-provided source of the current time, for use without the standard
+//! library.
+
+use crate::OffsetDateTime;
+use core::{
+    mem, ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+/// The registered time source, stored as a type-erased function pointer. A
+/// null pointer indicates that no source has been registered.
+static TIME_SOURCE: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Register a function returning the current [Unix
+/// timestamp](https://en.wikipedia.org/wiki/Unix_time), in seconds.
+///
+/// Without the standard library, there is no portable way to determine the
+/// current time. Platforms with a clock (such as an RTC or a network time
+/// source) can register it with this function, after which
+/// [`OffsetDateTime::now_utc`] will call it. Registering a new source
+/// replaces the previous one.
+///
+/// This function is only available when the `std` feature is disabled. With
+/// the standard library, `OffsetDateTime::now_utc` always uses the system
+/// clock.
+pub fn set_time_source(source: fn() -> i64) {
+    TIME_SOURCE.store(source as *mut (), Ordering::Release);
+}
+
+/// Obtain the current time from the registered source, if there is one.
+pub(crate) fn now_utc() -> Option<OffsetDateTime> {
+    let source = TIME_SOURCE.load(Ordering::Acquire);
+    if source.is_null() {
+        return None;
+    }
+
+    // Safety: The only non-null value ever stored is a `fn() -> i64`, which
+    // has the same size as a pointer.
+    #[allow(unsafe_code)]
+    let source = unsafe { mem::transmute::<*mut (), fn() -> i64>(source) };
+    Some(OffsetDateTime::from_unix_timestamp(source()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn now_utc_uses_registered_source() {
+        set_time_source(|| 1_609_459_200);
+        assert_eq!(
+            OffsetDateTime::now_utc(),
+            OffsetDateTime::from_unix_timestamp(1_609_459_200)
+        );
+    }
+}
@@ -2,15 +2,19 @@
 use crate::OffsetDateTime;
 use crate::{
     error,
-    format::{parse, ParsedItems},
-    DeferredFormat, Duration, ParseResult,
+    format::{offset, parse, parse::parse_with_zones, well_known, ParsedItems},
+    DeferredFormat, Duration, ParseResult, ParseStrictness, ZoneAbbreviations,
 };
 #[cfg(not(feature = "std"))]
 use alloc::{
     borrow::ToOwned,
     string::{String, ToString},
 };
-use core::fmt::{self, Display};
+use const_fn::const_fn;
+use core::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
 
 /// An offset from UTC.
 ///
@@ -145,6 +149,71 @@ impl UtcOffset {
         Self { seconds }
     }
 
+    /// Attempt to create a `UtcOffset` from a sign and the unsigned hours,
+    /// minutes, and seconds. The sign is applied to every component, so a
+    /// westerly offset never has components pointing in different
+    /// directions.
+    ///
+    /// ```rust
+    /// # use time::{offset, UtcOffset};
+    /// assert_eq!(UtcOffset::from_signed_hms(true, 5, 30, 0), Ok(offset!(-5:30)));
+    /// assert_eq!(UtcOffset::from_signed_hms(false, 5, 30, 0), Ok(offset!(+5:30)));
+    /// ```
+    ///
+    /// Returns an error if any component is not valid.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert!(UtcOffset::from_signed_hms(false, 24, 0, 0).is_err()); // 24 isn't a valid hour.
+    /// assert!(UtcOffset::from_signed_hms(true, 0, 60, 0).is_err()); // 60 isn't a valid minute.
+    /// assert!(UtcOffset::from_signed_hms(false, 0, 0, 60).is_err()); // 60 isn't a valid second.
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn from_signed_hms(
+        negative: bool,
+        hours: u8,
+        minutes: u8,
+        seconds: u8,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(hours in 0 => 23);
+        ensure_value_in_range!(minutes in 0 => 59);
+        ensure_value_in_range!(seconds in 0 => 59);
+
+        let magnitude = hours as i32 * 3_600 + minutes as i32 * 60 + seconds as i32;
+        Ok(Self::seconds(if negative { -magnitude } else { magnitude }))
+    }
+
+    /// Create a `UtcOffset` from a military time zone letter, as used in
+    /// aviation and by the military. `A` through `M` (skipping `J`) are one to
+    /// twelve hours east, `N` through `Y` are one to twelve hours west, and `Z`
+    /// is UTC.
+    ///
+    /// Returns `None` for `J`, which indicates the observer's local time, as
+    /// well as for any character that is not an uppercase ASCII letter.
+    ///
+    /// ```rust
+    /// # use time::{offset, UtcOffset};
+    /// assert_eq!(UtcOffset::from_military_letter('Z'), Some(offset!(UTC)));
+    /// assert_eq!(UtcOffset::from_military_letter('A'), Some(offset!(+1)));
+    /// assert_eq!(UtcOffset::from_military_letter('N'), Some(offset!(-1)));
+    /// assert_eq!(UtcOffset::from_military_letter('J'), None);
+    /// ```
+    ///
+    /// This function is `const fn` when using rustc >= 1.46.
+    #[const_fn("1.46")]
+    pub const fn from_military_letter(letter: char) -> Option<Self> {
+        let hours = match letter {
+            'A'..='I' => (letter as u8 - b'A' + 1) as i8,
+            'K'..='M' => (letter as u8 - b'K' + 10) as i8,
+            'N'..='Y' => -((letter as u8 - b'N' + 1) as i8),
+            'Z' => 0,
+            _ => return None,
+        };
+        Some(Self::hours(hours))
+    }
+
     /// Get the number of seconds from UTC the value is. Positive is east,
     /// negative is west.
     ///
@@ -161,16 +230,40 @@ impl UtcOffset {
     /// Get the number of minutes from UTC the value is. Positive is east,
     /// negative is west.
     ///
+    /// Any seconds are truncated towards zero, so an offset of less than a
+    /// minute in either direction has zero minutes. Use
+    /// [`UtcOffset::as_minutes_checked`] to detect this.
+    ///
     /// ```rust
     /// # use time::UtcOffset;
     /// assert_eq!(UtcOffset::UTC.as_minutes(), 0);
     /// assert_eq!(UtcOffset::hours(12).as_minutes(), 720);
     /// assert_eq!(UtcOffset::hours(-12).as_minutes(), -720);
+    /// assert_eq!(UtcOffset::seconds(-90).as_minutes(), -1);
+    /// assert_eq!(UtcOffset::seconds(-30).as_minutes(), 0);
     /// ```
     pub const fn as_minutes(self) -> i16 {
         (self.as_seconds() / 60) as i16
     }
 
+    /// Get the number of minutes from UTC the value is, returning `None` if the
+    /// offset is not a whole number of minutes. Positive is east, negative is
+    /// west.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(UtcOffset::hours(-5).as_minutes_checked(), Some(-300));
+    /// assert_eq!(UtcOffset::seconds(-30).as_minutes_checked(), None);
+    /// ```
+    #[const_fn("1.46")]
+    pub const fn as_minutes_checked(self) -> Option<i16> {
+        if self.as_seconds() % 60 == 0 {
+            Some(self.as_minutes())
+        } else {
+            None
+        }
+    }
+
     /// Get the number of hours from UTC the value is. Positive is east,
     /// negative is west.
     ///
@@ -184,6 +277,42 @@ impl UtcOffset {
         (self.as_seconds() / 3_600) as i8
     }
 
+    /// Computes `self + duration`, returning `None` if the result is outside
+    /// the range ±23:59:59. Only the whole seconds of the duration are added.
+    ///
+    /// ```rust
+    /// # use time::{offset, prelude::*};
+    /// assert_eq!(offset!(+4:30).checked_add(1.hours()), Some(offset!(+5:30)));
+    /// assert_eq!(offset!(+23).checked_add(1.hours()), None);
+    /// ```
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        Self::checked_from_seconds((self.seconds as i64).checked_add(duration.whole_seconds())?)
+    }
+
+    /// Computes `self - duration`, returning `None` if the result is outside
+    /// the range ±23:59:59. Only the whole seconds of the duration are
+    /// subtracted.
+    ///
+    /// ```rust
+    /// # use time::{offset, prelude::*};
+    /// assert_eq!(offset!(+4:30).checked_sub(1.hours()), Some(offset!(+3:30)));
+    /// assert_eq!(offset!(-23).checked_sub(1.hours()), None);
+    /// ```
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        Self::checked_from_seconds((self.seconds as i64).checked_sub(duration.whole_seconds())?)
+    }
+
+    /// Create a `UtcOffset` from the number of seconds, returning `None` if it
+    /// is outside the range ±23:59:59.
+    #[const_fn("1.46")]
+    const fn checked_from_seconds(seconds: i64) -> Option<Self> {
+        if (seconds < -86_399) | (seconds > 86_399) {
+            None
+        } else {
+            Some(Self::seconds(seconds as i32))
+        }
+    }
+
     /// Convert a `UtcOffset` to ` Duration`. Useful for implementing operators.
     pub(crate) const fn as_duration(self) -> Duration {
         Duration::seconds(self.seconds as i64)
@@ -192,6 +321,12 @@ impl UtcOffset {
     /// Obtain the system's UTC offset at a known moment in time. If the offset
     /// cannot be determined, UTC is returned.
     ///
+    /// This function may be called from multiple threads at once. On Unix, the
+    /// system's time zone state is refreshed and read under a lock internal to
+    /// this crate. Other code that modifies the `TZ` environment variable or
+    /// calls `tzset` concurrently is not covered by that lock, and may cause an
+    /// incorrect offset to be returned.
+    ///
     /// ```rust,no_run
     /// # use time::{UtcOffset, OffsetDateTime};
     /// let unix_epoch = OffsetDateTime::unix_epoch();
@@ -199,6 +334,7 @@ impl UtcOffset {
     /// println!("{}", local_offset.format("%z"));
     /// ```
     #[cfg(feature = "std")]
+    #[cfg_attr(docs, doc(cfg(feature = "std")))]
     pub fn local_offset_at(datetime: OffsetDateTime) -> Self {
         try_local_offset_at(datetime).unwrap_or(Self::UTC)
     }
@@ -206,6 +342,8 @@ impl UtcOffset {
     /// Attempt to obtain the system's UTC offset at a known moment in time. If
     /// the offset cannot be determined, an error is returned.
     ///
+    /// See [`UtcOffset::local_offset_at`] for the thread-safety guarantees.
+    ///
     /// ```rust,no_run
     /// # use time::{UtcOffset, OffsetDateTime};
     /// let unix_epoch = OffsetDateTime::unix_epoch();
@@ -213,6 +351,7 @@ impl UtcOffset {
     /// assert!(local_offset.is_ok());
     /// ```
     #[cfg(feature = "std")]
+    #[cfg_attr(docs, doc(cfg(feature = "std")))]
     pub fn try_local_offset_at(
         datetime: OffsetDateTime,
     ) -> Result<Self, error::IndeterminateOffset> {
@@ -228,6 +367,7 @@ impl UtcOffset {
     /// println!("{}", local_offset.format("%z"));
     /// ```
     #[cfg(feature = "std")]
+    #[cfg_attr(docs, doc(cfg(feature = "std")))]
     pub fn current_local_offset() -> Self {
         let now = OffsetDateTime::now_utc();
         try_local_offset_at(now).unwrap_or(Self::UTC)
@@ -242,10 +382,38 @@ impl UtcOffset {
     /// assert!(local_offset.is_ok());
     /// ```
     #[cfg(feature = "std")]
+    #[cfg_attr(docs, doc(cfg(feature = "std")))]
     pub fn try_current_local_offset() -> Result<Self, error::IndeterminateOffset> {
         let now = OffsetDateTime::now_utc();
         try_local_offset_at(now).ok_or_else(error::IndeterminateOffset::new)
     }
+
+    /// Find the next instant after `datetime` at which the system's UTC offset
+    /// changes, such as the start or end of daylight saving time. The returned
+    /// value is the first second using the new offset, expressed in that
+    /// offset.
+    ///
+    /// No time zone database is consulted; the local offset is sampled once per
+    /// day over the following 400 days, after which a binary search narrows
+    /// down the exact second. Transitions that are reverted within a single
+    /// day may therefore be missed. If no transition is found, `Ok(None)` is
+    /// returned. If the local offset cannot be determined at any of the
+    /// sampled instants, an error is returned.
+    ///
+    /// ```rust,no_run
+    /// # use time::{UtcOffset, OffsetDateTime};
+    /// let now = OffsetDateTime::now_utc();
+    /// if let Ok(Some(transition)) = UtcOffset::next_transition_after(now) {
+    ///     println!("the offset changes to {} at {}", transition.offset(), transition);
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docs, doc(cfg(feature = "std")))]
+    pub fn next_transition_after(
+        datetime: OffsetDateTime,
+    ) -> Result<Option<OffsetDateTime>, error::IndeterminateOffset> {
+        next_transition_after(datetime, try_local_offset_at)
+    }
 }
 
 /// Methods that allow parsing and formatting the `UtcOffset`.
@@ -274,6 +442,22 @@ impl UtcOffset {
             .to_owned()
     }
 
+    /// Display the offset in the form used by ISO 8601 and RFC3339. UTC is
+    /// displayed as `Z`, while other offsets are displayed as `±HH:MM`, or
+    /// `±HH:MM:SS` if the offset contains seconds.
+    ///
+    /// This does not change the behavior of the `Display` implementation.
+    ///
+    /// ```rust
+    /// # use time::offset;
+    /// assert_eq!(offset!(UTC).display_iso8601().to_string(), "Z");
+    /// assert_eq!(offset!(+5:30).display_iso8601().to_string(), "+05:30");
+    /// assert_eq!(offset!(-1).display_iso8601().to_string(), "-01:00");
+    /// ```
+    pub fn display_iso8601(self) -> impl Display {
+        Iso8601Display(self)
+    }
+
     /// Attempt to parse the `UtcOffset` using the provided string.
     ///
     /// ```rust
@@ -285,6 +469,64 @@ impl UtcOffset {
         Self::try_from_parsed_items(parse(s.as_ref(), &format.into())?)
     }
 
+    /// Attempt to parse the `UtcOffset` using the provided string, with `%Z`
+    /// recognizing the provided abbreviations rather than the default ones.
+    ///
+    /// ```rust
+    /// # use time::{offset, UtcOffset, ZoneAbbreviations};
+    /// let zones = ZoneAbbreviations {
+    ///     known: &[("CST", offset!(-6))],
+    ///     ambiguous: &[],
+    /// };
+    /// assert_eq!(UtcOffset::parse_with_zones("CST", "%Z", zones), Ok(offset!(-6)));
+    /// assert!(UtcOffset::parse("CST", "%Z").is_err());
+    /// ```
+    pub fn parse_with_zones(
+        s: impl AsRef<str>,
+        format: impl AsRef<str>,
+        zones: ZoneAbbreviations<'_>,
+    ) -> ParseResult<Self> {
+        Self::try_from_parsed_items(parse_with_zones(s.as_ref(), &format.into(), zones)?)
+    }
+
+    /// Attempt to parse an RFC3339 offset (`Z` or `±HH:MM`), additionally
+    /// reporting whether the offset was given as `-00:00`.
+    ///
+    /// RFC3339 uses `-00:00` to indicate that the time is in UTC, but the
+    /// offset to local time is unknown. This is distinct from `+00:00` and
+    /// `Z`, which indicate that UTC is the preferred reference point. In all
+    /// three cases the returned offset is UTC; the boolean is only `true` for
+    /// `-00:00`. The form without a colon (`-0000`) is also accepted.
+    ///
+    /// ```rust
+    /// # use time::{UtcOffset, offset};
+    /// assert_eq!(UtcOffset::parse_rfc3339_unknown("-00:00"), Ok((offset!(UTC), true)));
+    /// assert_eq!(UtcOffset::parse_rfc3339_unknown("-0000"), Ok((offset!(UTC), true)));
+    /// assert_eq!(UtcOffset::parse_rfc3339_unknown("+00:00"), Ok((offset!(UTC), false)));
+    /// assert_eq!(UtcOffset::parse_rfc3339_unknown("Z"), Ok((offset!(UTC), false)));
+    /// assert_eq!(UtcOffset::parse_rfc3339_unknown("-05:00"), Ok((offset!(-5), false)));
+    /// ```
+    pub fn parse_rfc3339_unknown(s: impl AsRef<str>) -> ParseResult<(Self, bool)> {
+        let mut s = s.as_ref();
+
+        // A digit following the sign and hours indicates the form without a
+        // colon.
+        let (offset, is_unknown) = if s.len() > 3 && s.as_bytes()[3].is_ascii_digit() {
+            let is_negative = s.starts_with('-');
+            let mut items = ParsedItems::new();
+            offset::parse_z(&mut items, &mut s)?;
+            let offset = Self::try_from_parsed_items(items)?;
+            (offset, is_negative && offset == Self::UTC)
+        } else {
+            well_known::rfc3339::parse_offset(&mut s, ParseStrictness::Lenient)?
+        };
+
+        if !s.is_empty() {
+            return Err(error::Parse::UnexpectedTrailingCharacters);
+        }
+        Ok((offset, is_unknown))
+    }
+
     /// Given the items already parsed, attempt to create a `UtcOffset`.
     pub(crate) fn try_from_parsed_items(items: ParsedItems) -> ParseResult<Self> {
         items.offset.ok_or(error::Parse::InsufficientInformation)
@@ -312,6 +554,126 @@ impl Display for UtcOffset {
     }
 }
 
+/// A `UtcOffset` displayed in the form used by ISO 8601. Created by
+/// [`UtcOffset::display_iso8601`].
+#[derive(Debug, Clone, Copy)]
+struct Iso8601Display(UtcOffset);
+
+impl Display for Iso8601Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let offset = self.0;
+        if offset == UtcOffset::UTC {
+            return f.write_str("Z");
+        }
+
+        let sign = if offset.seconds < 0 { '-' } else { '+' };
+        let hours = offset.as_hours().abs();
+        let minutes = offset.as_minutes().abs() - hours as i16 * 60;
+        let seconds = offset.as_seconds().abs() - hours as i32 * 3_600 - minutes as i32 * 60;
+
+        write!(f, "{}{:02}:{:02}", sign, hours, minutes)?;
+
+        if seconds != 0 {
+            write!(f, ":{:02}", seconds)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Add<Duration> for UtcOffset {
+    type Output = Self;
+
+    /// Add the whole seconds of the `Duration` to the `UtcOffset`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the result is outside the range ±23:59:59. Use
+    /// [`UtcOffset::checked_add`](crate::UtcOffset::checked_add) to handle this
+    /// case.
+    fn add(self, duration: Duration) -> Self::Output {
+        self.checked_add(duration)
+            .expect("resulting value is out of range")
+    }
+}
+
+impl AddAssign<Duration> for UtcOffset {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for UtcOffset {
+    type Output = Self;
+
+    /// Subtract the whole seconds of the `Duration` from the `UtcOffset`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the result is outside the range ±23:59:59. Use
+    /// [`UtcOffset::checked_sub`](crate::UtcOffset::checked_sub) to handle this
+    /// case.
+    fn sub(self, duration: Duration) -> Self::Output {
+        self.checked_sub(duration)
+            .expect("resulting value is out of range")
+    }
+}
+
+impl SubAssign<Duration> for UtcOffset {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+/// Find the first instant after `datetime` at which `offset_at` returns a
+/// different value, searching at most 400 days ahead. An error is returned as
+/// soon as `offset_at` is unable to determine an offset.
+#[cfg(feature = "std")]
+fn next_transition_after(
+    datetime: OffsetDateTime,
+    offset_at: impl Fn(OffsetDateTime) -> Option<UtcOffset>,
+) -> Result<Option<OffsetDateTime>, error::IndeterminateOffset> {
+    /// The number of days to sample before giving up.
+    const MAX_DAYS: i64 = 400;
+
+    let offset_at = |timestamp| {
+        offset_at(OffsetDateTime::from_unix_timestamp(timestamp))
+            .ok_or_else(error::IndeterminateOffset::new)
+    };
+
+    let start = datetime.timestamp();
+    let initial = offset_at(start)?;
+
+    // Find a day on which the offset differs from the initial offset.
+    let mut low = start;
+    let mut high = start;
+    for day in 1..=MAX_DAYS {
+        let timestamp = start + day * 86_400;
+        if offset_at(timestamp)? != initial {
+            high = timestamp;
+            break;
+        }
+        low = timestamp;
+    }
+    if high == start {
+        return Ok(None);
+    }
+
+    // Narrow down the exact second of the transition. `low` always has the
+    // initial offset and `high` never does.
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if offset_at(mid)? == initial {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    let transition = OffsetDateTime::from_unix_timestamp(high);
+    Ok(Some(transition.to_offset(offset_at(high)?)))
+}
+
 /// Attempt to obtain the system's UTC offset. If the offset cannot be
 /// determined, `None` is returned.
 #[cfg(feature = "std")]
@@ -321,6 +683,33 @@ fn try_local_offset_at(datetime: OffsetDateTime) -> Option<UtcOffset> {
     {
         use standback::{convert::TryInto, mem::MaybeUninit};
 
+        use std::sync::{Mutex, MutexGuard, Once, PoisonError};
+
+        /// Serialize access to the system's time zone state, blocking until
+        /// the lock is available. The lock is released when the guard is
+        /// dropped.
+        fn lock_tz() -> MutexGuard<'static, ()> {
+            /// Initializes `TZ_LOCK` on first use.
+            static INIT: Once = Once::new();
+            /// The lock, leaked on initialization so that it lives for the
+            /// remainder of the program. `Mutex::new` cannot be called in a
+            /// `static` on the minimum supported Rust version.
+            static mut TZ_LOCK: *const Mutex<()> = std::ptr::null();
+
+            // Safety: `TZ_LOCK` is only written once, inside `call_once`, and
+            // is only read after `call_once` has returned. The pointer is
+            // never freed, so the reference is valid for `'static`.
+            #[allow(unsafe_code)]
+            let lock = unsafe {
+                INIT.call_once(|| TZ_LOCK = Box::into_raw(Box::new(Mutex::new(()))));
+                &*TZ_LOCK
+            };
+
+            // The lock protects no data, so a panic while it was held leaves
+            // nothing in an inconsistent state.
+            lock.lock().unwrap_or_else(PoisonError::into_inner)
+        }
+
         /// Convert the given Unix timestamp to a `libc::tm`. Returns `None` on
         /// any error.
         fn timestamp_to_tm(timestamp: i64) -> Option<libc::tm> {
@@ -336,10 +725,16 @@ fn try_local_offset_at(datetime: OffsetDateTime) -> Option<UtcOffset> {
 
             let mut tm = MaybeUninit::uninit();
 
+            // `tzset` writes to global state that `localtime_r` then reads.
+            // Holding the lock for both calls ensures that concurrent calls
+            // from this crate never observe a partially updated time zone.
+            let _guard = lock_tz();
+
             // Update timezone information from system. `localtime_r` does not
             // do this for us.
             //
-            // Safety: tzset is thread-safe.
+            // Safety: tzset is thread-safe, and calls from this crate are
+            // serialized by the lock above.
             #[allow(unsafe_code)]
             unsafe {
                 tzset();
@@ -545,6 +940,47 @@ mod test {
         assert_eq!(UtcOffset::seconds(-86_399).as_seconds(), -86_399);
     }
 
+    #[test]
+    fn from_signed_hms() {
+        assert_eq!(
+            UtcOffset::from_signed_hms(true, 3, 30, 15).map(UtcOffset::as_seconds),
+            Ok(-12_615)
+        );
+        assert_eq!(
+            UtcOffset::from_signed_hms(false, 3, 30, 15).map(UtcOffset::as_seconds),
+            Ok(12_615)
+        );
+        assert_eq!(
+            UtcOffset::from_signed_hms(true, 0, 0, 0),
+            Ok(UtcOffset::UTC)
+        );
+        assert_eq!(
+            UtcOffset::from_signed_hms(true, 23, 59, 59).map(UtcOffset::as_seconds),
+            Ok(-86_399)
+        );
+
+        assert_eq!(
+            UtcOffset::from_signed_hms(true, 5, 60, 0).map_err(|err| (err.name, err.value)),
+            Err(("minutes", 60))
+        );
+        assert!(UtcOffset::from_signed_hms(false, 24, 0, 0).is_err());
+        assert!(UtcOffset::from_signed_hms(false, 0, 0, 60).is_err());
+    }
+
+    #[test]
+    fn from_military_letter() {
+        assert_eq!(UtcOffset::from_military_letter('Z'), Some(offset!(UTC)));
+        assert_eq!(UtcOffset::from_military_letter('A'), Some(offset!(+1)));
+        assert_eq!(UtcOffset::from_military_letter('I'), Some(offset!(+9)));
+        assert_eq!(UtcOffset::from_military_letter('K'), Some(offset!(+10)));
+        assert_eq!(UtcOffset::from_military_letter('M'), Some(offset!(+12)));
+        assert_eq!(UtcOffset::from_military_letter('N'), Some(offset!(-1)));
+        assert_eq!(UtcOffset::from_military_letter('Y'), Some(offset!(-12)));
+        assert_eq!(UtcOffset::from_military_letter('J'), None);
+        assert_eq!(UtcOffset::from_military_letter('a'), None);
+        assert_eq!(UtcOffset::from_military_letter('1'), None);
+    }
+
     #[test]
     fn directional_seconds() {
         assert_eq!(UtcOffset::east_seconds(1), offset!(+0:00:01));
@@ -569,6 +1005,18 @@ mod test {
         assert_eq!(offset!(-0:00:59).as_minutes(), 0);
     }
 
+    #[test]
+    fn as_minutes_checked() {
+        assert_eq!(offset!(UTC).as_minutes_checked(), Some(0));
+        assert_eq!(offset!(+1).as_minutes_checked(), Some(60));
+        assert_eq!(offset!(-5:30).as_minutes_checked(), Some(-330));
+        assert_eq!(offset!(+23:59).as_minutes_checked(), Some(1_439));
+        assert_eq!(offset!(+0:00:30).as_minutes_checked(), None);
+        assert_eq!(offset!(-0:00:30).as_minutes_checked(), None);
+        assert_eq!(offset!(-1:00:01).as_minutes_checked(), None);
+        assert_eq!(offset!(+23:59:59).as_minutes_checked(), None);
+    }
+
     #[test]
     fn as_seconds() {
         assert_eq!(offset!(+1).as_seconds(), 3_600);
@@ -585,11 +1033,133 @@ mod test {
         assert_eq!(offset!(-1).as_duration(), (-1).hours());
     }
 
+    #[test]
+    fn add_sub_duration() {
+        assert_eq!(offset!(+4:30) + 1.hours(), offset!(+5:30));
+        assert_eq!(offset!(+4:30) - 1.hours(), offset!(+3:30));
+        assert_eq!(offset!(+0:30) - 1.hours(), offset!(-0:30));
+        // Only whole seconds apply.
+        assert_eq!(offset!(+1) + 1.999.seconds(), offset!(+1:00:01));
+        assert_eq!(offset!(+1) - 1.999.seconds(), offset!(+0:59:59));
+
+        let mut offset = offset!(-5);
+        offset += 1.hours();
+        assert_eq!(offset, offset!(-4));
+        offset -= 2.hours();
+        assert_eq!(offset, offset!(-6));
+    }
+
+    #[test]
+    fn checked_add_sub() {
+        assert_eq!(offset!(+4:30).checked_add(1.hours()), Some(offset!(+5:30)));
+        assert_eq!(offset!(+4:30).checked_sub(1.hours()), Some(offset!(+3:30)));
+        assert_eq!(
+            offset!(+23:59:58).checked_add(1.seconds()),
+            Some(offset!(+23:59:59))
+        );
+        assert_eq!(offset!(+23:59:59).checked_add(1.seconds()), None);
+        assert_eq!(offset!(-23:59:59).checked_sub(1.seconds()), None);
+        assert_eq!(offset!(UTC).checked_add(Duration::max_value()), None);
+        assert_eq!(offset!(UTC).checked_sub(Duration::min_value()), None);
+        assert_eq!(offset!(+1).checked_sub(Duration::max_value()), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "resulting value is out of range")]
+    fn add_overflow() {
+        let _ = offset!(+23) + 1.hours();
+    }
+
     #[test]
     fn utc_is_zero() {
         assert_eq!(UtcOffset::UTC, offset!(+0));
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn next_transition_after() {
+        // An offset function with a transition from +1 to +2 at a fixed
+        // instant.
+        let transition = OffsetDateTime::from_unix_timestamp(1_585_443_600);
+        let offset_at = |datetime: OffsetDateTime| {
+            if datetime < transition {
+                Some(UtcOffset::hours(1))
+            } else {
+                Some(UtcOffset::hours(2))
+            }
+        };
+
+        let found = super::next_transition_after(
+            OffsetDateTime::from_unix_timestamp(1_577_836_800),
+            offset_at,
+        );
+        assert_eq!(found, Ok(Some(transition)));
+        assert_eq!(
+            found.map(|found| found.map(OffsetDateTime::offset)),
+            Ok(Some(UtcOffset::hours(2)))
+        );
+
+        // Searching from the transition itself finds nothing further.
+        assert_eq!(
+            super::next_transition_after(transition, offset_at),
+            Ok(None)
+        );
+
+        // A constant offset never transitions.
+        assert_eq!(
+            super::next_transition_after(transition, |_| Some(UtcOffset::UTC)),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn next_transition_after_unknown_offset() {
+        let start = OffsetDateTime::from_unix_timestamp(0);
+
+        // An offset that can never be determined is not reported as the
+        // absence of a transition.
+        assert!(super::next_transition_after(start, |_| None).is_err());
+
+        // Nor is an offset that stops being determinable partway through.
+        let unknown_from = start + 30.days();
+        let offset_at = |datetime: OffsetDateTime| {
+            if datetime < unknown_from {
+                Some(UtcOffset::UTC)
+            } else {
+                None
+            }
+        };
+        assert!(super::next_transition_after(start, offset_at).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn next_transition_after_limit() {
+        let start = OffsetDateTime::from_unix_timestamp(0);
+        let offset_at = |transition: OffsetDateTime| {
+            move |datetime: OffsetDateTime| {
+                if datetime < transition {
+                    Some(UtcOffset::UTC)
+                } else {
+                    Some(UtcOffset::hours(-5))
+                }
+            }
+        };
+
+        let within = start + 399.days() + 12.hours();
+        assert_eq!(
+            super::next_transition_after(start, offset_at(within)),
+            Ok(Some(within))
+        );
+
+        let beyond = start + 401.days();
+        assert_eq!(
+            super::next_transition_after(start, offset_at(beyond)),
+            Ok(None)
+        );
+    }
+
     #[test]
     fn format() {
         assert_eq!(offset!(+1).format("%z"), "+0100");
@@ -617,6 +1187,205 @@ mod test {
         assert_eq!(UtcOffset::parse("-0001", "%z"), Ok(offset!(-0:01)));
     }
 
+    #[test]
+    fn display_iso8601() {
+        #[cfg(not(feature = "std"))]
+        use alloc::string::ToString;
+
+        assert_eq!(offset!(UTC).display_iso8601().to_string(), "Z");
+        assert_eq!(offset!(-0).display_iso8601().to_string(), "Z");
+        assert_eq!(offset!(+5:30).display_iso8601().to_string(), "+05:30");
+        assert_eq!(offset!(+5:30:15).display_iso8601().to_string(), "+05:30:15");
+        assert_eq!(offset!(-0:00:01).display_iso8601().to_string(), "-00:00:01");
+        assert_eq!(offset!(-12).display_iso8601().to_string(), "-12:00");
+
+        // The `Display` implementation is unchanged.
+        assert_eq!(offset!(UTC).to_string(), "+0");
+        assert_eq!(offset!(+5:30).to_string(), "+5:30");
+    }
+
+    #[test]
+    fn format_colon_z() {
+        assert_eq!(offset!(+1).format("%:z"), "+01:00");
+        assert_eq!(offset!(-5:30).format("%:z"), "-05:30");
+        assert_eq!(offset!(+0).format("%:z"), "+00:00");
+        assert_eq!(offset!(-0:00:01).format("%:z"), "-00:00");
+    }
+
+    #[test]
+    fn parse_colon_z() {
+        assert_eq!(UtcOffset::parse("+01:00", "%:z"), Ok(offset!(+1)));
+        assert_eq!(UtcOffset::parse("-05:30", "%:z"), Ok(offset!(-5:30)));
+        assert_eq!(UtcOffset::parse("-00:00", "%:z"), Ok(offset!(+0)));
+        assert_eq!(
+            UtcOffset::parse("+0100", "%:z"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::parse("+01", "%:z"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+    }
+
+    #[test]
+    fn parse_zone_abbreviation() {
+        assert_eq!(UtcOffset::parse("GMT", "%Z"), Ok(offset!(UTC)));
+        assert_eq!(UtcOffset::parse("EST", "%Z"), Ok(offset!(-5)));
+        assert_eq!(UtcOffset::parse("CEST", "%Z"), Ok(offset!(+2)));
+        assert_eq!(
+            UtcOffset::parse("CST", "%Z"),
+            Err(crate::ParseError::AmbiguousZoneAbbreviation)
+        );
+        assert_eq!(
+            UtcOffset::parse("", "%Z"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+
+        let zones = ZoneAbbreviations {
+            known: &[],
+            ambiguous: &["EST"],
+        };
+        assert_eq!(
+            UtcOffset::parse_with_zones("EST", "%Z", zones),
+            Err(crate::ParseError::AmbiguousZoneAbbreviation)
+        );
+        assert_eq!(
+            UtcOffset::parse_with_zones("UTC", "%Z", zones),
+            Ok(offset!(UTC))
+        );
+        assert_eq!(ZoneAbbreviations::default(), ZoneAbbreviations::DEFAULT);
+    }
+
+    #[test]
+    fn format_hash_z() {
+        assert_eq!(offset!(UTC).format("%#z"), "Z");
+        assert_eq!(offset!(+1).format("%#z"), "A");
+        assert_eq!(offset!(+10).format("%#z"), "K");
+        assert_eq!(offset!(+12).format("%#z"), "M");
+        assert_eq!(offset!(-1).format("%#z"), "N");
+        assert_eq!(offset!(-12).format("%#z"), "Y");
+        // There is no letter for these offsets.
+        assert_eq!(offset!(+5:30).format("%#z"), "+0530");
+        assert_eq!(offset!(+13).format("%#z"), "+1300");
+    }
+
+    #[test]
+    fn parse_hash_z() {
+        assert_eq!(UtcOffset::parse("Z", "%#z"), Ok(offset!(UTC)));
+        assert_eq!(UtcOffset::parse("A", "%#z"), Ok(offset!(+1)));
+        assert_eq!(UtcOffset::parse("M", "%#z"), Ok(offset!(+12)));
+        assert_eq!(UtcOffset::parse("Y", "%#z"), Ok(offset!(-12)));
+        assert_eq!(
+            UtcOffset::parse("J", "%#z"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::parse("", "%#z"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+        assert_eq!(UtcOffset::parse("+0530", "%#z"), Ok(offset!(+5:30)));
+        assert_eq!(UtcOffset::parse("-1300", "%#z"), Ok(offset!(-13)));
+
+        // The formatted value can always be read back.
+        for &offset in &[
+            offset!(UTC),
+            offset!(+1),
+            offset!(-12),
+            offset!(+5:30),
+            offset!(+13),
+            offset!(-9:30),
+        ] {
+            assert_eq!(UtcOffset::parse(offset.format("%#z"), "%#z"), Ok(offset));
+        }
+        assert!(crate::util::validate_format_string("%#").is_err());
+        assert!(crate::util::validate_format_string("%#y").is_err());
+    }
+
+    #[test]
+    fn format_triple_colon_z() {
+        assert_eq!(offset!(+5).format("%:::z"), "+05");
+        assert_eq!(offset!(-8).format("%:::z"), "-08");
+        assert_eq!(offset!(UTC).format("%:::z"), "+00");
+        assert_eq!(offset!(+5:30).format("%:::z"), "+05:30");
+        assert_eq!(offset!(-0:00:01).format("%:::z"), "-00:00");
+        assert_eq!(offset!(+5).format("%z"), "+0500");
+        assert_eq!(offset!(+5).format("%-z"), "+0500");
+    }
+
+    #[test]
+    fn parse_triple_colon_z() {
+        assert_eq!(UtcOffset::parse("+05", "%:::z"), Ok(offset!(+5)));
+        assert_eq!(UtcOffset::parse("-08", "%:::z"), Ok(offset!(-8)));
+        assert_eq!(UtcOffset::parse("+05:30", "%:::z"), Ok(offset!(+5:30)));
+        assert_eq!(
+            UtcOffset::parse("+05:3", "%:::z"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::parse("+05", "%z"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+        assert_eq!(
+            UtcOffset::parse("+05", "%-z"),
+            Err(crate::ParseError::InvalidOffset)
+        );
+    }
+
+    #[test]
+    fn parse_rfc3339_unknown() {
+        assert_eq!(
+            UtcOffset::parse_rfc3339_unknown("-00:00"),
+            Ok((offset!(UTC), true))
+        );
+        assert_eq!(
+            UtcOffset::parse_rfc3339_unknown("+00:00"),
+            Ok((offset!(UTC), false))
+        );
+        assert_eq!(
+            UtcOffset::parse_rfc3339_unknown("z"),
+            Ok((offset!(UTC), false))
+        );
+        assert_eq!(
+            UtcOffset::parse_rfc3339_unknown("+05:30"),
+            Ok((offset!(+5:30), false))
+        );
+        assert_eq!(
+            UtcOffset::parse_rfc3339_unknown("-00:01"),
+            Ok((offset!(-0:01), false))
+        );
+        assert_eq!(
+            UtcOffset::parse_rfc3339_unknown("-0000"),
+            Ok((offset!(UTC), true))
+        );
+        assert_eq!(
+            UtcOffset::parse_rfc3339_unknown("+0000"),
+            Ok((offset!(UTC), false))
+        );
+        assert_eq!(
+            UtcOffset::parse_rfc3339_unknown("-0530"),
+            Ok((offset!(-5:30), false))
+        );
+        assert!(UtcOffset::parse_rfc3339_unknown("").is_err());
+        assert_eq!(
+            UtcOffset::parse_rfc3339_unknown("+05:30xyz"),
+            Err(crate::ParseError::UnexpectedTrailingCharacters)
+        );
+        assert_eq!(
+            UtcOffset::parse_rfc3339_unknown("-0000xyz"),
+            Err(crate::ParseError::UnexpectedTrailingCharacters)
+        );
+        assert_eq!(
+            UtcOffset::parse_rfc3339_unknown("Z "),
+            Err(crate::ParseError::UnexpectedTrailingCharacters)
+        );
+
+        // The existing parser does not make the distinction.
+        assert_eq!(
+            UtcOffset::parse("-0000", "%z"),
+            UtcOffset::parse("+0000", "%z")
+        );
+    }
+
     #[test]
     fn display() {
         assert_eq!(offset!(UTC).to_string(), "+0");
@@ -629,4 +1398,25 @@ mod test {
         assert_eq!(offset!(+23:59:59).to_string(), "+23:59:59");
         assert_eq!(offset!(-23:59:59).to_string(), "-23:59:59");
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn local_offset_at_concurrent() {
+        let datetime = OffsetDateTime::unix_epoch();
+        let expected = UtcOffset::local_offset_at(datetime);
+
+        let handles = (0..8)
+            .map(|_| {
+                std::thread::spawn(move || {
+                    for _ in 0..100 {
+                        assert_eq!(UtcOffset::local_offset_at(datetime), expected);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            assert!(handle.join().is_ok());
+        }
+    }
 }
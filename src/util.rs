@@ -16,8 +16,31 @@ const DAYS_IN_MONTH_COMMON_LEAP: [[u16; 12]; 2] = [
     [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
 ];
 
+/// The number of days before the start of each month in both common and leap
+/// years. This is shared by all conversions between month-day and ordinal
+/// dates, avoiding the need to sum the lengths of the preceding months.
+pub(crate) const DAYS_BEFORE_MONTH_COMMON_LEAP: [[u16; 12]; 2] = [
+    [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334],
+    [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335],
+];
+
 /// Get the number of days in the month of a given year.
-pub(crate) const fn days_in_year_month(year: i32, month: u8) -> u8 {
+///
+/// This is the single source of truth for month lengths within the crate,
+/// including the handling of February in leap years.
+///
+/// ```rust
+/// # use time::util::days_in_year_month;
+/// assert_eq!(days_in_year_month(2019, 2), 28);
+/// assert_eq!(days_in_year_month(2020, 2), 29);
+/// assert_eq!(days_in_year_month(2020, 4), 30);
+/// assert_eq!(days_in_year_month(2020, 12), 31);
+/// ```
+///
+/// # Panics
+///
+/// This panics if `month` is not in the range `1..=12`.
+pub const fn days_in_year_month(year: i32, month: u8) -> u8 {
     DAYS_IN_MONTH_COMMON_LEAP[is_leap_year(year) as usize][month as usize - 1] as u8
 }
 
@@ -1,5 +1,8 @@
 use const_fn::const_fn;
-use core::fmt::{self, Display};
+use core::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Sub, SubAssign},
+};
 #[cfg(feature = "serde")]
 use standback::convert::TryInto;
 use Weekday::*;
@@ -134,6 +137,103 @@ impl Weekday {
     pub const fn number_days_from_sunday(self) -> u8 {
         (self as u8 + 1) % 7
     }
+
+    /// Check if the weekday falls on the weekend, taken to be Saturday and
+    /// Sunday.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert!(Weekday::Saturday.is_weekend());
+    /// assert!(Weekday::Sunday.is_weekend());
+    /// assert!(!Weekday::Friday.is_weekend());
+    /// ```
+    pub const fn is_weekend(self) -> bool {
+        self as u8 >= Saturday as u8
+    }
+
+    /// Check if the weekday is not on the weekend, taken to be Saturday and
+    /// Sunday.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert!(Weekday::Monday.is_weekday());
+    /// assert!(!Weekday::Sunday.is_weekday());
+    /// ```
+    pub const fn is_weekday(self) -> bool {
+        !self.is_weekend()
+    }
+
+    /// Check if the weekday falls on the provided weekend. This is useful in
+    /// regions where the weekend is not Saturday and Sunday.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// let weekend = [Weekday::Friday, Weekday::Saturday];
+    /// assert!(Weekday::Friday.is_weekend_in(&weekend));
+    /// assert!(!Weekday::Sunday.is_weekend_in(&weekend));
+    /// ```
+    pub fn is_weekend_in(self, weekend: &[Weekday]) -> bool {
+        weekend.contains(&self)
+    }
+
+    /// Get the weekday with the given zero-indexed number of days from Monday.
+    /// The value must be in the range `0..7`.
+    fn from_number_days_from_monday(days: u8) -> Self {
+        match days {
+            0 => Monday,
+            1 => Tuesday,
+            2 => Wednesday,
+            3 => Thursday,
+            4 => Friday,
+            5 => Saturday,
+            6 => Sunday,
+            _ => unreachable!("A value mod 7 is always in the range 0..7"),
+        }
+    }
+}
+
+impl Add<u8> for Weekday {
+    type Output = Self;
+
+    /// Advance the weekday by the given number of days, wrapping around the
+    /// end of the week.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::Saturday + 2, Weekday::Monday);
+    /// assert_eq!(Weekday::Monday + 7, Weekday::Monday);
+    /// ```
+    fn add(self, days: u8) -> Self::Output {
+        Self::from_number_days_from_monday((self.number_days_from_monday() + days % 7) % 7)
+    }
+}
+
+impl AddAssign<u8> for Weekday {
+    fn add_assign(&mut self, days: u8) {
+        *self = *self + days;
+    }
+}
+
+impl Sub<u8> for Weekday {
+    type Output = Self;
+
+    /// Move the weekday back by the given number of days, wrapping around the
+    /// start of the week.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::Monday - 2, Weekday::Saturday);
+    /// assert_eq!(Weekday::Monday - 7, Weekday::Monday);
+    /// ```
+    fn sub(self, days: u8) -> Self::Output {
+        Self::from_number_days_from_monday((self.number_days_from_monday() + 7 - days % 7) % 7)
+    }
+}
+
+impl SubAssign<u8> for Weekday {
+    fn sub_assign(&mut self, days: u8) {
+        *self = *self - days;
+    }
 }
 
 impl Display for Weekday {
@@ -231,6 +331,80 @@ mod test {
         assert_eq!(Saturday.number_days_from_sunday(), 6);
     }
 
+    #[test]
+    fn add() {
+        assert_eq!(Saturday + 2, Monday);
+        assert_eq!(Monday + 0, Monday);
+        assert_eq!(Monday + 6, Sunday);
+        assert_eq!(Monday + 7, Monday);
+        assert_eq!(Wednesday + 255, Saturday);
+        for &weekday in &[
+            Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday,
+        ] {
+            assert_eq!(weekday + 1, weekday.next());
+        }
+    }
+
+    #[test]
+    fn add_assign() {
+        let mut weekday = Friday;
+        weekday += 3;
+        assert_eq!(weekday, Monday);
+    }
+
+    #[test]
+    fn sub() {
+        assert_eq!(Monday - 2, Saturday);
+        assert_eq!(Sunday - 6, Monday);
+        assert_eq!(Tuesday - 7, Tuesday);
+        assert_eq!(Wednesday - 255, Sunday);
+        for &weekday in &[
+            Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday,
+        ] {
+            assert_eq!(weekday - 1, weekday.previous());
+        }
+    }
+
+    #[test]
+    fn is_weekend() {
+        assert!(!Monday.is_weekend());
+        assert!(!Tuesday.is_weekend());
+        assert!(!Wednesday.is_weekend());
+        assert!(!Thursday.is_weekend());
+        assert!(!Friday.is_weekend());
+        assert!(Saturday.is_weekend());
+        assert!(Sunday.is_weekend());
+    }
+
+    #[test]
+    fn is_weekday() {
+        assert!(Monday.is_weekday());
+        assert!(Tuesday.is_weekday());
+        assert!(Wednesday.is_weekday());
+        assert!(Thursday.is_weekday());
+        assert!(Friday.is_weekday());
+        assert!(!Saturday.is_weekday());
+        assert!(!Sunday.is_weekday());
+    }
+
+    #[test]
+    fn is_weekend_in() {
+        let weekend = [Friday, Saturday];
+        assert!(!Thursday.is_weekend_in(&weekend));
+        assert!(Friday.is_weekend_in(&weekend));
+        assert!(Saturday.is_weekend_in(&weekend));
+        assert!(!Sunday.is_weekend_in(&weekend));
+        assert!(!Monday.is_weekend_in(&[]));
+        assert!(Sunday.is_weekend_in(&[Saturday, Sunday]));
+    }
+
+    #[test]
+    fn sub_assign() {
+        let mut weekday = Monday;
+        weekday -= 3;
+        assert_eq!(weekday, Friday);
+    }
+
     #[test]
     fn display() {
         #[cfg(not(feature = "std"))]
@@ -0,0 +1,232 @@
+use crate::Weekday::{self, *};
+use core::iter::FromIterator;
+
+/// Every day of the week, in the order of their bits.
+const ALL: [Weekday; 7] = [
+    Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday,
+];
+
+/// A set of days of the week, such as "Monday, Wednesday, and Friday".
+///
+/// The set is stored as a single byte, making it a compact representation for
+/// recurring schedules. Iteration always proceeds from Monday to Sunday,
+/// regardless of the order in which days were inserted.
+///
+/// ```rust
+/// # use time::{Weekday::*, WeekdaySet};
+/// let set: WeekdaySet = [Monday, Wednesday, Friday].iter().cloned().collect();
+/// assert!(set.contains(Wednesday));
+/// assert!(!set.contains(Thursday));
+/// assert_eq!(set.len(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WeekdaySet {
+    /// One bit for each weekday, with Monday as the least significant bit.
+    bits: u8,
+}
+
+impl WeekdaySet {
+    /// Create a set containing no days.
+    ///
+    /// ```rust
+    /// # use time::WeekdaySet;
+    /// assert!(WeekdaySet::empty().is_empty());
+    /// ```
+    pub const fn empty() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Create a set containing every day of the week.
+    ///
+    /// ```rust
+    /// # use time::WeekdaySet;
+    /// assert_eq!(WeekdaySet::all().len(), 7);
+    /// ```
+    pub const fn all() -> Self {
+        Self { bits: 0b111_1111 }
+    }
+
+    /// Create a set containing Monday through Friday.
+    ///
+    /// ```rust
+    /// # use time::{Weekday, WeekdaySet};
+    /// assert!(WeekdaySet::weekdays().contains(Weekday::Friday));
+    /// assert!(!WeekdaySet::weekdays().contains(Weekday::Saturday));
+    /// ```
+    pub const fn weekdays() -> Self {
+        Self { bits: 0b001_1111 }
+    }
+
+    /// Create a set containing Saturday and Sunday.
+    ///
+    /// ```rust
+    /// # use time::{Weekday, WeekdaySet};
+    /// assert!(WeekdaySet::weekends().contains(Weekday::Sunday));
+    /// assert!(!WeekdaySet::weekends().contains(Weekday::Monday));
+    /// ```
+    pub const fn weekends() -> Self {
+        Self { bits: 0b110_0000 }
+    }
+
+    /// Get the bit corresponding to the provided weekday.
+    const fn bit(weekday: Weekday) -> u8 {
+        1 << weekday.number_days_from_monday()
+    }
+
+    /// Check if the set contains the provided weekday.
+    ///
+    /// ```rust
+    /// # use time::{Weekday, WeekdaySet};
+    /// assert!(WeekdaySet::all().contains(Weekday::Monday));
+    /// assert!(!WeekdaySet::empty().contains(Weekday::Monday));
+    /// ```
+    pub const fn contains(self, weekday: Weekday) -> bool {
+        self.bits & Self::bit(weekday) != 0
+    }
+
+    /// Check if the set contains no days.
+    ///
+    /// ```rust
+    /// # use time::WeekdaySet;
+    /// assert!(WeekdaySet::empty().is_empty());
+    /// assert!(!WeekdaySet::weekends().is_empty());
+    /// ```
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Get the number of days in the set.
+    ///
+    /// ```rust
+    /// # use time::WeekdaySet;
+    /// assert_eq!(WeekdaySet::weekdays().len(), 5);
+    /// assert_eq!(WeekdaySet::weekends().len(), 2);
+    /// ```
+    pub const fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Add a day to the set, returning whether it was newly inserted.
+    ///
+    /// ```rust
+    /// # use time::{Weekday, WeekdaySet};
+    /// let mut set = WeekdaySet::empty();
+    /// assert!(set.insert(Weekday::Tuesday));
+    /// assert!(!set.insert(Weekday::Tuesday));
+    /// assert!(set.contains(Weekday::Tuesday));
+    /// ```
+    pub fn insert(&mut self, weekday: Weekday) -> bool {
+        let is_new = !self.contains(weekday);
+        self.bits |= Self::bit(weekday);
+        is_new
+    }
+
+    /// Remove a day from the set, returning whether it was present.
+    ///
+    /// ```rust
+    /// # use time::{Weekday, WeekdaySet};
+    /// let mut set = WeekdaySet::weekends();
+    /// assert!(set.remove(Weekday::Sunday));
+    /// assert!(!set.remove(Weekday::Sunday));
+    /// assert!(!set.contains(Weekday::Sunday));
+    /// ```
+    pub fn remove(&mut self, weekday: Weekday) -> bool {
+        let was_present = self.contains(weekday);
+        self.bits &= !Self::bit(weekday);
+        was_present
+    }
+
+    /// Iterate over the days in the set, from Monday to Sunday.
+    ///
+    /// ```rust
+    /// # use time::{Weekday::*, WeekdaySet};
+    /// let mut days = WeekdaySet::weekends().iter();
+    /// assert_eq!(days.next(), Some(Saturday));
+    /// assert_eq!(days.next(), Some(Sunday));
+    /// assert_eq!(days.next(), None);
+    /// ```
+    pub fn iter(self) -> impl Iterator<Item = Weekday> {
+        (0..ALL.len())
+            .map(|i| ALL[i])
+            .filter(move |&weekday| self.contains(weekday))
+    }
+}
+
+impl FromIterator<Weekday> for WeekdaySet {
+    fn from_iter<T: IntoIterator<Item = Weekday>>(iter: T) -> Self {
+        let mut set = Self::empty();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Weekday> for WeekdaySet {
+    fn extend<T: IntoIterator<Item = Weekday>>(&mut self, iter: T) {
+        for weekday in iter {
+            let _ = self.insert(weekday);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    #[test]
+    fn constructors() {
+        assert!(WeekdaySet::empty().is_empty());
+        assert_eq!(WeekdaySet::default(), WeekdaySet::empty());
+        assert_eq!(WeekdaySet::all().iter().collect::<Vec<_>>(), ALL);
+        assert_eq!(
+            WeekdaySet::weekdays().iter().collect::<Vec<_>>(),
+            [Monday, Tuesday, Wednesday, Thursday, Friday]
+        );
+        assert_eq!(
+            WeekdaySet::weekends().iter().collect::<Vec<_>>(),
+            [Saturday, Sunday]
+        );
+        for &weekday in &ALL {
+            assert!(WeekdaySet::all().contains(weekday));
+            assert_eq!(
+                WeekdaySet::weekends().contains(weekday),
+                weekday.is_weekend()
+            );
+            assert_eq!(
+                WeekdaySet::weekdays().contains(weekday),
+                weekday.is_weekday()
+            );
+        }
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut set = WeekdaySet::empty();
+        assert!(set.insert(Friday));
+        assert!(set.insert(Monday));
+        assert!(set.insert(Wednesday));
+        assert!(!set.insert(Monday));
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [Monday, Wednesday, Friday]);
+
+        assert!(set.remove(Wednesday));
+        assert!(!set.remove(Wednesday));
+        assert!(!set.remove(Sunday));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [Monday, Friday]);
+
+        assert!(set.remove(Monday));
+        assert!(set.remove(Friday));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn from_iter() {
+        let set: WeekdaySet = [Sunday, Saturday, Sunday].iter().cloned().collect();
+        assert_eq!(set, WeekdaySet::weekends());
+
+        let mut set = WeekdaySet::weekends();
+        set.extend(WeekdaySet::weekdays().iter());
+        assert_eq!(set, WeekdaySet::all());
+    }
+}
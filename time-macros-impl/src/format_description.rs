@@ -0,0 +1,147 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+#[allow(unused_imports)]
+use standback::prelude::*;
+use syn::{
+    parse::{Parse, ParseStream},
+    LitStr, Result,
+};
+
+/// The characters that may follow a `%` (and any padding modifier) in a
+/// format description. This must be kept in sync with the formatting string
+/// parser in the time crate.
+const SPECIFIERS: &[char] = &[
+    'a', 'A', 'b', 'B', 'c', 'C', 'd', 'D', 'e', 'F', 'g', 'G', 'H', 'I', 'j', 'm', 'M', 'N', 'o',
+    'O', 'p', 'P', 'r', 'R', 's', 'S', 'T', 'u', 'U', 'V', 'w', 'W', 'y', 'Y', 'z', 'Z', '+',
+    // Aliases for chrono's specifiers.
+    'f', 'h', 'k', 'l', 'n', 't', 'v', 'x', 'X',
+];
+
+/// The specifiers that accept an explicit width, as in `%04Y`. This must be
+/// kept in sync with the formatting string parser in the time crate.
+const WIDTH_SPECIFIERS: &[char] = &[
+    'C', 'd', 'g', 'G', 'H', 'I', 'j', 'm', 'M', 'S', 'U', 'V', 'W', 'y', 'Y',
+];
+
+pub(crate) struct FormatDescription {
+    value: LitStr,
+}
+
+impl Parse for FormatDescription {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let value = input.parse::<LitStr>()?;
+        let s = value.value();
+        let mut chars = s.chars().peekable();
+        let mut optional_depth = 0_usize;
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                continue;
+            }
+
+            // Padding modifiers are permitted on every specifier.
+            let mut padding = None;
+            if let Some(&c) = chars.peek() {
+                if "-_0".contains(c) {
+                    padding = chars.next();
+                }
+            }
+
+            // `%N` may be preceded by the number of digits, as in `%6N`. The
+            // same applies to chrono's `%f`.
+            let mut lookahead = chars.clone().take(2);
+            if let (Some('1'..='9'), Some(c)) = (lookahead.next(), lookahead.next()) {
+                if c == 'N' || c == 'f' {
+                    let _ = chars.next();
+                }
+            }
+
+            // An explicit width may precede numeric specifiers, as in `%04Y`.
+            let mut width: Option<u8> = None;
+            while let Some(digit @ '0'..='9') = chars.peek().copied() {
+                let _ = chars.next();
+                width = width
+                    .unwrap_or(0)
+                    .checked_mul(10)
+                    .and_then(|width| width.checked_add(digit as u8 - b'0'));
+                if width.is_none() {
+                    return error!(value.span(), "width must be less than 256");
+                }
+            }
+            if let Some(width) = width {
+                if width == 0 {
+                    return error!(value.span(), "width must be nonzero");
+                }
+                if padding.is_some() && padding != Some('0') {
+                    return error!(
+                        value.span(),
+                        "a width may only be combined with the `0` modifier"
+                    );
+                }
+                match chars.peek() {
+                    Some(c) if WIDTH_SPECIFIERS.contains(c) => {}
+                    Some(c) => {
+                        return error!(value.span(), "specifier `{}` does not accept a width", c)
+                    }
+                    None => {}
+                }
+            }
+
+            match chars.next() {
+                Some('%') => {}
+                Some('(') => optional_depth += 1,
+                Some(')') => {
+                    if optional_depth == 0 {
+                        return error!(value.span(), "unmatched `%)` without a preceding `%(`");
+                    }
+                    optional_depth -= 1;
+                }
+                Some('[') => {
+                    if !chars.by_ref().any(|c| c == ']') {
+                        return error!(value.span(), "unterminated literal block `%[`");
+                    }
+                }
+                Some(':') => match chars.next() {
+                    Some('z') => {}
+                    Some(':') => match (chars.next(), chars.next()) {
+                        (Some(':'), Some('z')) => {}
+                        _ => return error!(value.span(), "expected `::z` after `%:`"),
+                    },
+                    Some(c) => return error!(value.span(), "invalid specifier `:{}`", c),
+                    None => return error!(value.span(), "cannot end formatting with `%:`"),
+                },
+                Some('.') => match (chars.next(), chars.next()) {
+                    (Some('1'..='9'), Some('f')) => {}
+                    _ => return error!(value.span(), "expected a digit and `f` after `%.`"),
+                },
+                Some('#') => match chars.next() {
+                    Some('z') => {}
+                    Some(c) => return error!(value.span(), "invalid specifier `#{}`", c),
+                    None => return error!(value.span(), "cannot end formatting with `%#`"),
+                },
+                Some(c) if SPECIFIERS.contains(&c) => {}
+                Some(c) => return error!(value.span(), "invalid specifier `{}`", c),
+                None => {
+                    return error!(
+                        value.span(),
+                        "cannot end formatting with `%`. If you want a literal `%`, you must use \
+                         `%%`."
+                    )
+                }
+            }
+        }
+
+        if optional_depth != 0 {
+            return error!(value.span(), "unterminated optional section `%(`");
+        }
+
+        Ok(Self { value })
+    }
+}
+
+impl ToTokens for FormatDescription {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Self { value } = self;
+        tokens.extend(quote! { #value });
+    }
+}
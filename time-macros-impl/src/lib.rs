@@ -80,11 +80,13 @@ mod kw {
 
 mod date;
 mod ext;
+mod format_description;
 mod offset;
 mod time;
 mod time_crate;
 
 use date::Date;
+use format_description::FormatDescription;
 use offset::Offset;
 use proc_macro_hack::proc_macro_hack;
 use quote::ToTokens;
@@ -107,4 +109,5 @@ impl_macros! {
     time: Time,
     offset: Offset,
     date: Date,
+    format_description: FormatDescription,
 }
@@ -85,10 +85,15 @@ impl Date {
             (month, self.year)
         };
 
-        match (day as i32 + (13 * (month as i32 + 1)) / 5 + adjusted_year + adjusted_year / 4
-            - adjusted_year / 100
-            + adjusted_year / 400)
-            .rem_euclid(7)
+        // Floored division is required for the leap year terms to be correct
+        // for negative years.
+        match (day as i32
+            + (13 * (month as i32 + 1)) / 5
+            + adjusted_year
+            + adjusted_year.div_euclid(4)
+            - adjusted_year.div_euclid(100)
+            + adjusted_year.div_euclid(400))
+        .rem_euclid(7)
         {
             0 => Saturday,
             1 => Sunday,
@@ -102,21 +107,25 @@ impl Date {
     }
 
     pub(crate) fn from_iso_ywd_unchecked(year: i32, week: u8, iso_weekday_number: u8) -> Date {
-        let ordinal = week as u16 * 7 + iso_weekday_number as u16
+        // This is negative for some dates in week 1.
+        let ordinal = week as i16 * 7 + iso_weekday_number as i16
             - (Self::from_yo_unchecked(year, 4)
                 .weekday()
-                .iso_weekday_number() as u16
+                .iso_weekday_number() as i16
                 + 3);
 
         if ordinal < 1 {
-            return Self::from_yo_unchecked(year - 1, ordinal + days_in_year(year - 1));
+            return Self::from_yo_unchecked(
+                year - 1,
+                (ordinal + days_in_year(year - 1) as i16) as u16,
+            );
         }
 
-        let days_in_cur_year = days_in_year(year);
+        let days_in_cur_year = days_in_year(year) as i16;
         if ordinal > days_in_cur_year {
-            Self::from_yo_unchecked(year + 1, ordinal - days_in_cur_year)
+            Self::from_yo_unchecked(year + 1, (ordinal - days_in_cur_year) as u16)
         } else {
-            Self::from_yo_unchecked(year, ordinal)
+            Self::from_yo_unchecked(year, ordinal as u16)
         }
     }
 
@@ -3,4 +3,4 @@
 use proc_macro_hack::proc_macro_hack;
 
 #[proc_macro_hack]
-pub use time_macros_impl::{date, offset, time};
+pub use time_macros_impl::{date, format_description, offset, time};